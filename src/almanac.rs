@@ -0,0 +1,464 @@
+use crate::calendar::*;
+use crate::constants::*;
+use crate::Instant;
+
+#[cfg(test)]
+pub mod events;
+
+// Algorithms are from Jean Meeus, "Astronomical Algorithms" (2nd edition), chapters 27 and 49.
+
+const DAYS_IN_JULIAN_CENTURY: f64 = 36_525.0;
+const JULIAN_DAY_OF_J2000: f64 = 2_451_545.0;
+const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+// The mean new moon of 2000-01-06, lunation 0.
+const JULIAN_DAY_OF_FIRST_NEW_MOON_OF_2000: f64 = 2_451_550.097_66;
+const PHASES_IN_LUNATION: i64 = 4;
+const EVENTS_IN_YEAR: usize = 4;
+const OUT_OF_RANGE: &str = "instant would be out of range";
+
+/// The principal phases of the Moon.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LunarPhase {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+/// The equinoxes and solstices, which mark the start of the astronomical seasons.
+///
+/// These are named by month, rather than by season, as the seasons are reversed in the southern hemisphere.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SeasonalEvent {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+/// Either a lunar phase or a seasonal event.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AstronomicalEvent {
+    Lunar(LunarPhase),
+    Seasonal(SeasonalEvent),
+}
+
+/// An iterator over the lunar phases, equinoxes and solstices in a range of instants, in chronological order.
+#[derive(Clone, Debug)]
+pub struct AstronomicalEvents {
+    next_phase: i64,
+    next_season: (i64, usize),
+    end: Instant,
+}
+
+impl LunarPhase {
+    const ALL: [LunarPhase; PHASES_IN_LUNATION as usize] = [
+        LunarPhase::NewMoon,
+        LunarPhase::FirstQuarter,
+        LunarPhase::FullMoon,
+        LunarPhase::LastQuarter,
+    ];
+
+    /// Calculates the instant of this phase in the given lunation.
+    ///
+    /// Lunations are numbered from the new moon of '2000-01-06', and are about 29.5 days long.
+    /// Results are accurate to within about a minute for the years -2000 to 4000.
+    ///
+    /// # Parameters
+    ///  - `lunation`: the lunation, which may be negative.
+    ///
+    /// # Panics
+    /// - if the instant would be out of range.
+    pub fn instant_in(&self, lunation: i64) -> Instant {
+        let phase = lunation
+            .checked_mul(PHASES_IN_LUNATION)
+            .and_then(|phase| phase.checked_add(*self as i64))
+            .expect(OUT_OF_RANGE);
+        instant_of_phase(phase)
+    }
+
+    /// Calculates the first instant of this phase strictly after the given instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to search from.
+    ///
+    /// # Panics
+    /// - if the search would leave the range of an instant, as it does near `Instant::MIN` and `Instant::MAX`.
+    pub fn next_after(&self, instant: Instant) -> Instant {
+        let mut lunation = estimate_lunation(instant) - 1;
+        while self.instant_in(lunation) <= instant {
+            lunation += 1;
+        }
+        self.instant_in(lunation)
+    }
+
+    /// Calculates the last instant of this phase strictly before the given instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to search from.
+    ///
+    /// # Panics
+    /// - if the search would leave the range of an instant, as it does near `Instant::MIN` and `Instant::MAX`.
+    pub fn previous_before(&self, instant: Instant) -> Instant {
+        let mut lunation = estimate_lunation(instant) + 1;
+        while self.instant_in(lunation) >= instant {
+            lunation -= 1;
+        }
+        self.instant_in(lunation)
+    }
+}
+
+impl SeasonalEvent {
+    const ALL: [SeasonalEvent; EVENTS_IN_YEAR] = [
+        SeasonalEvent::MarchEquinox,
+        SeasonalEvent::JuneSolstice,
+        SeasonalEvent::SeptemberEquinox,
+        SeasonalEvent::DecemberSolstice,
+    ];
+
+    /// Calculates the instant of this event in the given year.
+    ///
+    /// Results are accurate to within about a minute for the years -1000 to 3000.
+    ///
+    /// # Parameters
+    ///  - `year`: the (astronomically numbered) year.
+    ///
+    /// # Panics
+    /// - if the instant would be out of range.
+    pub fn instant_in(&self, year: i64) -> Instant {
+        instant_of_julian_ephemeris_day(seasonal_julian_ephemeris_day(*self, year))
+    }
+
+    /// Calculates the first instant of this event strictly after the given instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to search from.
+    ///
+    /// # Panics
+    /// - if the search would leave the range of an instant, as it does near `Instant::MIN` and `Instant::MAX`.
+    pub fn next_after(&self, instant: Instant) -> Instant {
+        let mut year = year_of(instant) - 1;
+        while self.instant_in(year) <= instant {
+            year += 1;
+        }
+        self.instant_in(year)
+    }
+
+    /// Calculates the last instant of this event strictly before the given instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to search from.
+    ///
+    /// # Panics
+    /// - if the search would leave the range of an instant, as it does near `Instant::MIN` and `Instant::MAX`.
+    pub fn previous_before(&self, instant: Instant) -> Instant {
+        let mut year = year_of(instant) + 1;
+        while self.instant_in(year) >= instant {
+            year -= 1;
+        }
+        self.instant_in(year)
+    }
+}
+
+impl AstronomicalEvents {
+    /// Obtains an iterator over the events from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// # Parameters
+    ///  - `start`: the start of the range.
+    ///  - `end`: the end of the range.
+    pub fn between(start: Instant, end: Instant) -> AstronomicalEvents {
+        let mut next_phase = (estimate_lunation(start) - 1) * PHASES_IN_LUNATION;
+        while instant_of_phase(next_phase) < start {
+            next_phase += 1;
+        }
+        let mut next_season = (year_of(start) - 1, 0);
+        while instant_of_season(next_season) < start {
+            next_season = following_season(next_season);
+        }
+
+        AstronomicalEvents {
+            next_phase,
+            next_season,
+            end,
+        }
+    }
+}
+
+impl Iterator for AstronomicalEvents {
+    type Item = (Instant, AstronomicalEvent);
+
+    fn next(&mut self) -> Option<(Instant, AstronomicalEvent)> {
+        let phase = instant_of_phase(self.next_phase);
+        let season = instant_of_season(self.next_season);
+
+        let next = if phase <= season {
+            let event = LunarPhase::ALL[self.next_phase.rem_euclid(PHASES_IN_LUNATION) as usize];
+            self.next_phase += 1;
+            (phase, AstronomicalEvent::Lunar(event))
+        } else {
+            let event = SeasonalEvent::ALL[self.next_season.1];
+            self.next_season = following_season(self.next_season);
+            (season, AstronomicalEvent::Seasonal(event))
+        };
+
+        if next.0 < self.end {
+            Some(next)
+        } else {
+            // Stay exhausted.
+            self.end = Instant::MIN;
+            None
+        }
+    }
+}
+
+fn instant_of_phase(phase: i64) -> Instant {
+    instant_of_julian_ephemeris_day(phase_julian_ephemeris_day(phase))
+}
+
+// As `Instant::of_julian_ephemeris_day`, but panicking with the message the methods here document.
+fn instant_of_julian_ephemeris_day(julian_ephemeris_day: f64) -> Instant {
+    match Instant::try_of_julian_ephemeris_day(julian_ephemeris_day) {
+        Ok(instant) => instant,
+        Err(_) => panic!("{}", OUT_OF_RANGE),
+    }
+}
+
+fn instant_of_season((year, index): (i64, usize)) -> Instant {
+    SeasonalEvent::ALL[index].instant_in(year)
+}
+
+fn following_season((year, index): (i64, usize)) -> (i64, usize) {
+    if index + 1 == EVENTS_IN_YEAR {
+        (year + 1, 0)
+    } else {
+        (year, index + 1)
+    }
+}
+
+fn estimate_lunation(instant: Instant) -> i64 {
+    ((instant.to_julian_ephemeris_day() - JULIAN_DAY_OF_FIRST_NEW_MOON_OF_2000)
+        / MEAN_SYNODIC_MONTH)
+        .floor() as i64
+}
+
+fn year_of(instant: Instant) -> i64 {
+    let (year, _, _) = date_of_epoch_day(instant.epoch_second().div_euclid(SECONDS_IN_DAY));
+    year
+}
+
+fn sum_of_sines(terms: &[(f64, f64)]) -> f64 {
+    terms
+        .iter()
+        .map(|(coefficient, argument)| coefficient * argument.to_radians().sin())
+        .sum()
+}
+
+// Meeus, chapter 49. `phase` counts quarter lunations from the new moon of 2000-01-06.
+fn phase_julian_ephemeris_day(phase: i64) -> f64 {
+    let k = phase as f64 / PHASES_IN_LUNATION as f64;
+    let t = k / 1236.85;
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+
+    let mean = JULIAN_DAY_OF_FIRST_NEW_MOON_OF_2000 + MEAN_SYNODIC_MONTH * k + 0.000_154_37 * t2
+        - 0.000_000_150 * t3
+        + 0.000_000_000_73 * t4;
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t2;
+    let m = 2.5534 + 29.105_356_70 * k - 0.000_001_4 * t2 - 0.000_000_11 * t3;
+    let mp =
+        201.5643 + 385.816_935_28 * k + 0.010_758_2 * t2 + 0.000_012_38 * t3 - 0.000_000_058 * t4;
+    let f =
+        160.7108 + 390.670_502_84 * k - 0.001_611_8 * t2 - 0.000_002_27 * t3 + 0.000_000_011 * t4;
+    let omega = 124.7746 - 1.563_755_88 * k + 0.002_067_2 * t2 + 0.000_002_15 * t3;
+
+    let correction = match phase.rem_euclid(PHASES_IN_LUNATION) {
+        0 => sum_of_sines(&[
+            (-0.40720, mp),
+            (0.17241 * e, m),
+            (0.01608, 2.0 * mp),
+            (0.01039, 2.0 * f),
+            (0.00739 * e, mp - m),
+            (-0.00514 * e, mp + m),
+            (0.00208 * e * e, 2.0 * m),
+            (-0.00111, mp - 2.0 * f),
+            (-0.00057, mp + 2.0 * f),
+            (0.00056 * e, 2.0 * mp + m),
+            (-0.00042, 3.0 * mp),
+            (0.00042 * e, m + 2.0 * f),
+            (0.00038 * e, m - 2.0 * f),
+            (-0.00024 * e, 2.0 * mp - m),
+            (-0.00017, omega),
+            (-0.00007, mp + 2.0 * m),
+            (0.00004, 2.0 * mp - 2.0 * f),
+            (0.00004, 3.0 * m),
+            (0.00003, mp + m - 2.0 * f),
+            (0.00003, 2.0 * mp + 2.0 * f),
+            (-0.00003, mp + m + 2.0 * f),
+            (0.00003, mp - m + 2.0 * f),
+            (-0.00002, mp - m - 2.0 * f),
+            (-0.00002, 3.0 * mp + m),
+            (0.00002, 4.0 * mp),
+        ]),
+        2 => sum_of_sines(&[
+            (-0.40614, mp),
+            (0.17302 * e, m),
+            (0.01614, 2.0 * mp),
+            (0.01043, 2.0 * f),
+            (0.00734 * e, mp - m),
+            (-0.00515 * e, mp + m),
+            (0.00209 * e * e, 2.0 * m),
+            (-0.00111, mp - 2.0 * f),
+            (-0.00057, mp + 2.0 * f),
+            (0.00056 * e, 2.0 * mp + m),
+            (-0.00042, 3.0 * mp),
+            (0.00042 * e, m + 2.0 * f),
+            (0.00038 * e, m - 2.0 * f),
+            (-0.00024 * e, 2.0 * mp - m),
+            (-0.00017, omega),
+            (-0.00007, mp + 2.0 * m),
+            (0.00004, 2.0 * mp - 2.0 * f),
+            (0.00004, 3.0 * m),
+            (0.00003, mp + m - 2.0 * f),
+            (0.00003, 2.0 * mp + 2.0 * f),
+            (-0.00003, mp + m + 2.0 * f),
+            (0.00003, mp - m + 2.0 * f),
+            (-0.00002, mp - m - 2.0 * f),
+            (-0.00002, 3.0 * mp + m),
+            (0.00002, 4.0 * mp),
+        ]),
+        quarter => {
+            let w = 0.00306 - 0.00038 * e * m.to_radians().cos() + 0.00026 * mp.to_radians().cos()
+                - 0.00002 * (mp - m).to_radians().cos()
+                + 0.00002 * (mp + m).to_radians().cos()
+                + 0.00002 * (2.0 * f).to_radians().cos();
+            let w = if quarter == 1 { w } else { -w };
+            w + sum_of_sines(&[
+                (-0.62801, mp),
+                (0.17172 * e, m),
+                (-0.01183 * e, mp + m),
+                (0.00862, 2.0 * mp),
+                (0.00804, 2.0 * f),
+                (0.00454 * e, mp - m),
+                (0.00204 * e * e, 2.0 * m),
+                (-0.00180, mp - 2.0 * f),
+                (-0.00070, mp + 2.0 * f),
+                (-0.00040, 3.0 * mp),
+                (-0.00034 * e, 2.0 * mp - m),
+                (0.00032 * e, m + 2.0 * f),
+                (0.00032 * e, m - 2.0 * f),
+                (-0.00028 * e * e, mp + 2.0 * m),
+                (0.00027 * e, 2.0 * mp + m),
+                (-0.00017, omega),
+                (-0.00005, mp - m - 2.0 * f),
+                (0.00004, 2.0 * mp + 2.0 * f),
+                (-0.00004, mp + m + 2.0 * f),
+                (0.00004, mp - 2.0 * m),
+                (0.00003, mp + m - 2.0 * f),
+                (0.00003, 3.0 * m),
+                (0.00002, 2.0 * mp - 2.0 * f),
+                (0.00002, mp - m + 2.0 * f),
+                (-0.00002, 3.0 * mp + m),
+            ])
+        }
+    };
+
+    let planetary = sum_of_sines(&[
+        (0.000_325, 299.77 + 0.107_408 * k - 0.009_173 * t2),
+        (0.000_165, 251.88 + 0.016_321 * k),
+        (0.000_164, 251.83 + 26.651_886 * k),
+        (0.000_126, 349.42 + 36.412_478 * k),
+        (0.000_110, 84.66 + 18.206_239 * k),
+        (0.000_062, 141.74 + 53.303_771 * k),
+        (0.000_060, 207.14 + 2.453_732 * k),
+        (0.000_056, 154.84 + 7.306_860 * k),
+        (0.000_047, 34.52 + 27.261_239 * k),
+        (0.000_042, 207.19 + 0.121_824 * k),
+        (0.000_040, 291.34 + 1.844_379 * k),
+        (0.000_037, 161.72 + 24.198_154 * k),
+        (0.000_035, 239.56 + 25.513_099 * k),
+        (0.000_023, 331.55 + 3.592_518 * k),
+    ]);
+
+    mean + correction + planetary
+}
+
+// Meeus, chapter 27.
+fn seasonal_julian_ephemeris_day(event: SeasonalEvent, year: i64) -> f64 {
+    let coefficients = if year < YEARS_IN_MILLENNIUM {
+        match event {
+            SeasonalEvent::MarchEquinox => {
+                [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071]
+            }
+            SeasonalEvent::JuneSolstice => {
+                [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025]
+            }
+            SeasonalEvent::SeptemberEquinox => {
+                [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074]
+            }
+            SeasonalEvent::DecemberSolstice => {
+                [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006]
+            }
+        }
+    } else {
+        match event {
+            SeasonalEvent::MarchEquinox => {
+                [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057]
+            }
+            SeasonalEvent::JuneSolstice => {
+                [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030]
+            }
+            SeasonalEvent::SeptemberEquinox => {
+                [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078]
+            }
+            SeasonalEvent::DecemberSolstice => {
+                [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032]
+            }
+        }
+    };
+    let y = if year < YEARS_IN_MILLENNIUM {
+        year as f64 / YEARS_IN_MILLENNIUM as f64
+    } else {
+        (year - 2000) as f64 / YEARS_IN_MILLENNIUM as f64
+    };
+    let mean = coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |total, coefficient| total * y + coefficient);
+
+    let t = (mean - JULIAN_DAY_OF_J2000) / DAYS_IN_JULIAN_CENTURY;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    mean + 0.00001 * s / lambda
+}
+
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
@@ -0,0 +1,198 @@
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+
+use crate::{
+    AstronomicalEvent, AstronomicalEvents, Instant, LocalDate, LunarPhase, SeasonalEvent,
+    TaiUtcTable, UtcInstant,
+};
+
+// The instant of the given UTC time on the given date.
+fn utc(year: i64, month: u32, day: u32, hour: i64, minute: i64) -> Instant {
+    let date = LocalDate::of(year, month, day);
+    TaiUtcTable::default()
+        .to_instant(UtcInstant::of_modified_julian_day(
+            date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            (hour * MINUTES_IN_HOUR + minute) * SECONDS_IN_MINUTE * NANOSECONDS_IN_SECOND,
+        ))
+        .unwrap()
+}
+
+fn assert_within_minutes(minutes: i64, expected: Instant, actual: Instant) {
+    let difference = (expected.epoch_second() - actual.epoch_second()).abs();
+    assert!(
+        difference <= minutes * SECONDS_IN_MINUTE,
+        "expected {:?}, was {:?}",
+        expected,
+        actual
+    );
+}
+
+fn assert_julian_ephemeris_day(expected: f64, actual: Instant) {
+    let difference = (expected - actual.to_julian_ephemeris_day()).abs();
+    assert!(
+        difference < 0.000_01,
+        "expected {}, was {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn meeus_new_moon() {
+    // Example 49.a: the new moon of February 1977.
+    assert_julian_ephemeris_day(2_443_192.651_18, LunarPhase::NewMoon.instant_in(-283));
+}
+
+#[test]
+fn meeus_last_quarter() {
+    // Example 49.b: the first last quarter of 2044.
+    assert_julian_ephemeris_day(2_467_636.491_86, LunarPhase::LastQuarter.instant_in(544));
+}
+
+#[test]
+fn meeus_june_solstice() {
+    // Example 27.a: the June solstice of 1962.
+    assert_julian_ephemeris_day(
+        2_437_837.392_45,
+        SeasonalEvent::JuneSolstice.instant_in(1962),
+    );
+}
+
+#[test]
+fn seasons_of_2020() {
+    assert_within_minutes(
+        1,
+        utc(2020, 3, 20, 3, 50),
+        SeasonalEvent::MarchEquinox.instant_in(2020),
+    );
+    assert_within_minutes(
+        1,
+        utc(2020, 6, 20, 21, 44),
+        SeasonalEvent::JuneSolstice.instant_in(2020),
+    );
+    assert_within_minutes(
+        1,
+        utc(2020, 9, 22, 13, 31),
+        SeasonalEvent::SeptemberEquinox.instant_in(2020),
+    );
+    assert_within_minutes(
+        1,
+        utc(2020, 12, 21, 10, 2),
+        SeasonalEvent::DecemberSolstice.instant_in(2020),
+    );
+}
+
+#[test]
+fn next_and_previous_phase() {
+    let halloween = utc(2020, 10, 31, 0, 0);
+
+    assert_within_minutes(
+        1,
+        utc(2020, 10, 31, 14, 49),
+        LunarPhase::FullMoon.next_after(halloween),
+    );
+    assert_within_minutes(
+        1,
+        utc(2020, 10, 1, 21, 5),
+        LunarPhase::FullMoon.previous_before(halloween),
+    );
+    assert_within_minutes(
+        1,
+        utc(2020, 10, 16, 19, 31),
+        LunarPhase::NewMoon.previous_before(halloween),
+    );
+}
+
+#[test]
+fn next_and_previous_are_strict() {
+    let full_moon = LunarPhase::FullMoon.instant_in(260);
+    let equinox = SeasonalEvent::MarchEquinox.instant_in(2020);
+
+    assert!(LunarPhase::FullMoon.next_after(full_moon) > full_moon);
+    assert!(LunarPhase::FullMoon.previous_before(full_moon) < full_moon);
+    assert_eq!(
+        SeasonalEvent::MarchEquinox.instant_in(2021),
+        SeasonalEvent::MarchEquinox.next_after(equinox)
+    );
+    assert_eq!(
+        SeasonalEvent::MarchEquinox.instant_in(2019),
+        SeasonalEvent::MarchEquinox.previous_before(equinox)
+    );
+}
+
+#[test]
+fn events_of_2020() {
+    let events: Vec<_> =
+        AstronomicalEvents::between(utc(2020, 1, 1, 0, 0), utc(2021, 1, 1, 0, 0)).collect();
+
+    assert_eq!(54, events.len());
+    assert!(events.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(
+        vec![
+            AstronomicalEvent::Seasonal(SeasonalEvent::MarchEquinox),
+            AstronomicalEvent::Seasonal(SeasonalEvent::JuneSolstice),
+            AstronomicalEvent::Seasonal(SeasonalEvent::SeptemberEquinox),
+            AstronomicalEvent::Seasonal(SeasonalEvent::DecemberSolstice),
+        ],
+        events
+            .iter()
+            .map(|(_, event)| *event)
+            .filter(|event| matches!(event, AstronomicalEvent::Seasonal(_)))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        AstronomicalEvent::Lunar(LunarPhase::FirstQuarter),
+        events[0].1
+    );
+    assert_eq!(
+        13,
+        events
+            .iter()
+            .filter(|(_, event)| *event == AstronomicalEvent::Lunar(LunarPhase::FullMoon))
+            .count()
+    );
+}
+
+#[test]
+fn events_are_half_open() {
+    let equinox = SeasonalEvent::MarchEquinox.instant_in(2020);
+
+    let starting = AstronomicalEvents::between(equinox, Instant::MAX).next();
+    let ending = AstronomicalEvents::between(Instant::EPOCH, equinox).last();
+
+    assert_eq!(
+        Some((
+            equinox,
+            AstronomicalEvent::Seasonal(SeasonalEvent::MarchEquinox)
+        )),
+        starting
+    );
+    assert!(ending.unwrap().0 < equinox);
+}
+
+#[test]
+fn instants_out_of_range() {
+    let message = "instant would be out of range";
+    for &lunation in &[i64::MAX / 2, i64::MIN / 2, i64::MAX, i64::MIN, 1 << 45] {
+        expect_panic(message, || LunarPhase::NewMoon.instant_in(lunation)).unwrap();
+        expect_panic(message, || LunarPhase::LastQuarter.instant_in(lunation)).unwrap();
+    }
+    for &year in &[i64::MAX, i64::MIN, 1 << 40] {
+        expect_panic(message, || SeasonalEvent::MarchEquinox.instant_in(year)).unwrap();
+    }
+}
+
+#[test]
+fn searches_out_of_range() {
+    for &instant in &[Instant::MIN, Instant::MAX] {
+        let message = "instant would be out of range";
+        expect_panic(message, || LunarPhase::NewMoon.next_after(instant)).unwrap();
+        expect_panic(message, || LunarPhase::FullMoon.previous_before(instant)).unwrap();
+        expect_panic(message, || SeasonalEvent::MarchEquinox.next_after(instant)).unwrap();
+        expect_panic(message, || {
+            SeasonalEvent::DecemberSolstice.previous_before(instant)
+        })
+        .unwrap();
+    }
+}
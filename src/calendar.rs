@@ -0,0 +1,129 @@
+use crate::constants::*;
+
+#[cfg(test)]
+pub mod conversions;
+
+// Proleptic ISO (Gregorian) calendar arithmetic, shared by anything that needs to map
+// a count of days since the epoch to a civil year, month and day (and back).
+
+pub const fn is_leap_year(year: i64) -> bool {
+    (year & 3) == 0 && (year % YEARS_IN_CENTURY != 0 || year % YEARS_IN_LEAP_YEAR_EPICYCLE == 0)
+}
+
+pub const fn days_in_year(year: i64) -> i64 {
+    if is_leap_year(year) {
+        DAYS_IN_LONG_YEAR
+    } else {
+        DAYS_IN_YEAR
+    }
+}
+
+pub const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days since '1970-01-01' of the given (valid) date.
+pub const fn epoch_day_of(year: i64, month: u32, day: u32) -> i64 {
+    let month = month as i64;
+    let mut total = DAYS_IN_YEAR * year;
+    if year >= 0 {
+        total += (year + 3) / YEARS_IN_LEAP_YEAR_CYCLE - (year + 99) / YEARS_IN_CENTURY
+            + (year + 399) / YEARS_IN_LEAP_YEAR_EPICYCLE;
+    } else {
+        total -= year / -YEARS_IN_LEAP_YEAR_CYCLE - year / -YEARS_IN_CENTURY
+            + year / -YEARS_IN_LEAP_YEAR_EPICYCLE;
+    }
+    total += (367 * month - 362) / 12;
+    total += day as i64 - 1;
+    if month > 2 {
+        total -= 1;
+        if !is_leap_year(year) {
+            total -= 1;
+        }
+    }
+    total - EPOCH_OFFSET_FROM_ZERO_DAYS
+}
+
+/// The year, month and day of the given number of days since '1970-01-01'.
+pub const fn date_of_epoch_day(epoch_day: i64) -> (i64, u32, u32) {
+    // Shift to a year starting on March 1st, so the leap day is the last day of the year.
+    let mut zero_day = epoch_day + EPOCH_OFFSET_FROM_ZERO_DAYS - 60;
+    let mut adjust = 0;
+    if zero_day < 0 {
+        let adjust_cycles = (zero_day + 1) / DAYS_IN_LEAP_YEAR_EPICYCLE - 1;
+        adjust = adjust_cycles * YEARS_IN_LEAP_YEAR_EPICYCLE;
+        zero_day -= adjust_cycles * DAYS_IN_LEAP_YEAR_EPICYCLE;
+    }
+    let mut year_estimate =
+        (YEARS_IN_LEAP_YEAR_EPICYCLE * zero_day + 591) / DAYS_IN_LEAP_YEAR_EPICYCLE;
+    let mut day_of_year_estimate = zero_day - days_before_march_year(year_estimate);
+    if day_of_year_estimate < 0 {
+        year_estimate -= 1;
+        day_of_year_estimate = zero_day - days_before_march_year(year_estimate);
+    }
+    year_estimate += adjust;
+
+    let march_month = (day_of_year_estimate * 5 + 2) / 153;
+    let month = (march_month + 2) % 12 + 1;
+    let day = day_of_year_estimate - (march_month * 306 + 5) / 10 + 1;
+    year_estimate += march_month / 10;
+
+    (year_estimate, month as u32, day as u32)
+}
+
+/// The first day of week 1 of a year or month starting on the given number of days since '1970-01-01',
+/// for weeks starting on `first_day_of_week` (1 for Monday to 7 for Sunday),
+/// where week 1 is the first with at least `minimal_days` days in the year or month.
+pub const fn first_week_start(
+    first_epoch_day: i64,
+    first_day_of_week: i64,
+    minimal_days: i64,
+) -> i64 {
+    // '1970-01-01' was a Thursday.
+    let days_into_week =
+        (first_epoch_day + 3 - (first_day_of_week - 1)).rem_euclid(DAYS_IN_WEEK_ISO);
+    let start = first_epoch_day - days_into_week;
+    if DAYS_IN_WEEK_ISO - days_into_week >= minimal_days {
+        start
+    } else {
+        start + DAYS_IN_WEEK_ISO
+    }
+}
+
+/// The week-based year and week of the year of the given number of days since '1970-01-01',
+/// for weeks as in [`first_week_start`].
+pub const fn week_of_epoch_day(
+    epoch_day: i64,
+    first_day_of_week: i64,
+    minimal_days: i64,
+) -> (i64, i64) {
+    let (mut year, _, _) = date_of_epoch_day(epoch_day);
+    if epoch_day < first_week_start(epoch_day_of(year, 1, 1), first_day_of_week, minimal_days) {
+        year -= 1;
+    } else if epoch_day
+        >= first_week_start(
+            epoch_day_of(year + 1, 1, 1),
+            first_day_of_week,
+            minimal_days,
+        )
+    {
+        year += 1;
+    }
+    let start = first_week_start(epoch_day_of(year, 1, 1), first_day_of_week, minimal_days);
+    (year, (epoch_day - start) / DAYS_IN_WEEK_ISO + 1)
+}
+
+const fn days_before_march_year(year: i64) -> i64 {
+    DAYS_IN_YEAR * year + year / YEARS_IN_LEAP_YEAR_CYCLE - year / YEARS_IN_CENTURY
+        + year / YEARS_IN_LEAP_YEAR_EPICYCLE
+}
@@ -0,0 +1,94 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::calendar::*;
+
+const MAX_EPOCH_DAY: i64 = MAX_INSTANT_YEAR * DAYS_IN_LONG_YEAR;
+
+#[test]
+fn epoch_day_of_known_dates() {
+    assert_eq!(0, epoch_day_of(1970, 1, 1));
+    assert_eq!(11_016, epoch_day_of(2000, 2, 29));
+    assert_eq!(11_017, epoch_day_of(2000, 3, 1));
+    assert_eq!(-1, epoch_day_of(1969, 12, 31));
+    assert_eq!(-EPOCH_OFFSET_FROM_ZERO_DAYS, epoch_day_of(0, 1, 1));
+}
+
+#[test]
+fn date_of_epoch_day_known_dates() {
+    assert_eq!((1970, 1, 1), date_of_epoch_day(0));
+    assert_eq!((2000, 2, 29), date_of_epoch_day(11_016));
+    assert_eq!((1969, 12, 31), date_of_epoch_day(-1));
+    assert_eq!(
+        (-1, 12, 31),
+        date_of_epoch_day(-EPOCH_OFFSET_FROM_ZERO_DAYS - 1)
+    );
+}
+
+proptest! {
+    #[test]
+    fn epoch_day_round_trip(epoch_day in -MAX_EPOCH_DAY..=MAX_EPOCH_DAY) {
+        let (year, month, day) = date_of_epoch_day(epoch_day);
+
+        prop_assert!((1..=12).contains(&month));
+        prop_assert!(day >= 1 && day <= days_in_month(year, month));
+        prop_assert_eq!(epoch_day, epoch_day_of(year, month, day));
+    }
+}
+
+proptest! {
+    #[test]
+    fn date_of_epoch_day_is_consecutive(epoch_day in -MAX_EPOCH_DAY..MAX_EPOCH_DAY) {
+        let (year, month, day) = date_of_epoch_day(epoch_day);
+        let next = date_of_epoch_day(epoch_day + 1);
+
+        let expected = if day < days_in_month(year, month) {
+            (year, month, day + 1)
+        } else if month < 12 {
+            (year, month + 1, 1)
+        } else {
+            (year + 1, 1, 1)
+        };
+
+        prop_assert_eq!(expected, next);
+    }
+}
+
+proptest! {
+    #[test]
+    fn days_in_year_matches_epoch_days(year in -MAX_INSTANT_YEAR..MAX_INSTANT_YEAR) {
+        prop_assert_eq!(days_in_year(year), epoch_day_of(year + 1, 1, 1) - epoch_day_of(year, 1, 1));
+    }
+}
+
+#[test]
+fn week_of_epoch_day_known_dates() {
+    assert_eq!(
+        (2004, 53),
+        week_of_epoch_day(epoch_day_of(2005, 1, 1), 1, 4)
+    );
+    assert_eq!(
+        (2009, 1),
+        week_of_epoch_day(epoch_day_of(2008, 12, 29), 1, 4)
+    );
+    assert_eq!((2005, 1), week_of_epoch_day(epoch_day_of(2005, 1, 1), 7, 1));
+    assert_eq!((2005, 2), week_of_epoch_day(epoch_day_of(2005, 1, 2), 7, 1));
+}
+
+proptest! {
+    #[test]
+    fn week_of_epoch_day_is_within_its_year(
+        epoch_day in -MAX_EPOCH_DAY..=MAX_EPOCH_DAY,
+        first_day_of_week in 1..=7i64,
+        minimal_days in 1..=7i64,
+    ) {
+        let (year, week) = week_of_epoch_day(epoch_day, first_day_of_week, minimal_days);
+        let start = first_week_start(epoch_day_of(year, 1, 1), first_day_of_week, minimal_days);
+        let next = first_week_start(epoch_day_of(year + 1, 1, 1), first_day_of_week, minimal_days);
+
+        prop_assert!((1..=53).contains(&week));
+        prop_assert!(start + (week - 1) * 7 <= epoch_day && epoch_day < start + week * 7);
+        prop_assert!(epoch_day < next);
+    }
+}
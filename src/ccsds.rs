@@ -0,0 +1,779 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::calendar::*;
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::util::const_expect;
+use crate::{DateTimeError, Instant, LocalDate, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod time_codes;
+
+// Binary time codes from CCSDS 301.0-B-4, "Time Code Formats".
+
+const CUC_LEVEL_1: u8 = 0b001;
+const CUC_LEVEL_2: u8 = 0b010;
+const CDS_CODE: u8 = 0b100;
+const CCS_CODE: u8 = 0b101;
+const EXTENSION: u8 = 0b1000_0000;
+const MAX_CUC_BASIC_COARSE_OCTETS: u32 = 4;
+const MAX_CUC_BASIC_FINE_OCTETS: u32 = 3;
+const MAX_CUC_COARSE_OCTETS: u32 = 7;
+const MAX_CUC_FINE_OCTETS: u32 = 10;
+const MAX_CCS_SUBSECOND_OCTETS: u32 = 6;
+const MILLISECOND_OCTETS: usize = 4;
+const PICOSECONDS_IN_NANOSECOND: u32 = 1_000;
+const BITS_IN_OCTET: u32 = 8;
+const BCD_DIGITS_IN_OCTET: u32 = 2;
+const NANOSECOND_DIGITS: u32 = 9;
+
+/// The format of a CCSDS Unsegmented Time Code (CUC): a binary count of seconds and fractions of a second.
+///
+/// The count is on the TAI time scale, from 1958-01-01 (level 1) or an agency-defined epoch (level 2).
+/// The coarse (whole seconds) part is 1 to 7 octets, and the fine (fractional) part is 0 to 10 octets,
+/// each octet of which is a further 1/256th of the one before.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CucFormat {
+    coarse_octets: u8,
+    fine_octets: u8,
+    epoch: Instant,
+    agency_epoch: bool,
+}
+
+/// The format of a CCSDS Day Segmented Time Code (CDS): a count of days, milliseconds of the day,
+/// and optionally a finer sub-millisecond part.
+///
+/// The code is on the UTC time scale, so the millisecond of day reaches 86,400,999 during a leap second.
+/// Days are counted from 1958-01-01 (level 1) or an agency-defined epoch (level 2).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CdsFormat {
+    day_octets: u8,
+    resolution: CdsResolution,
+    epoch: LocalDate,
+    agency_epoch: bool,
+}
+
+/// The resolution of the sub-millisecond part of a CDS time code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CdsResolution {
+    /// No sub-millisecond part.
+    Milliseconds,
+    /// Two octets of microseconds within the millisecond.
+    Microseconds,
+    /// Four octets of picoseconds within the millisecond.
+    Picoseconds,
+}
+
+/// The format of a CCSDS Calendar Segmented Time Code (CCS): a UTC calendar date and time, in binary coded decimal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CcsFormat {
+    calendar: CcsCalendar,
+    subsecond_octets: u8,
+}
+
+/// How a CCS time code gives the date.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CcsCalendar {
+    /// The month and day of the month.
+    MonthDay,
+    /// The day of the year.
+    DayOfYear,
+}
+
+/// An error decoding a CCSDS time code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DecodeCcsdsError {
+    reason: &'static str,
+}
+
+impl CucFormat {
+    /// Constant for the CCSDS epoch, '1958-01-01 00:00:00 TAI'.
+    pub const CCSDS_EPOCH: Instant =
+        Instant::of_epoch_second(epoch_day_of(1958, 1, 1) * SECONDS_IN_DAY);
+
+    /// Obtains a level 1 format, counting from the CCSDS epoch.
+    ///
+    /// # Parameters
+    ///  - `coarse_octets`: the octets of whole seconds, from 1 to 7.
+    ///  - `fine_octets`: the octets of fractional seconds, from 0 to 10.
+    ///
+    /// # Panics
+    /// - if either octet count is out of range.
+    pub const fn of(coarse_octets: u32, fine_octets: u32) -> CucFormat {
+        const_expect!(
+            CucFormat::of_checked(coarse_octets, fine_octets),
+            "octet count out of range"
+        )
+    }
+
+    /// Obtains a level 1 format, or an error if either octet count is out of range.
+    ///
+    /// # Parameters
+    ///  - `coarse_octets`: the octets of whole seconds, from 1 to 7.
+    ///  - `fine_octets`: the octets of fractional seconds, from 0 to 10.
+    pub fn try_of(coarse_octets: u32, fine_octets: u32) -> Result<CucFormat, DateTimeError> {
+        CucFormat::of_checked(coarse_octets, fine_octets).ok_or(INVALID_OCTET_COUNT)
+    }
+
+    const fn of_checked(coarse_octets: u32, fine_octets: u32) -> Option<CucFormat> {
+        if coarse_octets < 1
+            || coarse_octets > MAX_CUC_COARSE_OCTETS
+            || fine_octets > MAX_CUC_FINE_OCTETS
+        {
+            return None;
+        }
+        Some(CucFormat {
+            coarse_octets: coarse_octets as u8,
+            fine_octets: fine_octets as u8,
+            epoch: CucFormat::CCSDS_EPOCH,
+            agency_epoch: false,
+        })
+    }
+
+    /// Returns a copy of this format as level 2, counting from an agency-defined epoch.
+    ///
+    /// # Parameters
+    ///  - `epoch`: the instant the count starts from.
+    pub const fn with_agency_epoch(&self, epoch: Instant) -> CucFormat {
+        CucFormat {
+            coarse_octets: self.coarse_octets,
+            fine_octets: self.fine_octets,
+            epoch,
+            agency_epoch: true,
+        }
+    }
+
+    /// Reads the format from a preamble (P-field) of one or two octets.
+    ///
+    /// # Parameters
+    ///  - `preamble`: the octets starting with the preamble; any octets after it are ignored.
+    ///  - `agency_epoch`: the epoch to use for a level 2 time code.
+    ///
+    /// # Returns
+    /// The format and the length of the preamble,
+    /// or an error if the preamble is not for a CUC, or is level 2 and no agency epoch was given.
+    pub fn of_preamble(
+        preamble: &[u8],
+        agency_epoch: Option<Instant>,
+    ) -> Result<(CucFormat, usize), DecodeCcsdsError> {
+        let first = *preamble.first().ok_or(TRUNCATED)?;
+        let (mut coarse_octets, mut fine_octets) =
+            ((first >> 2 & 0b11) as u32 + 1, (first & 0b11) as u32);
+        let mut length = 1;
+        if first & EXTENSION != 0 {
+            let second = *preamble.get(1).ok_or(TRUNCATED)?;
+            if second & EXTENSION != 0 {
+                return Err(error("unsupported preamble extension"));
+            }
+            coarse_octets += (second >> 5 & 0b11) as u32;
+            fine_octets += (second >> 2 & 0b111) as u32;
+            length = 2;
+        }
+
+        let format = CucFormat::of(coarse_octets, fine_octets);
+        match time_code_id(first) {
+            CUC_LEVEL_1 => Ok((format, length)),
+            CUC_LEVEL_2 => agency_epoch
+                .map(|epoch| (format.with_agency_epoch(epoch), length))
+                .ok_or_else(|| error("agency epoch required")),
+            _ => Err(error("not a CUC preamble")),
+        }
+    }
+
+    /// Gets the number of octets of whole seconds.
+    pub const fn coarse_octets(&self) -> u32 {
+        self.coarse_octets as u32
+    }
+
+    /// Gets the number of octets of fractional seconds.
+    pub const fn fine_octets(&self) -> u32 {
+        self.fine_octets as u32
+    }
+
+    /// Gets the epoch the count starts from.
+    pub const fn epoch(&self) -> Instant {
+        self.epoch
+    }
+
+    /// Checks if the count starts from an agency-defined epoch (level 2), rather than the CCSDS epoch.
+    pub const fn is_agency_epoch(&self) -> bool {
+        self.agency_epoch
+    }
+
+    /// Gets the length of the time code (T-field) in octets.
+    pub const fn length(&self) -> usize {
+        self.coarse_octets as usize + self.fine_octets as usize
+    }
+
+    /// Encodes the preamble (P-field) describing this format.
+    pub fn preamble(&self) -> Vec<u8> {
+        let id = if self.agency_epoch {
+            CUC_LEVEL_2
+        } else {
+            CUC_LEVEL_1
+        };
+        let basic_coarse = self.coarse_octets().min(MAX_CUC_BASIC_COARSE_OCTETS);
+        let basic_fine = self.fine_octets().min(MAX_CUC_BASIC_FINE_OCTETS);
+        let first = (id << 4) | ((basic_coarse - 1) << 2) as u8 | basic_fine as u8;
+
+        if basic_coarse == self.coarse_octets() && basic_fine == self.fine_octets() {
+            vec![first]
+        } else {
+            let extra_coarse = self.coarse_octets() - basic_coarse;
+            let extra_fine = self.fine_octets() - basic_fine;
+            vec![
+                EXTENSION | first,
+                ((extra_coarse << 5) | (extra_fine << 2)) as u8,
+            ]
+        }
+    }
+
+    /// Encodes an instant as a time code (T-field).
+    ///
+    /// The fraction of the second is truncated to the resolution of the fine octets.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to encode.
+    ///
+    /// # Returns
+    /// The time code, or `None` if the instant is before the epoch, or too late to fit in the coarse octets.
+    pub fn encode(&self, instant: Instant) -> Option<Vec<u8>> {
+        let elapsed = total_nanos(instant.epoch_second(), instant.nano())
+            - total_nanos(self.epoch.epoch_second(), self.epoch.nano());
+        let seconds = elapsed.div_euclid(NANOSECONDS_IN_SECOND as i128);
+        if seconds < 0 || seconds >> (BITS_IN_OCTET * self.coarse_octets()) != 0 {
+            return None;
+        }
+        let nanos = elapsed.rem_euclid(NANOSECONDS_IN_SECOND as i128) as u128;
+        let fine = (nanos << (BITS_IN_OCTET * self.fine_octets())) / NANOSECONDS_IN_SECOND as u128;
+
+        let mut code = Vec::with_capacity(self.length());
+        write_octets(&mut code, seconds as u128, self.coarse_octets as usize);
+        write_octets(&mut code, fine, self.fine_octets as usize);
+        Some(code)
+    }
+
+    /// Decodes a time code (T-field) to an instant.
+    ///
+    /// The fraction of the second is rounded to the nearest nanosecond.
+    ///
+    /// # Parameters
+    ///  - `code`: the octets of the time code, without a preamble.
+    ///
+    /// # Returns
+    /// The instant, or an error if the time code is the wrong length, or the instant would overflow.
+    pub fn decode(&self, code: &[u8]) -> Result<Instant, DecodeCcsdsError> {
+        check_length(code, self.length())?;
+        let (coarse, fine) = code.split_at(self.coarse_octets as usize);
+        let seconds = read_octets(coarse) as i64;
+        let bits = BITS_IN_OCTET * self.fine_octets();
+        let nanos =
+            (read_octets(fine) * NANOSECONDS_IN_SECOND as u128 + ((1 << bits) >> 1)) >> bits;
+
+        self.epoch
+            .epoch_second()
+            .checked_add(seconds)
+            .and_then(|epoch_second| {
+                of_seconds_and_adjustment_checked(
+                    epoch_second,
+                    self.epoch.nano() as i64 + nanos as i64,
+                )
+            })
+            .map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos as i64))
+            .ok_or(OVERFLOW)
+    }
+}
+
+impl CdsFormat {
+    /// Constant for the CCSDS epoch, '1958-01-01'.
+    pub const CCSDS_EPOCH: LocalDate = LocalDate::of(1958, 1, 1);
+
+    /// Obtains a level 1 format, counting days from the CCSDS epoch.
+    ///
+    /// # Parameters
+    ///  - `day_octets`: the octets of days, 2 or 3.
+    ///  - `resolution`: the resolution of the sub-millisecond part.
+    ///
+    /// # Panics
+    /// - if the day octets are out of range.
+    pub const fn of(day_octets: u32, resolution: CdsResolution) -> CdsFormat {
+        const_expect!(
+            CdsFormat::of_checked(day_octets, resolution),
+            "octet count out of range"
+        )
+    }
+
+    /// Obtains a level 1 format, or an error if the day octets are out of range.
+    ///
+    /// # Parameters
+    ///  - `day_octets`: the octets of days, 2 or 3.
+    ///  - `resolution`: the resolution of the sub-millisecond part.
+    pub fn try_of(day_octets: u32, resolution: CdsResolution) -> Result<CdsFormat, DateTimeError> {
+        CdsFormat::of_checked(day_octets, resolution).ok_or(INVALID_OCTET_COUNT)
+    }
+
+    const fn of_checked(day_octets: u32, resolution: CdsResolution) -> Option<CdsFormat> {
+        if day_octets != 2 && day_octets != 3 {
+            return None;
+        }
+        Some(CdsFormat {
+            day_octets: day_octets as u8,
+            resolution,
+            epoch: CdsFormat::CCSDS_EPOCH,
+            agency_epoch: false,
+        })
+    }
+
+    /// Returns a copy of this format as level 2, counting days from an agency-defined epoch.
+    ///
+    /// # Parameters
+    ///  - `epoch`: the (UTC) date the count starts from.
+    pub const fn with_agency_epoch(&self, epoch: LocalDate) -> CdsFormat {
+        CdsFormat {
+            day_octets: self.day_octets,
+            resolution: self.resolution,
+            epoch,
+            agency_epoch: true,
+        }
+    }
+
+    /// Reads the format from a preamble (P-field) of one octet.
+    ///
+    /// # Parameters
+    ///  - `preamble`: the octets starting with the preamble; any octets after it are ignored.
+    ///  - `agency_epoch`: the epoch to use for a level 2 time code.
+    ///
+    /// # Returns
+    /// The format and the length of the preamble,
+    /// or an error if the preamble is not for a CDS, or is level 2 and no agency epoch was given.
+    pub fn of_preamble(
+        preamble: &[u8],
+        agency_epoch: Option<LocalDate>,
+    ) -> Result<(CdsFormat, usize), DecodeCcsdsError> {
+        let first = *preamble.first().ok_or(TRUNCATED)?;
+        if first & EXTENSION != 0 || time_code_id(first) != CDS_CODE {
+            return Err(error("not a CDS preamble"));
+        }
+        let resolution = match first & 0b11 {
+            0b00 => CdsResolution::Milliseconds,
+            0b01 => CdsResolution::Microseconds,
+            0b10 => CdsResolution::Picoseconds,
+            _ => return Err(error("reserved sub-millisecond resolution")),
+        };
+        let format = CdsFormat::of(2 + (first >> 2 & 1) as u32, resolution);
+        if first >> 3 & 1 == 0 {
+            Ok((format, 1))
+        } else {
+            agency_epoch
+                .map(|epoch| (format.with_agency_epoch(epoch), 1))
+                .ok_or_else(|| error("agency epoch required"))
+        }
+    }
+
+    /// Gets the number of octets of days.
+    pub const fn day_octets(&self) -> u32 {
+        self.day_octets as u32
+    }
+
+    /// Gets the resolution of the sub-millisecond part.
+    pub const fn resolution(&self) -> CdsResolution {
+        self.resolution
+    }
+
+    /// Gets the date the count of days starts from.
+    pub const fn epoch(&self) -> LocalDate {
+        self.epoch
+    }
+
+    /// Checks if the count starts from an agency-defined epoch (level 2), rather than the CCSDS epoch.
+    pub const fn is_agency_epoch(&self) -> bool {
+        self.agency_epoch
+    }
+
+    /// Gets the length of the time code (T-field) in octets.
+    pub const fn length(&self) -> usize {
+        self.day_octets as usize + MILLISECOND_OCTETS + self.submillisecond_octets()
+    }
+
+    const fn submillisecond_octets(&self) -> usize {
+        match self.resolution {
+            CdsResolution::Milliseconds => 0,
+            CdsResolution::Microseconds => 2,
+            CdsResolution::Picoseconds => 4,
+        }
+    }
+
+    /// Encodes the preamble (P-field) describing this format.
+    pub fn preamble(&self) -> Vec<u8> {
+        let resolution = match self.resolution {
+            CdsResolution::Milliseconds => 0b00,
+            CdsResolution::Microseconds => 0b01,
+            CdsResolution::Picoseconds => 0b10,
+        };
+        vec![
+            (CDS_CODE << 4)
+                | ((self.agency_epoch as u8) << 3)
+                | ((self.day_octets - 2) << 2)
+                | resolution,
+        ]
+    }
+
+    /// Encodes an instant as a time code (T-field).
+    ///
+    /// The fraction of the millisecond is truncated to the resolution of the format.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to encode.
+    ///  - `table`: the table used to convert to UTC.
+    ///
+    /// # Returns
+    /// The time code, or `None` if UTC is not defined at the instant,
+    /// or the day is before the epoch or too late to fit in the day octets.
+    pub fn encode(&self, instant: Instant, table: &TaiUtcTable) -> Option<Vec<u8>> {
+        let utc = table.to_utc(instant)?;
+        let day = utc.modified_julian_day() - modified_julian_day_of(self.epoch);
+        if day < 0 || day >> (BITS_IN_OCTET * self.day_octets()) != 0 {
+            return None;
+        }
+        let nano_of_millisecond = (utc.nano_of_day() % NANOSECONDS_IN_MILLISECOND) as u128;
+
+        let mut code = Vec::with_capacity(self.length());
+        write_octets(&mut code, day as u128, self.day_octets as usize);
+        write_octets(
+            &mut code,
+            (utc.nano_of_day() / NANOSECONDS_IN_MILLISECOND) as u128,
+            MILLISECOND_OCTETS,
+        );
+        match self.resolution {
+            CdsResolution::Milliseconds => {}
+            CdsResolution::Microseconds => write_octets(&mut code, nano_of_millisecond / 1_000, 2),
+            CdsResolution::Picoseconds => write_octets(
+                &mut code,
+                nano_of_millisecond * PICOSECONDS_IN_NANOSECOND as u128,
+                4,
+            ),
+        }
+        Some(code)
+    }
+
+    /// Decodes a time code (T-field) to an instant.
+    ///
+    /// Picoseconds are truncated to the nanosecond.
+    ///
+    /// # Parameters
+    ///  - `code`: the octets of the time code, without a preamble.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant, or an error if the time code is the wrong length, a segment is out of range,
+    /// or the time does not exist in UTC.
+    pub fn decode(&self, code: &[u8], table: &TaiUtcTable) -> Result<Instant, DecodeCcsdsError> {
+        check_length(code, self.length())?;
+        let (day, rest) = code.split_at(self.day_octets as usize);
+        let (millisecond, submillisecond) = rest.split_at(MILLISECOND_OCTETS);
+
+        let millisecond = read_octets(millisecond) as i64;
+        if millisecond >= (SECONDS_IN_DAY + 1) * MILLISECONDS_IN_SECOND {
+            return Err(error("millisecond of day out of range"));
+        }
+        let submillisecond = read_octets(submillisecond) as i64;
+        let nano_of_millisecond = match self.resolution {
+            CdsResolution::Milliseconds => 0,
+            CdsResolution::Microseconds if submillisecond < 1_000 => submillisecond * 1_000,
+            CdsResolution::Picoseconds if submillisecond < 1_000_000_000 => {
+                submillisecond / PICOSECONDS_IN_NANOSECOND as i64
+            }
+            _ => return Err(error("sub-millisecond out of range")),
+        };
+
+        let utc = UtcInstant::of_modified_julian_day(
+            modified_julian_day_of(self.epoch) + read_octets(day) as i64,
+            millisecond * NANOSECONDS_IN_MILLISECOND + nano_of_millisecond,
+        );
+        table.to_instant(utc).ok_or(NOT_UTC)
+    }
+}
+
+impl CcsFormat {
+    /// Obtains a format.
+    ///
+    /// # Parameters
+    ///  - `calendar`: how the date is given.
+    ///  - `subsecond_octets`: the octets of fractional seconds, from 0 to 6, each holding two decimal digits.
+    ///
+    /// # Panics
+    /// - if the subsecond octets are out of range.
+    pub const fn of(calendar: CcsCalendar, subsecond_octets: u32) -> CcsFormat {
+        const_expect!(
+            CcsFormat::of_checked(calendar, subsecond_octets),
+            "octet count out of range"
+        )
+    }
+
+    /// Obtains a format, or an error if the subsecond octets are out of range.
+    ///
+    /// # Parameters
+    ///  - `calendar`: how the date is given.
+    ///  - `subsecond_octets`: the octets of fractional seconds, from 0 to 6, each holding two decimal digits.
+    pub fn try_of(
+        calendar: CcsCalendar,
+        subsecond_octets: u32,
+    ) -> Result<CcsFormat, DateTimeError> {
+        CcsFormat::of_checked(calendar, subsecond_octets).ok_or(INVALID_OCTET_COUNT)
+    }
+
+    const fn of_checked(calendar: CcsCalendar, subsecond_octets: u32) -> Option<CcsFormat> {
+        if subsecond_octets > MAX_CCS_SUBSECOND_OCTETS {
+            return None;
+        }
+        Some(CcsFormat {
+            calendar,
+            subsecond_octets: subsecond_octets as u8,
+        })
+    }
+
+    /// Reads the format from a preamble (P-field) of one octet.
+    ///
+    /// # Parameters
+    ///  - `preamble`: the octets starting with the preamble; any octets after it are ignored.
+    ///
+    /// # Returns
+    /// The format and the length of the preamble, or an error if the preamble is not for a CCS.
+    pub fn of_preamble(preamble: &[u8]) -> Result<(CcsFormat, usize), DecodeCcsdsError> {
+        let first = *preamble.first().ok_or(TRUNCATED)?;
+        if first & EXTENSION != 0 || time_code_id(first) != CCS_CODE {
+            return Err(error("not a CCS preamble"));
+        }
+        let subsecond_octets = (first & 0b111) as u32;
+        if subsecond_octets > MAX_CCS_SUBSECOND_OCTETS {
+            return Err(error("reserved subsecond resolution"));
+        }
+        let calendar = if first >> 3 & 1 == 0 {
+            CcsCalendar::MonthDay
+        } else {
+            CcsCalendar::DayOfYear
+        };
+        Ok((CcsFormat::of(calendar, subsecond_octets), 1))
+    }
+
+    /// Gets how the date is given.
+    pub const fn calendar(&self) -> CcsCalendar {
+        self.calendar
+    }
+
+    /// Gets the number of octets of fractional seconds.
+    pub const fn subsecond_octets(&self) -> u32 {
+        self.subsecond_octets as u32
+    }
+
+    /// Gets the length of the time code (T-field) in octets.
+    pub const fn length(&self) -> usize {
+        // Year, month and day (or day of year), hour, minute, second.
+        7 + self.subsecond_octets as usize
+    }
+
+    /// Encodes the preamble (P-field) describing this format.
+    pub fn preamble(&self) -> Vec<u8> {
+        let calendar = match self.calendar {
+            CcsCalendar::MonthDay => 0,
+            CcsCalendar::DayOfYear => 1,
+        };
+        vec![(CCS_CODE << 4) | (calendar << 3) | self.subsecond_octets]
+    }
+
+    /// Encodes an instant as a time code (T-field).
+    ///
+    /// The fraction of the second is truncated to the resolution of the subsecond octets.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to encode.
+    ///  - `table`: the table used to convert to UTC.
+    ///
+    /// # Returns
+    /// The time code, or `None` if UTC is not defined at the instant, or the year is after 9999.
+    pub fn encode(&self, instant: Instant, table: &TaiUtcTable) -> Option<Vec<u8>> {
+        let utc = table.to_utc(instant)?;
+        let epoch_day = utc.modified_julian_day() - MODIFIED_JULIAN_DAY_OF_EPOCH;
+        let (year, month, day) = date_of_epoch_day(epoch_day);
+        if year > 9999 {
+            return None;
+        }
+        let second_of_day = utc.nano_of_day() / NANOSECONDS_IN_SECOND;
+        let (hour, minute, second) = if second_of_day >= SECONDS_IN_DAY {
+            (23, 59, SECONDS_IN_MINUTE + second_of_day - SECONDS_IN_DAY)
+        } else {
+            (
+                second_of_day / SECONDS_IN_HOUR,
+                second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR,
+                second_of_day % SECONDS_IN_MINUTE,
+            )
+        };
+
+        let mut code = Vec::with_capacity(self.length());
+        write_bcd(&mut code, year as u64, 2);
+        match self.calendar {
+            CcsCalendar::MonthDay => {
+                write_bcd(&mut code, month as u64, 1);
+                write_bcd(&mut code, day as u64, 1);
+            }
+            CcsCalendar::DayOfYear => {
+                let day_of_year = epoch_day - epoch_day_of(year, 1, 1) + 1;
+                write_bcd(&mut code, day_of_year as u64, 2)
+            }
+        }
+        write_bcd(&mut code, hour as u64, 1);
+        write_bcd(&mut code, minute as u64, 1);
+        write_bcd(&mut code, second as u64, 1);
+
+        let nano = (utc.nano_of_day() % NANOSECONDS_IN_SECOND) as u64;
+        let digits = BCD_DIGITS_IN_OCTET * self.subsecond_octets();
+        let subsecond = if digits <= NANOSECOND_DIGITS {
+            nano / 10u64.pow(NANOSECOND_DIGITS - digits)
+        } else {
+            nano * 10u64.pow(digits - NANOSECOND_DIGITS)
+        };
+        write_bcd(&mut code, subsecond, self.subsecond_octets as usize);
+        Some(code)
+    }
+
+    /// Decodes a time code (T-field) to an instant.
+    ///
+    /// Fractions of a nanosecond are truncated.
+    ///
+    /// # Parameters
+    ///  - `code`: the octets of the time code, without a preamble.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant, or an error if the time code is the wrong length, is not valid binary coded decimal,
+    /// a field is out of range, or the time does not exist in UTC.
+    pub fn decode(&self, code: &[u8], table: &TaiUtcTable) -> Result<Instant, DecodeCcsdsError> {
+        check_length(code, self.length())?;
+        let year = read_bcd(&code[0..2])? as i64;
+        let date = match self.calendar {
+            CcsCalendar::MonthDay => {
+                let (month, day) = (read_bcd(&code[2..3])?, read_bcd(&code[3..4])?);
+                if month < 1
+                    || month > MONTHS_IN_YEAR as u64
+                    || day < 1
+                    || day > days_in_month(year, month as u32) as u64
+                {
+                    return Err(error("date out of range"));
+                }
+                LocalDate::of(year, month as u32, day as u32)
+            }
+            CcsCalendar::DayOfYear => {
+                let day_of_year = read_bcd(&code[2..4])?;
+                if day_of_year < 1 || day_of_year > days_in_year(year) as u64 {
+                    return Err(error("date out of range"));
+                }
+                LocalDate::of_year_day(year, day_of_year as u32)
+            }
+        };
+
+        let (hour, minute, second) = (
+            read_bcd(&code[4..5])? as i64,
+            read_bcd(&code[5..6])? as i64,
+            read_bcd(&code[6..7])? as i64,
+        );
+        let leap_second = (hour, minute, second) == (23, 59, 60);
+        if hour >= HOURS_IN_DAY
+            || minute >= MINUTES_IN_HOUR
+            || (second >= SECONDS_IN_MINUTE && !leap_second)
+        {
+            return Err(error("time out of range"));
+        }
+
+        let subsecond = read_bcd(&code[7..])?;
+        let digits = BCD_DIGITS_IN_OCTET * self.subsecond_octets();
+        let nano = if digits <= NANOSECOND_DIGITS {
+            subsecond * 10u64.pow(NANOSECOND_DIGITS - digits)
+        } else {
+            subsecond / 10u64.pow(digits - NANOSECOND_DIGITS)
+        };
+
+        let utc = UtcInstant::of_modified_julian_day(
+            date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            ((hour * MINUTES_IN_HOUR + minute) * SECONDS_IN_MINUTE + second)
+                * NANOSECONDS_IN_SECOND
+                + nano as i64,
+        );
+        table.to_instant(utc).ok_or(NOT_UTC)
+    }
+}
+
+impl DecodeCcsdsError {
+    /// Gets a description of what was wrong with the time code.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for DecodeCcsdsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CCSDS time code: {}", self.reason)
+    }
+}
+
+impl Error for DecodeCcsdsError {}
+
+const TRUNCATED: DecodeCcsdsError = error("truncated");
+const OVERFLOW: DecodeCcsdsError = error("instant out of range");
+const NOT_UTC: DecodeCcsdsError = error("no such UTC instant");
+const INVALID_OCTET_COUNT: DateTimeError =
+    DateTimeError::InvalidArgument("octet count out of range");
+
+const fn error(reason: &'static str) -> DecodeCcsdsError {
+    DecodeCcsdsError { reason }
+}
+
+const fn time_code_id(preamble: u8) -> u8 {
+    preamble >> 4 & 0b111
+}
+
+const fn modified_julian_day_of(date: LocalDate) -> i64 {
+    date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH
+}
+
+fn check_length(code: &[u8], length: usize) -> Result<(), DecodeCcsdsError> {
+    if code.len() < length {
+        Err(TRUNCATED)
+    } else if code.len() > length {
+        Err(error("unexpected trailing octets"))
+    } else {
+        Ok(())
+    }
+}
+
+fn write_octets(code: &mut Vec<u8>, value: u128, octets: usize) {
+    code.extend(
+        (0..octets)
+            .rev()
+            .map(|octet| (value >> (BITS_IN_OCTET as usize * octet)) as u8),
+    );
+}
+
+fn read_octets(octets: &[u8]) -> u128 {
+    octets
+        .iter()
+        .fold(0, |value, &octet| (value << BITS_IN_OCTET) | octet as u128)
+}
+
+fn write_bcd(code: &mut Vec<u8>, value: u64, octets: usize) {
+    code.extend((0..octets).rev().map(|octet| {
+        let pair = value / 100u64.pow(octet as u32) % 100;
+        (((pair / 10) << 4) | (pair % 10)) as u8
+    }));
+}
+
+fn read_bcd(octets: &[u8]) -> Result<u64, DecodeCcsdsError> {
+    octets.iter().try_fold(0, |value, &octet| {
+        let (high, low) = ((octet >> 4) as u64, (octet & 0xF) as u64);
+        if high > 9 || low > 9 {
+            Err(error("invalid binary coded decimal"))
+        } else {
+            Ok(value * 100 + high * 10 + low)
+        }
+    })
+}
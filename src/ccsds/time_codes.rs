@@ -0,0 +1,360 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{
+    CcsCalendar, CcsFormat, CdsFormat, CdsResolution, CucFormat, Instant, LocalDate, TaiUtcTable,
+    UtcInstant,
+};
+
+fn utc(date: LocalDate, nano_of_day: i64) -> Instant {
+    TaiUtcTable::default()
+        .to_instant(UtcInstant::of_modified_julian_day(
+            date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            nano_of_day,
+        ))
+        .unwrap()
+}
+
+// Halfway through the leap second '2016-12-31T23:59:60.5Z'.
+fn leap_second() -> Instant {
+    utc(
+        LocalDate::of(2016, 12, 31),
+        NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND / 2,
+    )
+}
+
+#[test]
+fn cuc_epoch() {
+    assert_eq!(-378_691_200, CucFormat::CCSDS_EPOCH.epoch_second());
+}
+
+#[test]
+fn cuc_preamble() {
+    assert_eq!(vec![0x1E], CucFormat::of(4, 2).preamble());
+    assert_eq!(vec![0x10], CucFormat::of(1, 0).preamble());
+    assert_eq!(
+        vec![0x2F],
+        CucFormat::of(4, 3)
+            .with_agency_epoch(Instant::EPOCH)
+            .preamble()
+    );
+    assert_eq!(vec![0x9F, 0x7C], CucFormat::of(7, 10).preamble());
+
+    for &(coarse, fine) in &[(1, 0), (4, 2), (5, 0), (7, 10), (2, 9)] {
+        let format = CucFormat::of(coarse, fine);
+        assert_eq!(
+            Ok((format, format.preamble().len())),
+            CucFormat::of_preamble(&format.preamble(), None)
+        );
+    }
+}
+
+#[test]
+fn cuc_preamble_invalid() {
+    assert_eq!(
+        "agency epoch required",
+        CucFormat::of_preamble(&[0x2F], None).unwrap_err().reason()
+    );
+    assert_eq!(
+        "not a CUC preamble",
+        CucFormat::of_preamble(&[0x41], None).unwrap_err().reason()
+    );
+    assert_eq!(
+        "truncated",
+        CucFormat::of_preamble(&[0x9F], None).unwrap_err().reason()
+    );
+    expect_panic("octet count out of range", || CucFormat::of(0, 0)).unwrap();
+    expect_panic("octet count out of range", || CucFormat::of(1, 11)).unwrap();
+}
+
+#[test]
+fn cuc_encode() {
+    let format = CucFormat::of(4, 2);
+    let instant = Instant::of_epoch_second_and_adjustment(
+        CucFormat::CCSDS_EPOCH.epoch_second() + 0x0102_0304,
+        NANOSECONDS_IN_SECOND / 2,
+    );
+
+    assert_eq!(
+        Some(vec![0x01, 0x02, 0x03, 0x04, 0x80, 0x00]),
+        format.encode(instant)
+    );
+    assert_eq!(
+        Ok(instant),
+        format.decode(&[0x01, 0x02, 0x03, 0x04, 0x80, 0x00])
+    );
+}
+
+#[test]
+fn cuc_agency_epoch() {
+    let format = CucFormat::of(4, 0).with_agency_epoch(Instant::EPOCH);
+
+    assert_eq!(
+        Some(vec![0x00, 0x00, 0x00, 0x2A]),
+        format.encode(Instant::of_epoch_second(42))
+    );
+    assert_eq!(
+        Ok(Instant::of_epoch_second(42)),
+        format.decode(&[0, 0, 0, 42])
+    );
+}
+
+#[test]
+fn cuc_out_of_range() {
+    let format = CucFormat::of(1, 0);
+
+    assert_eq!(None, format.encode(Instant::EPOCH));
+    assert_eq!(
+        None,
+        format.encode(Instant::of_epoch_second_and_adjustment(
+            CucFormat::CCSDS_EPOCH.epoch_second(),
+            -1
+        ))
+    );
+    assert_eq!("truncated", format.decode(&[]).unwrap_err().reason());
+    assert_eq!(
+        "unexpected trailing octets",
+        format.decode(&[0, 0]).unwrap_err().reason()
+    );
+    assert_eq!(
+        "instant out of range",
+        CucFormat::of(7, 0)
+            .with_agency_epoch(Instant::MAX)
+            .decode(&[0, 0, 0, 0, 0, 0, 1])
+            .unwrap_err()
+            .reason()
+    );
+}
+
+proptest! {
+    #[test]
+    fn cuc_round_trip(seconds in 0..1i64 << 32, nanos in 0..NANOSECONDS_IN_SECOND, fine in 4..=10u32) {
+        let format = CucFormat::of(4, fine);
+        let instant = Instant::of_epoch_second_and_adjustment(
+            CucFormat::CCSDS_EPOCH.epoch_second() + seconds,
+            nanos,
+        );
+
+        // Four or more fine octets resolve less than half a nanosecond.
+        prop_assert_eq!(Ok(instant), format.decode(&format.encode(instant).unwrap()));
+    }
+}
+
+#[test]
+fn cds_preamble() {
+    let agency =
+        CdsFormat::of(3, CdsResolution::Picoseconds).with_agency_epoch(LocalDate::of(2000, 1, 1));
+
+    assert_eq!(
+        vec![0x40],
+        CdsFormat::of(2, CdsResolution::Milliseconds).preamble()
+    );
+    assert_eq!(
+        vec![0x41],
+        CdsFormat::of(2, CdsResolution::Microseconds).preamble()
+    );
+    assert_eq!(vec![0x4E], agency.preamble());
+    assert_eq!(
+        Ok((agency, 1)),
+        CdsFormat::of_preamble(&[0x4E], Some(LocalDate::of(2000, 1, 1)))
+    );
+    assert_eq!(
+        "reserved sub-millisecond resolution",
+        CdsFormat::of_preamble(&[0x43], None).unwrap_err().reason()
+    );
+    assert_eq!(
+        "not a CDS preamble",
+        CdsFormat::of_preamble(&[0x1E], None).unwrap_err().reason()
+    );
+}
+
+#[test]
+fn cds_leap_second() {
+    let table = TaiUtcTable::default();
+    let format = CdsFormat::of(2, CdsResolution::Microseconds);
+    // 21,549 days after 1958-01-01, and 86,400,500 milliseconds into the day.
+    let code = [0x54, 0x2D, 0x05, 0x26, 0x5D, 0xF4, 0x00, 0x00];
+
+    assert_eq!(Some(code.to_vec()), format.encode(leap_second(), &table));
+    assert_eq!(Ok(leap_second()), format.decode(&code, &table));
+}
+
+#[test]
+fn cds_resolutions() {
+    let table = TaiUtcTable::default();
+    let instant = utc(LocalDate::of(2020, 1, 1), 1_234_567_891);
+
+    let milliseconds = CdsFormat::of(2, CdsResolution::Milliseconds);
+    let microseconds = CdsFormat::of(2, CdsResolution::Microseconds);
+    let picoseconds = CdsFormat::of(3, CdsResolution::Picoseconds);
+
+    let truncated = |nanos| utc(LocalDate::of(2020, 1, 1), nanos);
+    assert_eq!(
+        Ok(truncated(1_234_000_000)),
+        milliseconds.decode(&milliseconds.encode(instant, &table).unwrap(), &table)
+    );
+    assert_eq!(
+        Ok(truncated(1_234_567_000)),
+        microseconds.decode(&microseconds.encode(instant, &table).unwrap(), &table)
+    );
+    assert_eq!(
+        Ok(instant),
+        picoseconds.decode(&picoseconds.encode(instant, &table).unwrap(), &table)
+    );
+}
+
+#[test]
+fn cds_invalid() {
+    let table = TaiUtcTable::default();
+    let format = CdsFormat::of(2, CdsResolution::Microseconds);
+    let day_2017_12_31 =
+        (LocalDate::of(2017, 12, 31).to_epoch_day() - CdsFormat::CCSDS_EPOCH.to_epoch_day()) as u16;
+    let [high, low] = day_2017_12_31.to_be_bytes();
+
+    // There was no leap second at the end of 2017.
+    assert_eq!(
+        "no such UTC instant",
+        format
+            .decode(&[high, low, 0x05, 0x26, 0x5C, 0x00, 0x00, 0x00], &table)
+            .unwrap_err()
+            .reason()
+    );
+    // UTC was not defined in 1958.
+    assert_eq!(
+        "no such UTC instant",
+        format
+            .decode(&[0, 0, 0, 0, 0, 0, 0, 0], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "millisecond of day out of range",
+        format
+            .decode(&[high, low, 0x05, 0x26, 0x5F, 0xE8, 0x00, 0x00], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "sub-millisecond out of range",
+        format
+            .decode(&[high, low, 0, 0, 0, 0, 0x03, 0xE8], &table)
+            .unwrap_err()
+            .reason()
+    );
+    // Two octets of days run out in 2137.
+    assert_eq!(
+        None,
+        format.encode(utc(LocalDate::of(2200, 1, 1), 0), &table)
+    );
+}
+
+#[test]
+fn ccs_preamble() {
+    assert_eq!(
+        vec![0x51],
+        CcsFormat::of(CcsCalendar::MonthDay, 1).preamble()
+    );
+    assert_eq!(
+        vec![0x5E],
+        CcsFormat::of(CcsCalendar::DayOfYear, 6).preamble()
+    );
+    assert_eq!(
+        Ok((CcsFormat::of(CcsCalendar::DayOfYear, 6), 1)),
+        CcsFormat::of_preamble(&[0x5E])
+    );
+    assert_eq!(
+        "reserved subsecond resolution",
+        CcsFormat::of_preamble(&[0x57]).unwrap_err().reason()
+    );
+    expect_panic("octet count out of range", || {
+        CcsFormat::of(CcsCalendar::MonthDay, 7)
+    })
+    .unwrap();
+}
+
+#[test]
+fn ccs_leap_second() {
+    let table = TaiUtcTable::default();
+    let month_day = CcsFormat::of(CcsCalendar::MonthDay, 1);
+    let day_of_year = CcsFormat::of(CcsCalendar::DayOfYear, 1);
+    let month_day_code = [0x20, 0x16, 0x12, 0x31, 0x23, 0x59, 0x60, 0x50];
+    let day_of_year_code = [0x20, 0x16, 0x03, 0x66, 0x23, 0x59, 0x60, 0x50];
+
+    assert_eq!(
+        Some(month_day_code.to_vec()),
+        month_day.encode(leap_second(), &table)
+    );
+    assert_eq!(Ok(leap_second()), month_day.decode(&month_day_code, &table));
+    assert_eq!(
+        Some(day_of_year_code.to_vec()),
+        day_of_year.encode(leap_second(), &table)
+    );
+    assert_eq!(
+        Ok(leap_second()),
+        day_of_year.decode(&day_of_year_code, &table)
+    );
+}
+
+#[test]
+fn ccs_resolutions() {
+    let table = TaiUtcTable::default();
+    let instant = utc(LocalDate::of(2020, 1, 1), 1_234_567_891);
+
+    for subsecond_octets in 0..=6 {
+        let format = CcsFormat::of(CcsCalendar::MonthDay, subsecond_octets);
+        let digits = (2 * subsecond_octets).min(9);
+        let resolution = 10i64.pow(9 - digits);
+
+        assert_eq!(
+            Ok(utc(
+                LocalDate::of(2020, 1, 1),
+                1_234_567_891 / resolution * resolution
+            )),
+            format.decode(&format.encode(instant, &table).unwrap(), &table)
+        );
+    }
+}
+
+#[test]
+fn ccs_invalid() {
+    let table = TaiUtcTable::default();
+    let format = CcsFormat::of(CcsCalendar::MonthDay, 0);
+
+    assert_eq!(
+        "invalid binary coded decimal",
+        format
+            .decode(&[0x20, 0x1A, 0x01, 0x01, 0x00, 0x00, 0x00], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "date out of range",
+        format
+            .decode(&[0x20, 0x17, 0x02, 0x29, 0x00, 0x00, 0x00], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "time out of range",
+        format
+            .decode(&[0x20, 0x16, 0x12, 0x31, 0x23, 0x58, 0x60], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "no such UTC instant",
+        format
+            .decode(&[0x20, 0x17, 0x12, 0x31, 0x23, 0x59, 0x60], &table)
+            .unwrap_err()
+            .reason()
+    );
+    assert_eq!(
+        "date out of range",
+        CcsFormat::of(CcsCalendar::DayOfYear, 0)
+            .decode(&[0x20, 0x17, 0x03, 0x66, 0x00, 0x00, 0x00], &table)
+            .unwrap_err()
+            .reason()
+    );
+}
@@ -1,8 +1,10 @@
+pub const NANOSECONDS_IN_MICROSECOND: i64 = NANOSECONDS_IN_SECOND / MICROSECONDS_IN_SECOND;
 pub const NANOSECONDS_IN_MILLISECOND: i64 = NANOSECONDS_IN_SECOND / MILLISECONDS_IN_SECOND;
 pub const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
 pub const NANOSECONDS_IN_MINUTE: i64 = SECONDS_IN_MINUTE * NANOSECONDS_IN_SECOND;
 pub const NANOSECONDS_IN_HOUR: i64 = MINUTES_IN_HOUR * NANOSECONDS_IN_MINUTE;
 pub const NANOSECONDS_IN_DAY: i64 = HOURS_IN_DAY * NANOSECONDS_IN_HOUR;
+pub const MICROSECONDS_IN_SECOND: i64 = 1_000_000;
 pub const MILLISECONDS_IN_SECOND: i64 = 1_000;
 pub const SECONDS_IN_MINUTE: i64 = 60;
 pub const SECONDS_IN_HOUR: i64 = SECONDS_IN_MINUTE * MINUTES_IN_HOUR;
@@ -31,8 +33,26 @@ pub const EPOCH_OFFSET_FROM_ZERO_DAYS: i64 = (2000 / YEARS_IN_LEAP_YEAR_EPICYCLE
     // Leap years from 1970 to 2000:
     // 1972, 1976, 1980, 1984, 1988, 1992, 1996
     - 7;
+// Nothing uses this yet, but it completes the offsets from year zero.
+#[allow(dead_code)]
 pub const EPOCH_OFFSET_FROM_ZERO_SECONDS: i64 = EPOCH_OFFSET_FROM_ZERO_DAYS * SECONDS_IN_DAY;
 pub const YEARS_IN_DECADE: i64 = 10;
 pub const YEARS_IN_CENTURY: i64 = 100;
 pub const YEARS_IN_MILLENNIUM: i64 = 1000;
+// As java.time, an era is a billion years.
+pub const YEARS_IN_ERA: i64 = 1_000_000_000;
 pub const DAYS_IN_WEEK_ISO: i64 = 7;
+// Terrestrial Time is defined as TAI + 32.184 seconds.
+pub const TERRESTRIAL_TIME_OFFSET_FROM_TAI_NANOSECONDS: i64 = 32_184_000_000;
+// The Modified Julian Day of '1970-01-01'.
+pub const MODIFIED_JULIAN_DAY_OF_EPOCH: i64 = 40_587;
+// Julian Days start at noon, so the Modified Julian Day epoch is at Julian Day 2,400,000.5,
+// half a day after the start of Julian Day 2,400,000.
+pub const WHOLE_JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH: i64 = 2_400_000;
+pub const JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH: f64 =
+    WHOLE_JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH as f64 + 0.5;
+pub const MAX_YEAR: i64 = MAX_INSTANT_YEAR - 1;
+pub const MIN_YEAR: i64 = -MAX_YEAR;
+pub const MONTHS_IN_YEAR: i64 = 12;
+pub const JULIAN_DAY_OF_EPOCH: f64 =
+    MODIFIED_JULIAN_DAY_OF_EPOCH as f64 + JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH;
@@ -0,0 +1,82 @@
+use crate::constants::*;
+use crate::util::const_expect;
+use crate::{ChronoField, DateTimeError};
+
+#[cfg(test)]
+pub mod factories;
+
+/// A day of the week, such as 'Tuesday'.
+///
+/// Days are numbered following ISO-8601, from 1 (Monday) to 7 (Sunday).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DayOfWeek {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+const DAYS: [DayOfWeek; DAYS_IN_WEEK_ISO as usize] = [
+    DayOfWeek::Monday,
+    DayOfWeek::Tuesday,
+    DayOfWeek::Wednesday,
+    DayOfWeek::Thursday,
+    DayOfWeek::Friday,
+    DayOfWeek::Saturday,
+    DayOfWeek::Sunday,
+];
+
+impl DayOfWeek {
+    /// Obtains a `DayOfWeek` from its ISO-8601 number.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week, from 1 (Monday) to 7 (Sunday).
+    ///
+    /// # Panics
+    /// - if the day of the week is out of range.
+    pub const fn of(day_of_week: u32) -> DayOfWeek {
+        const_expect!(
+            DayOfWeek::of_checked(day_of_week),
+            "day of week out of range"
+        )
+    }
+
+    const fn of_checked(day_of_week: u32) -> Option<DayOfWeek> {
+        if day_of_week < 1 || day_of_week > DAYS_IN_WEEK_ISO as u32 {
+            None
+        } else {
+            Some(DAYS[day_of_week as usize - 1])
+        }
+    }
+
+    /// Obtains a `DayOfWeek` from its ISO-8601 number, or an error if it is out of range.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week, from 1 (Monday) to 7 (Sunday).
+    pub fn try_of(day_of_week: u32) -> Result<DayOfWeek, DateTimeError> {
+        let range = 1..=DAYS_IN_WEEK_ISO;
+        DateTimeError::check(ChronoField::DayOfWeek, day_of_week as i64, range)?;
+        Ok(DayOfWeek::of(day_of_week))
+    }
+
+    /// Obtains the day of the week of a number of days since '1970-01-01', which was a Thursday.
+    pub(crate) const fn of_epoch_day(epoch_day: i64) -> DayOfWeek {
+        DAYS[(epoch_day + DayOfWeek::Thursday as i64 - 1).rem_euclid(DAYS_IN_WEEK_ISO) as usize]
+    }
+
+    /// Gets the ISO-8601 number of the day, from 1 (Monday) to 7 (Sunday).
+    pub const fn value(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the day of the week the given number of days later.
+    ///
+    /// # Parameters
+    ///  - `days`: the days to add, which may be negative.
+    pub const fn plus(&self, days: i64) -> DayOfWeek {
+        DAYS[((*self as i64 - 1 + days.rem_euclid(DAYS_IN_WEEK_ISO)) % DAYS_IN_WEEK_ISO) as usize]
+    }
+}
@@ -0,0 +1,47 @@
+use proptest::prelude::*;
+
+use crate::assert::expect_panic;
+use crate::{DayOfWeek, LocalDate};
+
+#[test]
+fn of() {
+    assert_eq!(DayOfWeek::Monday, DayOfWeek::of(1));
+    assert_eq!(DayOfWeek::Sunday, DayOfWeek::of(7));
+    assert_eq!(3, DayOfWeek::Wednesday.value());
+}
+
+proptest! {
+    #[test]
+    fn of_out_of_range(day_of_week in prop_oneof![Just(0u32), 8..=u32::MAX]) {
+        expect_panic("day of week out of range", || DayOfWeek::of(day_of_week))?;
+    }
+}
+
+proptest! {
+    #[test]
+    fn plus(day_of_week in 1u32..=7, days: i64) {
+        let day = DayOfWeek::of(day_of_week);
+
+        prop_assert_eq!(
+            (day_of_week as i64 - 1 + days).rem_euclid(7) as u32 + 1,
+            day.plus(days).value()
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_date(epoch_day in -1_000_000i64..1_000_000) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        prop_assert_eq!(date.day_of_week().plus(1), date.plus_days(1).day_of_week());
+    }
+}
+
+#[test]
+fn of_known_dates() {
+    assert_eq!(DayOfWeek::Thursday, LocalDate::EPOCH.day_of_week());
+    assert_eq!(DayOfWeek::Tuesday, LocalDate::of(2003, 7, 1).day_of_week());
+    assert_eq!(DayOfWeek::Saturday, LocalDate::of(2000, 1, 1).day_of_week());
+    assert_eq!(DayOfWeek::Monday, LocalDate::of(1, 1, 1).day_of_week());
+}
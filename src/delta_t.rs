@@ -0,0 +1,326 @@
+use crate::calendar::*;
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::util::const_expect;
+use crate::{DateTimeError, Instant};
+
+#[cfg(test)]
+pub mod models;
+
+/// A model of ΔT, the difference between Terrestrial Time (TT) and Universal Time (UT).
+///
+/// ΔT is the consequence of the irregular (and slowing) rotation of the Earth, and is only known from observation.
+/// UTC tracks UT to within a second from 1972 onwards, but before that, and after the last announced leap second,
+/// the only way to relate an `Instant` to the rotation of the Earth is to model ΔT.
+///
+/// All values obtained through a model are **estimates**, not observations.
+/// Their uncertainty grows quickly away from the present: to minutes by the year 1000, and to hours by 2000 BCE.
+pub trait DeltaTModel {
+    /// Gets the modeled value of ΔT, in seconds.
+    ///
+    /// # Parameters
+    ///  - `decimal_year`: the (proleptic Gregorian) year, with the elapsed part of the year as the fraction.
+    fn delta_t(&self, decimal_year: f64) -> f64;
+
+    /// Obtains the modeled Universal Time for the given instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to map.
+    ///
+    /// # Panics
+    /// - if the modeled ΔT would overflow the universal time.
+    fn universal_time_of(&self, instant: Instant) -> ModeledUniversalTime {
+        let tai = (instant.epoch_second(), instant.nano());
+        let offset = |decimal_year| terrestrial_time_offset() - self.delta_t(decimal_year);
+        // ΔT is a function of Universal Time, not of the instant, so iterate towards the fixed point.
+        // Any realistic model changes far slower than time itself, so this converges within a few steps.
+        let mut estimate = shift(tai, offset(decimal_year(tai)));
+        for _ in 0..MAX_ITERATIONS {
+            let next = estimate.and_then(|estimate| shift(tai, offset(decimal_year(estimate))));
+            if next == estimate {
+                break;
+            }
+            estimate = next;
+        }
+        let (epoch_second, nanosecond_of_second) =
+            estimate.expect("delta t would overflow universal time");
+
+        ModeledUniversalTime {
+            epoch_second,
+            nanosecond_of_second,
+        }
+    }
+
+    /// Obtains the instant for the given modeled Universal Time.
+    ///
+    /// # Parameters
+    ///  - `universal_time`: the universal time to map.
+    ///
+    /// # Panics
+    /// - if the modeled ΔT would overflow the instant.
+    fn instant_of(&self, universal_time: ModeledUniversalTime) -> Instant {
+        let ut = (
+            universal_time.epoch_second,
+            universal_time.nanosecond_of_second,
+        );
+        let (epoch_second, nanos) = shift(
+            ut,
+            self.delta_t(decimal_year(ut)) - terrestrial_time_offset(),
+        )
+        .expect("delta t would overflow instant");
+
+        Instant::of_epoch_second_and_adjustment(epoch_second, nanos as i64)
+    }
+}
+
+/// The Espenak–Meeus piecewise polynomial model of ΔT.
+///
+/// These are the polynomials used for the NASA Five Millennium Canon of Solar Eclipses,
+/// fitted to historical observations between -500 and 2005, and extrapolated up to 2150.
+/// Outside of that range the [`MorrisonStephenson`] parabola is used.
+///
+/// [`MorrisonStephenson`]: struct.MorrisonStephenson.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EspenakMeeus;
+
+/// The Morrison–Stephenson long-term parabola model of ΔT.
+///
+/// This models ΔT as `-20 + 32u²` seconds, where `u` is the number of centuries since 1820.
+/// It is a coarse fit intended for dates far from the present in either direction.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MorrisonStephenson;
+
+/// A modeled point in Universal Time (UT1), as seconds since '1970-01-01 00:00:00 UT'.
+///
+/// Unlike an [`Instant`], this is **not** a measured time scale:
+/// it is only as accurate as the [`DeltaTModel`] used to obtain it.
+///
+/// [`Instant`]: struct.Instant.html
+/// [`DeltaTModel`]: trait.DeltaTModel.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ModeledUniversalTime {
+    epoch_second: i64,
+    nanosecond_of_second: u32,
+}
+
+impl ModeledUniversalTime {
+    /// Obtains a universal time using seconds since '1970-01-01 00:00:00 UT'.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    pub const fn of_epoch_second(epoch_seconds: i64) -> ModeledUniversalTime {
+        ModeledUniversalTime {
+            epoch_second: epoch_seconds,
+            nanosecond_of_second: 0,
+        }
+    }
+
+    /// Obtains a universal time using seconds and an adjustment in nanoseconds since '1970-01-01 00:00:00 UT'.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    ///
+    /// # Panics
+    /// - if the adjusted amount of seconds would overflow the universal time.
+    pub const fn of_epoch_second_and_adjustment(
+        epoch_seconds: i64,
+        nano_adjustment: i64,
+    ) -> ModeledUniversalTime {
+        const_expect!(
+            ModeledUniversalTime::of_epoch_second_and_adjustment_checked(
+                epoch_seconds,
+                nano_adjustment
+            ),
+            "nano adjustment would overflow universal time"
+        )
+    }
+
+    /// Obtains a universal time using seconds and an adjustment in nanoseconds since '1970-01-01 00:00:00 UT',
+    /// or an error if the adjusted seconds would overflow the universal time.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    pub fn try_of_epoch_second_and_adjustment(
+        epoch_seconds: i64,
+        nano_adjustment: i64,
+    ) -> Result<ModeledUniversalTime, DateTimeError> {
+        ModeledUniversalTime::of_epoch_second_and_adjustment_checked(epoch_seconds, nano_adjustment)
+            .ok_or(DateTimeError::Overflow)
+    }
+
+    const fn of_epoch_second_and_adjustment_checked(
+        epoch_seconds: i64,
+        nano_adjustment: i64,
+    ) -> Option<ModeledUniversalTime> {
+        match of_seconds_and_adjustment_checked(epoch_seconds, nano_adjustment) {
+            None => None,
+            Some((epoch_second, nanosecond_of_second)) => Some(ModeledUniversalTime {
+                epoch_second,
+                nanosecond_of_second,
+            }),
+        }
+    }
+
+    /// Gets the number of seconds before or after the epoch.
+    pub const fn epoch_second(&self) -> i64 {
+        self.epoch_second
+    }
+
+    /// Gets the number of nanoseconds farther along the timeline in this universal time.
+    pub const fn nano(&self) -> u32 {
+        self.nanosecond_of_second
+    }
+}
+
+impl DeltaTModel for EspenakMeeus {
+    fn delta_t(&self, decimal_year: f64) -> f64 {
+        let y = decimal_year;
+        if y < -500.0 {
+            MorrisonStephenson.delta_t(y)
+        } else if y < 500.0 {
+            let u = y / 100.0;
+            polynomial(
+                u,
+                &[
+                    10583.6,
+                    -1014.41,
+                    33.78311,
+                    -5.952053,
+                    -0.1798452,
+                    0.022174192,
+                    0.0090316521,
+                ],
+            )
+        } else if y < 1600.0 {
+            let u = (y - 1000.0) / 100.0;
+            polynomial(
+                u,
+                &[
+                    1574.2,
+                    -556.01,
+                    71.23472,
+                    0.319781,
+                    -0.8503463,
+                    -0.005050998,
+                    0.0083572073,
+                ],
+            )
+        } else if y < 1700.0 {
+            let t = y - 1600.0;
+            polynomial(t, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0])
+        } else if y < 1800.0 {
+            let t = y - 1700.0;
+            polynomial(
+                t,
+                &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1_174_000.0],
+            )
+        } else if y < 1860.0 {
+            let t = y - 1800.0;
+            polynomial(
+                t,
+                &[
+                    13.72,
+                    -0.332447,
+                    0.0068612,
+                    0.0041116,
+                    -0.00037436,
+                    0.0000121272,
+                    -0.0000001699,
+                    0.000000000875,
+                ],
+            )
+        } else if y < 1900.0 {
+            let t = y - 1860.0;
+            polynomial(
+                t,
+                &[
+                    7.62,
+                    0.5737,
+                    -0.251754,
+                    0.01680668,
+                    -0.0004473624,
+                    1.0 / 233_174.0,
+                ],
+            )
+        } else if y < 1920.0 {
+            let t = y - 1900.0;
+            polynomial(t, &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197])
+        } else if y < 1941.0 {
+            let t = y - 1920.0;
+            polynomial(t, &[21.20, 0.84493, -0.076100, 0.0020936])
+        } else if y < 1961.0 {
+            let t = y - 1950.0;
+            polynomial(t, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0])
+        } else if y < 1986.0 {
+            let t = y - 1975.0;
+            polynomial(t, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+        } else if y < 2005.0 {
+            let t = y - 2000.0;
+            polynomial(
+                t,
+                &[
+                    63.86,
+                    0.3345,
+                    -0.060374,
+                    0.0017275,
+                    0.000651814,
+                    0.00002373599,
+                ],
+            )
+        } else if y < 2050.0 {
+            let t = y - 2000.0;
+            polynomial(t, &[62.92, 0.32217, 0.005589])
+        } else if y < 2150.0 {
+            MorrisonStephenson.delta_t(y) - 0.5628 * (2150.0 - y)
+        } else {
+            MorrisonStephenson.delta_t(y)
+        }
+    }
+}
+
+impl DeltaTModel for MorrisonStephenson {
+    fn delta_t(&self, decimal_year: f64) -> f64 {
+        let u = (decimal_year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    }
+}
+
+const MAX_ITERATIONS: usize = 8;
+
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |total, coefficient| total * x + coefficient)
+}
+
+fn decimal_year((epoch_second, nanos): (i64, u32)) -> f64 {
+    let epoch_day = epoch_second.div_euclid(SECONDS_IN_DAY);
+    let second_of_day = epoch_second.rem_euclid(SECONDS_IN_DAY);
+    let (year, _, _) = date_of_epoch_day(epoch_day);
+    let day_of_year = (epoch_day - epoch_day_of(year, 1, 1)) as f64
+        + (second_of_day as f64 + nanos as f64 / NANOSECONDS_IN_SECOND as f64)
+            / SECONDS_IN_DAY as f64;
+
+    year as f64 + day_of_year / days_in_year(year) as f64
+}
+
+// Moves a timestamp by the given (fractional) amount of seconds.
+fn shift((epoch_second, nanos): (i64, u32), seconds: f64) -> Option<(i64, u32)> {
+    let whole_seconds = seconds.floor();
+    // `as` saturates, so anything out of range would otherwise silently clamp.
+    if !(whole_seconds > i64::MIN as f64 && whole_seconds < i64::MAX as f64) {
+        return None;
+    }
+    let adjustment = ((seconds - whole_seconds) * NANOSECONDS_IN_SECOND as f64).round() as i64;
+
+    epoch_second
+        .checked_add(whole_seconds as i64)
+        .and_then(|seconds| of_seconds_and_adjustment_checked(seconds, nanos as i64 + adjustment))
+}
+
+fn terrestrial_time_offset() -> f64 {
+    TERRESTRIAL_TIME_OFFSET_FROM_TAI_NANOSECONDS as f64 / NANOSECONDS_IN_SECOND as f64
+}
@@ -0,0 +1,102 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{DeltaTModel, EspenakMeeus, Instant, ModeledUniversalTime, MorrisonStephenson};
+
+// Roughly ±10,000 years around the epoch.
+const SECONDS_IN_TEN_MILLENNIA: i64 = 10 * YEARS_IN_MILLENNIUM * DAYS_IN_LONG_YEAR * SECONDS_IN_DAY;
+
+fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        (expected - actual).abs() < 0.01,
+        "expected {}, was {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn espenak_meeus_reference_values() {
+    assert_close(-2.79, EspenakMeeus.delta_t(1900.0));
+    assert_close(29.07, EspenakMeeus.delta_t(1950.0));
+    assert_close(63.86, EspenakMeeus.delta_t(2000.0));
+    assert_close(1574.2, EspenakMeeus.delta_t(1000.0));
+    assert_close(10583.6, EspenakMeeus.delta_t(0.0));
+}
+
+#[test]
+fn espenak_meeus_uses_parabola_outside_fitted_range() {
+    assert_close(
+        MorrisonStephenson.delta_t(-1000.0),
+        EspenakMeeus.delta_t(-1000.0),
+    );
+    assert_close(
+        MorrisonStephenson.delta_t(2150.0),
+        EspenakMeeus.delta_t(2150.0),
+    );
+    assert_close(
+        MorrisonStephenson.delta_t(3000.0),
+        EspenakMeeus.delta_t(3000.0),
+    );
+}
+
+#[test]
+fn morrison_stephenson_reference_values() {
+    assert_close(-20.0, MorrisonStephenson.delta_t(1820.0));
+    assert_close(12.0, MorrisonStephenson.delta_t(1920.0));
+    assert_close(108.0, MorrisonStephenson.delta_t(2020.0));
+}
+
+#[test]
+fn universal_time_of_epoch() {
+    let universal_time = EspenakMeeus.universal_time_of(Instant::EPOCH);
+
+    // At the start of 1970, TT - UT was about 40.2 seconds.
+    let expected = TERRESTRIAL_TIME_OFFSET_FROM_TAI_NANOSECONDS - 40_200_000_000;
+    let actual =
+        universal_time.epoch_second() * NANOSECONDS_IN_SECOND + universal_time.nano() as i64;
+    assert!(
+        (expected - actual).abs() < 100_000_000,
+        "was {:?}",
+        universal_time
+    );
+}
+
+#[test]
+fn universal_time_of_ancient_instant_lags() {
+    // By 500 BCE, the Earth has accumulated hours of rotation relative to atomic time.
+    let instant = Instant::of_epoch_second(-2500 * DAYS_IN_YEAR * SECONDS_IN_DAY);
+    let universal_time = EspenakMeeus.universal_time_of(instant);
+
+    let hours_behind = (instant.epoch_second() - universal_time.epoch_second()) / SECONDS_IN_HOUR;
+    assert_eq!(4, hours_behind);
+}
+
+proptest! {
+    #[test]
+    fn universal_time_round_trip(seconds in -SECONDS_IN_TEN_MILLENNIA..SECONDS_IN_TEN_MILLENNIA, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let round_trip = EspenakMeeus.instant_of(EspenakMeeus.universal_time_of(instant));
+
+        let difference = (round_trip.epoch_second() - instant.epoch_second()) * NANOSECONDS_IN_SECOND
+            + round_trip.nano() as i64 - instant.nano() as i64;
+        prop_assert!(difference.abs() <= 1_000, "difference of {} nanoseconds", difference);
+    }
+}
+
+proptest! {
+    #[test]
+    fn universal_time_of_overflow(nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(Instant::MIN.epoch_second(), nanos);
+        crate::assert::expect_panic("delta t would overflow universal time", || MorrisonStephenson.universal_time_of(instant))?;
+    }
+}
+
+#[test]
+fn of_epoch_second_and_adjustment() {
+    let universal_time = ModeledUniversalTime::of_epoch_second_and_adjustment(10, -1);
+
+    assert_eq!(9, universal_time.epoch_second());
+    assert_eq!(999_999_999, universal_time.nano());
+}
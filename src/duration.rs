@@ -1,12 +1,27 @@
-use std::i64;
-use std::u32;
+use std::fmt;
+use std::str::FromStr;
 
 use crate::constants::*;
+use crate::iso::*;
+use crate::rounding::*;
 use crate::seconds_nanos::*;
 use crate::util::const_expect;
+use crate::{ChronoUnit, DateTimeError};
 
 #[cfg(test)]
+pub mod accessors;
+#[cfg(test)]
+pub mod division;
+#[cfg(test)]
+#[allow(clippy::legacy_numeric_constants)]
 pub mod factories;
+mod human;
+#[cfg(test)]
+pub mod rounding;
+#[cfg(test)]
+pub mod text;
+
+pub use self::human::{HumanUnit, ParseHumanDurationError};
 
 /// A time-based amount of time, such as '34.5 seconds'.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -57,6 +72,14 @@ impl Duration {
         }
     }
 
+    /// Obtains a `Duration` representing a number of standard days, or an error if they would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `days`: the days in the duration.
+    pub fn try_of_days(days: i64) -> Result<Duration, DateTimeError> {
+        Duration::of_days_checked(days).ok_or(DateTimeError::Overflow)
+    }
+
     /// Obtains a `Duration` representing a number of standard hours.
     ///
     /// The seconds are calculated based on the standard definition of an hour, where each hour is 3600 seconds.
@@ -82,6 +105,14 @@ impl Duration {
         }
     }
 
+    /// Obtains a `Duration` representing a number of hours, or an error if they would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `hours`: the hours in the duration.
+    pub fn try_of_hours(hours: i64) -> Result<Duration, DateTimeError> {
+        Duration::of_hours_checked(hours).ok_or(DateTimeError::Overflow)
+    }
+
     /// Obtains a `Duration` representing a number of standard minutes.
     ///
     /// The seconds are calculated based on the standard definition of a minute, where each minute is 60 seconds.
@@ -107,6 +138,14 @@ impl Duration {
         }
     }
 
+    /// Obtains a `Duration` representing a number of minutes, or an error if they would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `minutes`: the minutes in the duration.
+    pub fn try_of_minutes(minutes: i64) -> Result<Duration, DateTimeError> {
+        Duration::of_minutes_checked(minutes).ok_or(DateTimeError::Overflow)
+    }
+
     /// Obtains a Duration representing a number of seconds and an adjustment in nanoseconds.
     ///
     /// # Parameters
@@ -136,6 +175,20 @@ impl Duration {
         }
     }
 
+    /// Obtains a `Duration` representing a number of seconds and an adjustment in nanoseconds,
+    /// or an error if the adjusted seconds would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds in the duration.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    pub fn try_of_seconds_and_adjustment(
+        seconds: i64,
+        nano_adjustment: i64,
+    ) -> Result<Duration, DateTimeError> {
+        Duration::of_seconds_and_adjustment_checked(seconds, nano_adjustment)
+            .ok_or(DateTimeError::Overflow)
+    }
+
     /// Obtains a Duration representing a number of seconds.
     ///
     /// The nanosecond field will be set to 0.
@@ -181,6 +234,42 @@ impl Duration {
         }
     }
 
+    /// Obtains a `Duration` from an exact number of nanoseconds.
+    ///
+    /// This is the inverse of [`to_total_nanos()`].
+    ///
+    /// # Parameters
+    ///  - `nanos`: the nanoseconds in the duration.
+    ///
+    /// # Panics
+    /// - if the nanoseconds would overflow the duration.
+    ///
+    /// [`to_total_nanos()`]: struct.Duration.html#method.to_total_nanos
+    pub const fn of_total_nanos(nanos: i128) -> Duration {
+        const_expect!(
+            Duration::of_total_nanos_checked(nanos),
+            "duration would overflow"
+        )
+    }
+
+    /// Obtains a `Duration` from an exact number of nanoseconds, or an error if they would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `nanos`: the nanoseconds in the duration.
+    pub fn try_of_total_nanos(nanos: i128) -> Result<Duration, DateTimeError> {
+        Duration::of_total_nanos_checked(nanos).ok_or(DateTimeError::Overflow)
+    }
+
+    const fn of_total_nanos_checked(nanos: i128) -> Option<Duration> {
+        match of_total_nanos_checked(nanos) {
+            None => None,
+            Some((seconds, nanos)) => Some(Duration {
+                seconds,
+                nanoseconds_of_second: nanos,
+            }),
+        }
+    }
+
     /// Gets the number of nanoseconds within the second in this duration.
     ///
     /// [`seconds()`]: struct.Duration.html#method.seconds
@@ -194,4 +283,484 @@ impl Duration {
     pub const fn seconds(&self) -> i64 {
         self.seconds
     }
+
+    /// Checks if this duration is zero length.
+    pub const fn is_zero(&self) -> bool {
+        self.seconds == 0 && self.nanoseconds_of_second == 0
+    }
+
+    /// Checks if this duration is shorter than zero.
+    pub const fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+
+    /// Checks if this duration is longer than zero.
+    pub const fn is_positive(&self) -> bool {
+        self.seconds > 0 || (self.seconds == 0 && self.nanoseconds_of_second > 0)
+    }
+
+    /// Returns a copy of this duration with the given number of seconds, keeping the nanosecond of second.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds to use.
+    pub const fn with_seconds(&self, seconds: i64) -> Duration {
+        Duration {
+            seconds,
+            nanoseconds_of_second: self.nanoseconds_of_second,
+        }
+    }
+
+    /// Returns a copy of this duration with the given nanosecond of second, keeping the seconds.
+    ///
+    /// # Parameters
+    ///  - `nano`: the nanosecond of second to use, from 0 to 999,999,999.
+    ///
+    /// # Panics
+    /// - if the nanosecond is out of range.
+    pub const fn with_nanos(&self, nano: u32) -> Duration {
+        if nano >= NANOSECONDS_IN_SECOND as u32 {
+            panic!("nano out of range");
+        }
+        Duration {
+            seconds: self.seconds,
+            nanoseconds_of_second: nano,
+        }
+    }
+
+    /// Gets the number of whole days in this duration, as standard days of 24 hours.
+    ///
+    /// As in java.time, this divides the whole [`seconds()`], truncating towards zero.
+    /// The seconds are rounded down, so '-36 hours' is -1 day, but so is '-86,399.5 seconds'.
+    ///
+    /// [`seconds()`]: struct.Duration.html#method.seconds
+    pub const fn to_days(&self) -> i64 {
+        self.seconds / SECONDS_IN_DAY
+    }
+
+    /// Gets the number of whole hours in this duration, dividing the whole seconds as [`to_days()`] does.
+    ///
+    /// [`to_days()`]: struct.Duration.html#method.to_days
+    pub const fn to_hours(&self) -> i64 {
+        self.seconds / SECONDS_IN_HOUR
+    }
+
+    /// Gets the number of whole minutes in this duration, dividing the whole seconds as [`to_days()`] does.
+    ///
+    /// [`to_days()`]: struct.Duration.html#method.to_days
+    pub const fn to_minutes(&self) -> i64 {
+        self.seconds / SECONDS_IN_MINUTE
+    }
+
+    /// Gets the number of whole milliseconds in this duration, truncated towards zero as in java.time,
+    /// so '-0.5 milliseconds' is 0.
+    ///
+    /// # Panics
+    /// - if the milliseconds would overflow an `i64`, which is possible for durations over about 292 million years.
+    pub const fn to_millis(&self) -> i64 {
+        const_expect!(self.to_millis_checked(), "millis would overflow")
+    }
+
+    /// Gets the number of whole milliseconds in this duration, truncated towards zero,
+    /// or `None` if they would overflow an `i64`.
+    pub const fn to_millis_checked(&self) -> Option<i64> {
+        i64_of(self.to_total_nanos() / NANOSECONDS_IN_MILLISECOND as i128)
+    }
+
+    /// Gets the number of nanoseconds in this duration.
+    ///
+    /// # Panics
+    /// - if the nanoseconds would overflow an `i64`, which is possible for durations over about 292 years.
+    ///   [`to_total_nanos()`] never overflows.
+    ///
+    /// [`to_total_nanos()`]: struct.Duration.html#method.to_total_nanos
+    pub const fn to_nanos(&self) -> i64 {
+        const_expect!(self.to_nanos_checked(), "nanos would overflow")
+    }
+
+    /// Gets the number of nanoseconds in this duration, or `None` if they would overflow an `i64`.
+    pub const fn to_nanos_checked(&self) -> Option<i64> {
+        i64_of(self.to_total_nanos())
+    }
+
+    /// Gets the exact number of nanoseconds in this duration.
+    ///
+    /// Every duration fits in an `i128` of nanoseconds, so this never overflows.
+    pub const fn to_total_nanos(&self) -> i128 {
+        total_nanos(self.seconds, self.nanoseconds_of_second)
+    }
+
+    /// Gets the days part of this duration, which is the same as [`to_days()`].
+    ///
+    /// The `to_*_part` getters split the duration into days, hours, minutes, seconds and nanoseconds, as in java.time.
+    /// The parts down to the seconds split the whole [`seconds()`], which are rounded down,
+    /// and the fraction is always positive, so '-0.5 seconds' is -1 second and 500 milliseconds.
+    ///
+    /// [`to_days()`]: struct.Duration.html#method.to_days
+    /// [`seconds()`]: struct.Duration.html#method.seconds
+    pub const fn to_days_part(&self) -> i64 {
+        self.to_days()
+    }
+
+    /// Gets the hours part of this duration, from -23 to 23.
+    pub const fn to_hours_part(&self) -> i32 {
+        (self.to_hours() % HOURS_IN_DAY) as i32
+    }
+
+    /// Gets the minutes part of this duration, from -59 to 59.
+    pub const fn to_minutes_part(&self) -> i32 {
+        (self.to_minutes() % MINUTES_IN_HOUR) as i32
+    }
+
+    /// Gets the seconds part of this duration, from -59 to 59.
+    pub const fn to_seconds_part(&self) -> i32 {
+        (self.seconds % SECONDS_IN_MINUTE) as i32
+    }
+
+    /// Gets the milliseconds part of the fraction of the second, from 0 to 999.
+    pub const fn to_millis_part(&self) -> i32 {
+        self.to_nanos_part() / NANOSECONDS_IN_MILLISECOND as i32
+    }
+
+    /// Gets the fraction of the second in nanoseconds, from 0 to 999,999,999, which is the same as [`nano()`].
+    ///
+    /// [`nano()`]: struct.Duration.html#method.nano
+    pub const fn to_nanos_part(&self) -> i32 {
+        self.nanoseconds_of_second as i32
+    }
+
+    /// Gets this duration as a floating-point number of seconds.
+    ///
+    /// The result is the closest `f64` to the exact duration for durations under 2^53 nanoseconds (about 104 days),
+    /// and is otherwise within one unit in the last place, so nanoseconds are lost for longer durations.
+    pub fn to_seconds_f64(&self) -> f64 {
+        self.to_total_nanos() as f64 / NANOSECONDS_IN_SECOND as f64
+    }
+
+    /// Obtains a `Duration` from a floating-point number of seconds, rounded to the nearest nanosecond.
+    ///
+    /// The whole seconds are kept exactly, and only the fraction is rounded.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds in the duration.
+    ///
+    /// # Panics
+    /// - if the seconds are not finite, or would overflow the duration.
+    pub fn of_seconds_f64(seconds: f64) -> Duration {
+        const_expect!(
+            Duration::of_seconds_f64_checked(seconds),
+            "seconds out of range"
+        )
+    }
+
+    /// Obtains a `Duration` from a floating-point number of seconds, rounded to the nearest nanosecond,
+    /// or an error if the seconds are not finite or would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds in the duration.
+    pub fn try_of_seconds_f64(seconds: f64) -> Result<Duration, DateTimeError> {
+        Duration::of_seconds_f64_checked(seconds).ok_or(DateTimeError::Overflow)
+    }
+
+    fn of_seconds_f64_checked(seconds: f64) -> Option<Duration> {
+        // 2^63, which is exactly representable, unlike i64::MAX.
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+        if !(-LIMIT..LIMIT).contains(&seconds) {
+            return None;
+        }
+        let whole = seconds.trunc();
+        // Splitting off the fraction is exact, so only its scaling to nanoseconds loses precision.
+        let nanos = ((seconds - whole) * NANOSECONDS_IN_SECOND as f64).round();
+        Duration::of_seconds_and_adjustment_checked(whole as i64, nanos as i64)
+    }
+
+    /// Returns a copy of this duration truncated to a unit, such as whole minutes.
+    ///
+    /// The unit must fit in a day, from nanoseconds to days, and truncation is towards zero,
+    /// so '-1.5 seconds' truncated to seconds is '-1 second'.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit to truncate to.
+    ///
+    /// # Panics
+    /// - if the unit is longer than a day.
+    pub const fn truncated_to(&self, unit: ChronoUnit) -> Duration {
+        let granularity = truncation_nanos(unit);
+        let nanos = total_nanos(self.seconds, self.nanoseconds_of_second);
+        // Truncating towards zero never leaves the range of the duration.
+        Duration::of_total_nanos(nanos - nanos % granularity)
+    }
+
+    /// Returns a copy of this duration rounded to a multiple of another, such as 15 minutes.
+    ///
+    /// # Parameters
+    ///  - `granularity`: the duration to round to a multiple of, which must be positive.
+    ///  - `mode`: how to round durations between two multiples.
+    ///
+    /// # Panics
+    /// - if the granularity is not positive.
+    /// - if the rounded duration would overflow.
+    pub const fn rounded_to(&self, granularity: Duration, mode: RoundingMode) -> Duration {
+        let granularity = total_nanos(granularity.seconds, granularity.nanoseconds_of_second);
+        if granularity <= 0 {
+            panic!("granularity must be positive");
+        }
+        let nanos = round(
+            total_nanos(self.seconds, self.nanoseconds_of_second),
+            granularity,
+            mode,
+        );
+        Duration::of_total_nanos(nanos)
+    }
+
+    /// Gets how many times a divisor fits in this duration, truncated towards zero.
+    ///
+    /// For example, 'PT1.1S' divided by 'PT0.25S' is 4, and '-PT1.1S' is -4.
+    ///
+    /// # Parameters
+    ///  - `divisor`: the duration to divide by.
+    ///
+    /// # Panics
+    /// - if the divisor is zero.
+    /// - if the quotient would overflow an `i64`, such as when dividing a long duration by a nanosecond.
+    pub const fn divided_by_duration(&self, divisor: Duration) -> i64 {
+        let divisor = divisor.nonzero_nanos();
+        const_expect!(
+            i64_of(self.to_total_nanos() / divisor),
+            "quotient would overflow"
+        )
+    }
+
+    /// Gets how many times a divisor fits in this duration, rounded towards negative infinity.
+    ///
+    /// For a positive divisor this counts the whole slots up to and including this duration,
+    /// so '-PT1.1S' divided by 'PT0.25S' is -5.
+    ///
+    /// # Parameters
+    ///  - `divisor`: the duration to divide by.
+    ///
+    /// # Panics
+    /// - if the divisor is zero.
+    /// - if the quotient would overflow an `i64`.
+    pub const fn div_floor(&self, divisor: Duration) -> i64 {
+        let divisor = divisor.nonzero_nanos();
+        let nanos = self.to_total_nanos();
+        let quotient = nanos / divisor;
+        let quotient = if nanos % divisor != 0 && (nanos < 0) != (divisor < 0) {
+            quotient - 1
+        } else {
+            quotient
+        };
+        const_expect!(i64_of(quotient), "quotient would overflow")
+    }
+
+    /// Gets what is left of this duration after taking out a whole number of divisors,
+    /// which is never negative and shorter than the length of the divisor.
+    ///
+    /// As for `i64::rem_euclid`, '-PT1.1S' with a divisor of 'PT0.25S' leaves 'PT0.15S'.
+    ///
+    /// # Parameters
+    ///  - `divisor`: the duration to divide by.
+    ///
+    /// # Panics
+    /// - if the divisor is zero.
+    pub const fn rem_euclid(&self, divisor: Duration) -> Duration {
+        let divisor = divisor.nonzero_nanos();
+        // The remainder is shorter than the divisor, so it always fits.
+        Duration::of_total_nanos(self.to_total_nanos().rem_euclid(divisor))
+    }
+
+    const fn nonzero_nanos(&self) -> i128 {
+        if self.is_zero() {
+            panic!("divisor must not be zero");
+        }
+        self.to_total_nanos()
+    }
+
+    /// Returns a copy of this duration divided by a floating-point number, rounded to a nanosecond.
+    ///
+    /// The divisor is used exactly, as the binary fraction it holds, so the only rounding is the final one,
+    /// and dividing by `0.1` is not quite the same as multiplying by 10.
+    ///
+    /// # Parameters
+    ///  - `divisor`: the value to divide by.
+    ///  - `mode`: how to round quotients between two nanoseconds.
+    ///
+    /// # Panics
+    /// - if the divisor is zero or not finite.
+    /// - if the quotient would overflow the duration.
+    pub fn divided_by_f64(&self, divisor: f64, mode: RoundingMode) -> Duration {
+        if divisor == 0.0 || !divisor.is_finite() {
+            panic!("divisor must be finite and not zero");
+        }
+        // The divisor is exactly mantissa * 2^exponent.
+        let bits = divisor.to_bits();
+        let biased_exponent = (bits >> 52 & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as u128;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+
+        let nanos = self.to_total_nanos();
+        let magnitude = nanos.unsigned_abs();
+        let (quotient, remainder, denominator) = if exponent >= 0 {
+            // Any denominator over twice the largest duration rounds the same way.
+            let denominator = if exponent >= 70 {
+                1 << 126
+            } else {
+                mantissa << exponent
+            };
+            (
+                magnitude / denominator,
+                magnitude % denominator,
+                denominator,
+            )
+        } else {
+            // Long division, shifting the remainder up by the power of two a few bits at a time.
+            let mut quotient = magnitude / mantissa;
+            let mut remainder = magnitude % mantissa;
+            let mut shift = -exponent as u32;
+            while shift > 0 && (quotient > 0 || remainder > 0) {
+                let bits = if shift < 64 { shift } else { 64 };
+                if quotient > u128::MAX >> (bits + 2) {
+                    panic!("duration would overflow");
+                }
+                quotient = (quotient << bits) + (remainder << bits) / mantissa;
+                remainder = (remainder << bits) % mantissa;
+                shift -= bits;
+            }
+            (quotient, remainder, mantissa)
+        };
+
+        let (quotient, remainder, denominator) =
+            (quotient as i128, remainder as i128, denominator as i128);
+        let (quotient, remainder) = if (nanos < 0) == (divisor < 0.0) {
+            (quotient, remainder)
+        } else if remainder == 0 {
+            (-quotient, 0)
+        } else {
+            (-quotient - 1, denominator - remainder)
+        };
+        Duration::of_total_nanos(round_quotient(quotient, remainder, denominator, mode))
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Formats this duration in ISO-8601 format, using hours, minutes and seconds, such as 'PT8H6M12.345S'.
+    ///
+    /// Each part carries the sign of the duration, so a negative duration looks like 'PT-6H-3M-0.5S'.
+    /// A zero duration is 'PT0S'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Duration::ZERO {
+            return write!(f, "PT0S");
+        }
+        let negative_fraction = self.seconds < 0 && self.nanoseconds_of_second > 0;
+        let whole_seconds = if negative_fraction {
+            self.seconds + 1
+        } else {
+            self.seconds
+        };
+        let hours = whole_seconds / SECONDS_IN_HOUR;
+        let minutes = whole_seconds % SECONDS_IN_HOUR / SECONDS_IN_MINUTE;
+        let seconds = whole_seconds % SECONDS_IN_MINUTE;
+
+        write!(f, "PT")?;
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+        if seconds == 0 && self.nanoseconds_of_second == 0 {
+            return Ok(());
+        }
+
+        if negative_fraction && seconds == 0 {
+            write!(f, "-0")?;
+        } else {
+            write!(f, "{}", seconds)?;
+        }
+        if self.nanoseconds_of_second > 0 {
+            let fraction = if negative_fraction {
+                NANOSECONDS_IN_SECOND as u32 - self.nanoseconds_of_second
+            } else {
+                self.nanoseconds_of_second
+            };
+            let digits = format!("{:09}", fraction);
+            write!(f, ".{}", digits.trim_end_matches('0'))?;
+        }
+        write!(f, "S")
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseIsoError;
+
+    /// Parses an ISO-8601 duration of the form `PnDTnHnMn.nS`, such as 'PT8H6M12.345S'.
+    ///
+    /// Days are standard days of exactly 24 hours.
+    /// Each amount, and the whole duration, may be signed; only seconds may have a fraction.
+    /// Letters are case insensitive.
+    fn from_str(text: &str) -> Result<Duration, ParseIsoError> {
+        const DATE_UNITS: &[(u8, i64)] = &[(b'D', SECONDS_IN_DAY)];
+        const TIME_UNITS: &[(u8, i64)] = &[
+            (b'H', SECONDS_IN_HOUR),
+            (b'M', SECONDS_IN_MINUTE),
+            (b'S', 1),
+        ];
+
+        let mut parser = Parser::of(text);
+        let negative = parser.sign() == Some(true);
+        parser.expect(b'P', "expected 'P'")?;
+
+        let mut total: i128 = 0;
+        let mut units = DATE_UNITS;
+        let mut in_time = false;
+        let mut amounts = 0;
+        while parser.peek().is_some() {
+            if !in_time && parser.eat(b'T') {
+                in_time = true;
+                units = TIME_UNITS;
+                if parser.peek().is_none() {
+                    return Err(parser.error("expected an amount"));
+                }
+                continue;
+            }
+
+            let amount_negative = parser.sign() == Some(true);
+            let (value, _) = parser.digits(1, 19, "invalid amount")?;
+            let fraction = if parser.eat(b'.') || parser.eat(b',') {
+                Some(parser.fraction()?)
+            } else {
+                None
+            };
+
+            let unit = parser.position();
+            let index = units
+                .iter()
+                .position(|&(letter, _)| parser.eat(letter))
+                .ok_or_else(|| parser.error("expected a unit"))?;
+            let (letter, seconds) = units[index];
+            if fraction.is_some() && letter != b'S' {
+                return Err(parser.error_at(unit, "only seconds may have a fraction"));
+            }
+            units = &units[index + 1..];
+
+            let amount = value as i128 * seconds as i128 * NANOSECONDS_IN_SECOND as i128
+                + fraction.unwrap_or(0) as i128;
+            total += if amount_negative { -amount } else { amount };
+            amounts += 1;
+        }
+        if amounts == 0 {
+            return Err(parser.error("expected an amount"));
+        }
+
+        of_total_nanos_checked(if negative { -total } else { total })
+            .map(|(seconds, nanos)| Duration {
+                seconds,
+                nanoseconds_of_second: nanos,
+            })
+            .ok_or_else(|| parser.error_at(0, "duration out of range"))
+    }
 }
@@ -0,0 +1,145 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::Duration;
+
+#[test]
+fn signs() {
+    assert!(Duration::ZERO.is_zero());
+    assert!(!Duration::ZERO.is_negative());
+    assert!(!Duration::ZERO.is_positive());
+    assert!(Duration::of_nanos(-1).is_negative());
+    assert!(!Duration::of_nanos(-1).is_positive());
+    assert!(Duration::of_nanos(1).is_positive());
+    assert!(!Duration::of_nanos(1).is_zero());
+}
+
+#[test]
+fn conversions() {
+    let duration = Duration::of_seconds_and_adjustment(36 * 3_600, 1_500_000_000);
+
+    assert_eq!(1, duration.to_days());
+    assert_eq!(36, duration.to_hours());
+    assert_eq!(36 * 60, duration.to_minutes());
+    assert_eq!(36 * 3_600_000 + 1_500, duration.to_millis());
+    assert_eq!(
+        36 * NANOSECONDS_IN_HOUR + 1_500_000_000,
+        duration.to_nanos()
+    );
+
+    // As in java.time, the whole seconds are rounded down before dividing, and the millis are truncated.
+    let negative = Duration::of_nanos(-59_500_000_000);
+    assert_eq!(-1, negative.to_minutes());
+    assert_eq!(-59_500, negative.to_millis());
+    assert_eq!(-1, Duration::of_hours(-36).to_days());
+    assert_eq!(0, Duration::of_nanos(-1).to_days());
+    assert_eq!(0, Duration::of_nanos(-1).to_millis());
+    assert_eq!(-1, Duration::of_nanos(-1).to_nanos());
+}
+
+#[test]
+fn conversions_overflow() {
+    assert_eq!(None, Duration::MAX.to_nanos_checked());
+    assert_eq!(None, Duration::MIN.to_millis_checked());
+    assert_eq!(
+        Some(i64::MAX / 1_000_000),
+        Duration::of_nanos(i64::MAX).to_millis_checked()
+    );
+    assert_eq!(
+        Some(i64::MIN),
+        Duration::of_nanos(i64::MIN).to_nanos_checked()
+    );
+    expect_panic("nanos would overflow", || Duration::MAX.to_nanos()).unwrap();
+    expect_panic("millis would overflow", || Duration::MIN.to_millis()).unwrap();
+}
+
+#[test]
+fn parts() {
+    let duration = Duration::of_seconds_and_adjustment(-(25 * 3_600 + 61), -500_000_001);
+
+    assert_eq!(-1, duration.to_days_part());
+    assert_eq!(-1, duration.to_hours_part());
+    assert_eq!(-1, duration.to_minutes_part());
+    assert_eq!(-2, duration.to_seconds_part());
+    assert_eq!(499, duration.to_millis_part());
+    assert_eq!(499_999_999, duration.to_nanos_part());
+
+    // java.time's Duration.ofNanos(-1) has 999,999,999 nanos, after -1 seconds.
+    let nano = Duration::of_nanos(-1);
+    assert_eq!(0, nano.to_minutes_part());
+    assert_eq!(-1, nano.to_seconds_part());
+    assert_eq!(999, nano.to_millis_part());
+    assert_eq!(999_999_999, nano.to_nanos_part());
+}
+
+#[test]
+fn with_seconds_and_nanos() {
+    let duration = Duration::of_seconds_and_adjustment(5, 250);
+
+    assert_eq!(
+        Duration::of_seconds_and_adjustment(-3, 250),
+        duration.with_seconds(-3)
+    );
+    assert_eq!(
+        Duration::of_seconds_and_adjustment(5, 999_999_999),
+        duration.with_nanos(999_999_999)
+    );
+    expect_panic("nano out of range", || duration.with_nanos(1_000_000_000)).unwrap();
+}
+
+#[test]
+fn seconds_f64() {
+    assert_eq!(1.5, Duration::of_millis(1_500).to_seconds_f64());
+    assert_eq!(-0.25, Duration::of_millis(-250).to_seconds_f64());
+    assert_eq!(Duration::of_millis(1_500), Duration::of_seconds_f64(1.5));
+    assert_eq!(Duration::of_nanos(-1), Duration::of_seconds_f64(-1e-9));
+    assert_eq!(
+        Duration::of_seconds(i64::MIN),
+        Duration::of_seconds_f64(-9_223_372_036_854_775_808.0)
+    );
+    expect_panic("seconds out of range", || {
+        Duration::of_seconds_f64(f64::NAN)
+    })
+    .unwrap();
+    expect_panic("seconds out of range", || {
+        Duration::of_seconds_f64(9_223_372_036_854_775_808.0)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn total_nanos_round_trip(seconds: i64, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(duration, Duration::of_total_nanos(duration.to_total_nanos()));
+    }
+
+    #[test]
+    fn parts_add_up(seconds: i64, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let total = duration.to_days_part() as i128 * NANOSECONDS_IN_DAY as i128
+            + duration.to_hours_part() as i128 * NANOSECONDS_IN_HOUR as i128
+            + duration.to_minutes_part() as i128 * NANOSECONDS_IN_MINUTE as i128
+            + duration.to_seconds_part() as i128 * NANOSECONDS_IN_SECOND as i128
+            + duration.to_nanos_part() as i128;
+
+        prop_assert_eq!(duration.to_total_nanos(), total);
+    }
+
+    #[test]
+    fn nanos_round_trip(nanos: i64) {
+        prop_assert_eq!(nanos, Duration::of_nanos(nanos).to_nanos());
+    }
+
+    #[test]
+    // Below 2^20 seconds, a nanosecond is several units in the last place.
+    fn seconds_f64_round_trip(nanos in -(1i64 << 50)..(1i64 << 50)) {
+        let duration = Duration::of_nanos(nanos);
+
+        prop_assert_eq!(duration, Duration::of_seconds_f64(duration.to_seconds_f64()));
+    }
+}
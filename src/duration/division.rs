@@ -0,0 +1,214 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::rounding::round;
+use crate::{Duration, RoundingMode};
+
+const MODES: [RoundingMode; 4] = [
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::HalfUp,
+    RoundingMode::HalfEven,
+];
+
+// The mode that rounds a negated amount the same way.
+fn reversed(mode: RoundingMode) -> RoundingMode {
+    match mode {
+        RoundingMode::Floor => RoundingMode::Ceiling,
+        RoundingMode::Ceiling => RoundingMode::Floor,
+        mode => mode,
+    }
+}
+
+fn millis(millis: i64) -> Duration {
+    Duration::of_millis(millis)
+}
+
+#[test]
+fn divided_by_duration() {
+    assert_eq!(4, millis(1_100).divided_by_duration(millis(250)));
+    assert_eq!(-4, millis(-1_100).divided_by_duration(millis(250)));
+    assert_eq!(-4, millis(1_100).divided_by_duration(millis(-250)));
+    assert_eq!(
+        i64::MAX,
+        Duration::of_nanos(i64::MAX).divided_by_duration(Duration::of_nanos(1))
+    );
+    assert_eq!(
+        -i64::MAX / NANOSECONDS_IN_SECOND,
+        Duration::of_seconds(-i64::MAX)
+            .divided_by_duration(Duration::of_seconds(NANOSECONDS_IN_SECOND))
+    );
+    expect_panic("divisor must not be zero", || {
+        millis(1).divided_by_duration(Duration::ZERO)
+    })
+    .unwrap();
+    expect_panic("quotient would overflow", || {
+        Duration::MAX.divided_by_duration(Duration::of_nanos(1))
+    })
+    .unwrap();
+}
+
+#[test]
+fn div_floor_and_rem_euclid() {
+    assert_eq!(4, millis(1_100).div_floor(millis(250)));
+    assert_eq!(-5, millis(-1_100).div_floor(millis(250)));
+    assert_eq!(-5, millis(1_100).div_floor(millis(-250)));
+    assert_eq!(4, millis(-1_000).div_floor(millis(-250)));
+    assert_eq!(millis(100), millis(1_100).rem_euclid(millis(250)));
+    assert_eq!(millis(150), millis(-1_100).rem_euclid(millis(250)));
+    assert_eq!(millis(150), millis(-1_100).rem_euclid(millis(-250)));
+    assert_eq!(
+        Duration::of_nanos(999_999_999),
+        Duration::MAX.rem_euclid(Duration::of_seconds(1))
+    );
+    expect_panic("divisor must not be zero", || {
+        millis(1).rem_euclid(Duration::ZERO)
+    })
+    .unwrap();
+    expect_panic("quotient would overflow", || {
+        Duration::MIN.div_floor(Duration::of_nanos(1))
+    })
+    .unwrap();
+}
+
+#[test]
+fn divided_by_f64() {
+    assert_eq!(
+        millis(500),
+        millis(1_000).divided_by_f64(2.0, RoundingMode::Floor)
+    );
+    assert_eq!(
+        millis(-500),
+        millis(1_000).divided_by_f64(-2.0, RoundingMode::Floor)
+    );
+    assert_eq!(
+        millis(4_000),
+        millis(1_000).divided_by_f64(0.25, RoundingMode::Floor)
+    );
+    // 0.1 is slightly more than a tenth, so the quotient is slightly less than ten times.
+    assert_eq!(
+        Duration::of_nanos(9_999_999_999),
+        millis(1_000).divided_by_f64(0.1, RoundingMode::Floor)
+    );
+    assert_eq!(
+        Duration::of_seconds(10),
+        millis(1_000).divided_by_f64(0.1, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        Duration::of_nanos(1),
+        Duration::of_nanos(1).divided_by_f64(1e300, RoundingMode::Ceiling)
+    );
+    assert_eq!(
+        Duration::of_nanos(-1),
+        Duration::of_nanos(1).divided_by_f64(-1e300, RoundingMode::Floor)
+    );
+    assert_eq!(
+        Duration::ZERO,
+        Duration::MAX.divided_by_f64(1e300, RoundingMode::HalfUp)
+    );
+    expect_panic("divisor must be finite and not zero", || {
+        millis(1).divided_by_f64(0.0, RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("divisor must be finite and not zero", || {
+        millis(1).divided_by_f64(f64::INFINITY, RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("duration would overflow", || {
+        millis(1).divided_by_f64(f64::MIN_POSITIVE, RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("duration would overflow", || {
+        Duration::MAX.divided_by_f64(0.5, RoundingMode::Floor)
+    })
+    .unwrap();
+}
+
+#[test]
+fn divided_by_f64_ties() {
+    let three = Duration::of_nanos(3);
+    let minus_three = Duration::of_nanos(-3);
+
+    assert_eq!(
+        Duration::of_nanos(1),
+        three.divided_by_f64(2.0, RoundingMode::Floor)
+    );
+    assert_eq!(
+        Duration::of_nanos(2),
+        three.divided_by_f64(2.0, RoundingMode::Ceiling)
+    );
+    assert_eq!(
+        Duration::of_nanos(2),
+        three.divided_by_f64(2.0, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Duration::of_nanos(2),
+        three.divided_by_f64(2.0, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        Duration::of_nanos(-2),
+        minus_three.divided_by_f64(2.0, RoundingMode::Floor)
+    );
+    assert_eq!(
+        Duration::of_nanos(-1),
+        minus_three.divided_by_f64(2.0, RoundingMode::Ceiling)
+    );
+    assert_eq!(
+        Duration::of_nanos(-2),
+        minus_three.divided_by_f64(2.0, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Duration::of_nanos(-2),
+        minus_three.divided_by_f64(2.0, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        Duration::of_nanos(2),
+        Duration::of_nanos(5).divided_by_f64(2.0, RoundingMode::HalfEven)
+    );
+}
+
+proptest! {
+    #[test]
+    fn div_floor_and_rem_euclid_add_back(nanos: i64, divisor in 1i64..=i64::MAX) {
+        let duration = Duration::of_nanos(nanos);
+        let divisor = Duration::of_nanos(divisor);
+
+        let quotient = duration.div_floor(divisor);
+        let remainder = duration.rem_euclid(divisor);
+
+        prop_assert!(remainder < divisor);
+        prop_assert_eq!(
+            duration.to_total_nanos(),
+            quotient as i128 * divisor.to_total_nanos() + remainder.to_total_nanos()
+        );
+    }
+
+    #[test]
+    fn divided_by_whole_f64_matches_integer_rounding(nanos: i64, divisor in 1i64..(1 << 53)) {
+        let duration = Duration::of_nanos(nanos);
+
+        for &mode in &MODES {
+            let expected = round(nanos as i128, divisor as i128, mode) / divisor as i128;
+            prop_assert_eq!(
+                Duration::of_total_nanos(expected),
+                duration.divided_by_f64(divisor as f64, mode)
+            );
+            prop_assert_eq!(
+                Duration::of_total_nanos(-expected),
+                duration.divided_by_f64(-(divisor as f64), reversed(mode))
+            );
+        }
+    }
+
+    #[test]
+    fn divided_by_power_of_two_fraction(nanos in -(1i64 << 40)..(1i64 << 40), power in 0i32..20) {
+        let duration = Duration::of_nanos(nanos);
+
+        prop_assert_eq!(
+            Duration::of_nanos(nanos << power),
+            duration.divided_by_f64(2f64.powi(-power), RoundingMode::Floor)
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+
+use super::Duration;
+use crate::constants::*;
+use crate::seconds_nanos::*;
+
+// Fraction digits beyond these can't change the nanoseconds of any unit.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// A unit of a human-readable duration, such as the 'm' of '90m'.
+///
+/// Days and weeks are standard days of exactly 24 hours.
+/// Months and years have no fixed length, so are not units of a duration.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HumanUnit {
+    /// Nanoseconds, 'ns'.
+    Nanos,
+    /// Microseconds, 'us' or 'µs'.
+    Micros,
+    /// Milliseconds, 'ms'.
+    Millis,
+    /// Seconds, 's'.
+    Seconds,
+    /// Minutes, 'm'.
+    Minutes,
+    /// Hours, 'h'.
+    Hours,
+    /// Standard days of 24 hours, 'd'.
+    Days,
+    /// Weeks of 7 standard days, 'w'.
+    Weeks,
+}
+
+/// An error parsing a human-readable duration, such as '1h 30m'.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseHumanDurationError {
+    position: usize,
+    reason: &'static str,
+}
+
+impl HumanUnit {
+    const ALL: [HumanUnit; 8] = [
+        HumanUnit::Nanos,
+        HumanUnit::Micros,
+        HumanUnit::Millis,
+        HumanUnit::Seconds,
+        HumanUnit::Minutes,
+        HumanUnit::Hours,
+        HumanUnit::Days,
+        HumanUnit::Weeks,
+    ];
+
+    /// Gets the suffix the unit is written with, such as 'ms'.
+    pub const fn suffix(&self) -> &'static str {
+        match self {
+            HumanUnit::Nanos => "ns",
+            HumanUnit::Micros => "us",
+            HumanUnit::Millis => "ms",
+            HumanUnit::Seconds => "s",
+            HumanUnit::Minutes => "m",
+            HumanUnit::Hours => "h",
+            HumanUnit::Days => "d",
+            HumanUnit::Weeks => "w",
+        }
+    }
+
+    const fn nanos(&self) -> i128 {
+        (match self {
+            HumanUnit::Nanos => 1,
+            HumanUnit::Micros => NANOSECONDS_IN_MICROSECOND,
+            HumanUnit::Millis => NANOSECONDS_IN_MILLISECOND,
+            HumanUnit::Seconds => NANOSECONDS_IN_SECOND,
+            HumanUnit::Minutes => NANOSECONDS_IN_MINUTE,
+            HumanUnit::Hours => NANOSECONDS_IN_HOUR,
+            HumanUnit::Days => NANOSECONDS_IN_DAY,
+            HumanUnit::Weeks => DAYS_IN_WEEK_ISO * NANOSECONDS_IN_DAY,
+        }) as i128
+    }
+
+    fn of_suffix(suffix: &str) -> Result<HumanUnit, &'static str> {
+        match suffix {
+            "" => Err("expected a unit"),
+            // 'µ' is the micro sign, and 'μ' the Greek letter mu.
+            "µs" | "μs" => Ok(HumanUnit::Micros),
+            "M" => Err("ambiguous unit 'M', use 'm' for minutes"),
+            "y" | "yr" | "Y" | "mo" | "mon" => Err("months and years have no fixed length"),
+            _ => HumanUnit::ALL
+                .iter()
+                .copied()
+                .find(|unit| unit.suffix() == suffix)
+                .ok_or("unknown unit"),
+        }
+    }
+}
+
+impl ParseHumanDurationError {
+    /// Gets the (byte) position in the text the error was found at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets a short description of what was wrong.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseHumanDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid duration at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl Error for ParseHumanDurationError {}
+
+impl Duration {
+    /// Parses a human-readable duration, as used by Go and many configuration files, such as '1h 30m', '90s' or '2d4h'.
+    ///
+    /// The duration is a list of amounts, each followed by one of the units
+    /// 'ns', 'us' (or 'µs'), 'ms', 's', 'm', 'h', 'd' and 'w', and optionally separated by whitespace.
+    /// Amounts may have a fraction, such as '1.5h', and the whole duration may be signed, such as '-1m30s'.
+    /// Days and weeks are standard days of exactly 24 hours; units are case sensitive, and 'M' is rejected as ambiguous.
+    /// A bare '0' is also accepted.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse.
+    pub fn parse_human(text: &str) -> Result<Duration, ParseHumanDurationError> {
+        let bytes = text.as_bytes();
+        let error = |position, reason| ParseHumanDurationError { position, reason };
+        let negative = bytes.first() == Some(&b'-');
+        let mut position = match bytes.first() {
+            Some(b'-') | Some(b'+') => 1,
+            _ => 0,
+        };
+        if &text[position..] == "0" {
+            return Ok(Duration::ZERO);
+        }
+
+        let mut total: i128 = 0;
+        loop {
+            let start = position;
+            let digits = |from: usize| {
+                bytes[from..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .count()
+            };
+            let whole_digits = digits(position);
+            let whole = match &text[position..position + whole_digits] {
+                "" => 0,
+                whole => whole
+                    .parse::<u64>()
+                    .map_err(|_| error(start, "amount out of range"))?,
+            };
+            position += whole_digits;
+            let mut fraction_digits = 0;
+            if bytes.get(position) == Some(&b'.') {
+                position += 1;
+                fraction_digits = digits(position);
+            }
+            let fraction = &text[position..position + fraction_digits];
+            position += fraction_digits;
+            if whole_digits == 0 && fraction_digits == 0 {
+                return Err(error(start, "expected an amount"));
+            }
+
+            let suffix_start = position;
+            position += text[position..]
+                .chars()
+                .take_while(|c| c.is_alphabetic())
+                .map(char::len_utf8)
+                .sum::<usize>();
+            let unit = HumanUnit::of_suffix(&text[suffix_start..position])
+                .map_err(|reason| error(suffix_start, reason))?;
+
+            let fraction = &fraction[..fraction.len().min(MAX_FRACTION_DIGITS)];
+            let fraction_nanos = match fraction.parse::<i128>() {
+                Ok(numerator) => numerator * unit.nanos() / 10i128.pow(fraction.len() as u32),
+                Err(_) => 0,
+            };
+            total += whole as i128 * unit.nanos() + fraction_nanos;
+            if total > -total_nanos(i64::MIN, 0) {
+                return Err(error(start, "duration out of range"));
+            }
+
+            position += bytes[position..]
+                .iter()
+                .take_while(|c| c.is_ascii_whitespace())
+                .count();
+            if position == bytes.len() {
+                if bytes[position - 1].is_ascii_whitespace() {
+                    return Err(error(position, "expected an amount"));
+                }
+                break;
+            }
+        }
+
+        of_total_nanos_checked(if negative { -total } else { total })
+            .map(|(seconds, nanos)| Duration {
+                seconds,
+                nanoseconds_of_second: nanos,
+            })
+            .ok_or_else(|| error(0, "duration out of range"))
+    }
+
+    /// Formats this duration as human-readable text, such as '1h 30m' or '-2d 4h 0.5s'.
+    ///
+    /// The duration is split into amounts of each unit from `largest` down to `smallest`, leaving out those that are zero.
+    /// What is left over below `smallest` is written as its fraction, truncated to `precision` digits
+    /// with trailing zeros left out. A zero duration is written as zero of the smallest unit, such as '0s'.
+    ///
+    /// The text is understood by [`parse_human()`], giving the same duration when nothing was truncated.
+    ///
+    /// # Parameters
+    ///  - `largest`: the largest unit to use, so that two days with a largest unit of hours are '48h'.
+    ///  - `smallest`: the smallest unit to use.
+    ///  - `precision`: the most digits after the decimal point of the smallest unit, up to 18.
+    ///
+    /// # Panics
+    /// - if `largest` is smaller than `smallest`.
+    ///
+    /// [`parse_human()`]: struct.Duration.html#method.parse_human
+    pub fn to_human_string(
+        &self,
+        largest: HumanUnit,
+        smallest: HumanUnit,
+        precision: usize,
+    ) -> String {
+        if largest < smallest {
+            panic!("largest unit is smaller than smallest");
+        }
+        let total = total_nanos(self.seconds, self.nanoseconds_of_second);
+        let mut remaining = total.abs();
+        let mut text = String::new();
+
+        let units = HumanUnit::ALL
+            .iter()
+            .rev()
+            .filter(|&&unit| unit <= largest && unit >= smallest);
+        for &unit in units {
+            let amount = remaining / unit.nanos();
+            remaining %= unit.nanos();
+            let fraction = if unit == smallest {
+                let digits = precision.min(MAX_FRACTION_DIGITS);
+                let scaled = remaining * 10i128.pow(digits as u32) / unit.nanos();
+                let fraction = format!("{:0width$}", scaled, width = digits);
+                fraction.trim_end_matches('0').to_string()
+            } else {
+                String::new()
+            };
+            if amount == 0 && fraction.is_empty() {
+                continue;
+            }
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            let _ = write!(text, "{}", amount);
+            if !fraction.is_empty() {
+                let _ = write!(text, ".{}", fraction);
+            }
+            text.push_str(unit.suffix());
+        }
+
+        if text.is_empty() {
+            // Everything was truncated away, so there is no sign either.
+            format!("0{}", smallest.suffix())
+        } else if total < 0 {
+            format!("-{}", text)
+        } else {
+            text
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{ChronoUnit, Duration, RoundingMode};
+
+#[test]
+fn truncated_to() {
+    let duration = Duration::of_nanos(90 * NANOSECONDS_IN_MINUTE + 1_500_000_001);
+
+    assert_eq!(duration, duration.truncated_to(ChronoUnit::Nanos));
+    assert_eq!(
+        Duration::of_millis(90 * 60_000 + 1_500),
+        duration.truncated_to(ChronoUnit::Millis)
+    );
+    assert_eq!(
+        Duration::of_minutes(90),
+        duration.truncated_to(ChronoUnit::Minutes)
+    );
+    assert_eq!(
+        Duration::of_hours(1),
+        duration.truncated_to(ChronoUnit::Hours)
+    );
+    assert_eq!(Duration::ZERO, duration.truncated_to(ChronoUnit::Days));
+    assert_eq!(
+        Duration::of_seconds(-1),
+        Duration::of_millis(-1_500).truncated_to(ChronoUnit::Seconds)
+    );
+    assert_eq!(
+        Duration::of_days(-2),
+        Duration::of_hours(-71).truncated_to(ChronoUnit::Days)
+    );
+    assert_eq!(
+        Duration::of_seconds(i64::MIN),
+        Duration::MIN.truncated_to(ChronoUnit::Seconds)
+    );
+}
+
+#[test]
+fn truncated_to_long_unit() {
+    expect_panic("unit is too large for truncation", || {
+        Duration::ZERO.truncated_to(ChronoUnit::Weeks)
+    })
+    .unwrap();
+}
+
+#[test]
+fn rounded_to() {
+    let quarter = Duration::of_minutes(15);
+    let round = |minutes, seconds, mode| {
+        Duration::of_seconds(minutes * SECONDS_IN_MINUTE + seconds).rounded_to(quarter, mode)
+    };
+
+    assert_eq!(
+        Duration::of_minutes(15),
+        round(22, 29, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Duration::of_minutes(30),
+        round(22, 30, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Duration::of_minutes(30),
+        round(22, 30, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        Duration::of_minutes(0),
+        round(7, 30, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        Duration::of_minutes(-15),
+        round(-7, -30, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Duration::of_minutes(0),
+        round(-7, -30, RoundingMode::HalfEven)
+    );
+    assert_eq!(Duration::of_minutes(15), round(29, 59, RoundingMode::Floor));
+    assert_eq!(
+        Duration::of_minutes(-30),
+        round(-15, -1, RoundingMode::Floor)
+    );
+    assert_eq!(
+        Duration::of_minutes(30),
+        round(15, 1, RoundingMode::Ceiling)
+    );
+    assert_eq!(
+        Duration::of_minutes(-15),
+        round(-29, -59, RoundingMode::Ceiling)
+    );
+    assert_eq!(
+        Duration::of_minutes(45),
+        round(45, 0, RoundingMode::Ceiling)
+    );
+}
+
+#[test]
+fn rounded_to_invalid() {
+    expect_panic("granularity must be positive", || {
+        Duration::ZERO.rounded_to(Duration::ZERO, RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("granularity must be positive", || {
+        Duration::ZERO.rounded_to(Duration::of_seconds(-1), RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("duration would overflow", || {
+        Duration::MAX.rounded_to(Duration::of_seconds(1), RoundingMode::Ceiling)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn rounded_to_nearest_multiple(
+        seconds in -1_000_000_000_000..1_000_000_000_000i64,
+        nanos in 0..NANOSECONDS_IN_SECOND,
+        granularity in 1..1_000_000_000_000i64,
+    ) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let granularity = Duration::of_nanos(granularity);
+        let total = |duration: Duration| {
+            duration.seconds() as i128 * NANOSECONDS_IN_SECOND as i128 + duration.nano() as i128
+        };
+
+        let floor = duration.rounded_to(granularity, RoundingMode::Floor);
+        let ceiling = duration.rounded_to(granularity, RoundingMode::Ceiling);
+
+        prop_assert_eq!(0, total(floor) % total(granularity));
+        prop_assert!(floor <= duration && duration <= ceiling);
+        prop_assert!(total(ceiling) - total(floor) <= total(granularity));
+        for &mode in &[RoundingMode::HalfUp, RoundingMode::HalfEven] {
+            let nearest = total(duration.rounded_to(granularity, mode));
+            prop_assert!(nearest == total(floor) || nearest == total(ceiling));
+            prop_assert!((nearest - total(duration)).abs() * 2 <= total(granularity));
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use proptest::prelude::*;
+
+use crate::assert::expect_panic;
+use crate::constants::*;
+
+use crate::{Duration, HumanUnit, ParseHumanDurationError, ParseIsoError};
+
+fn error(text: &str) -> (usize, &'static str) {
+    let error: ParseIsoError = text.parse::<Duration>().unwrap_err();
+    (error.position(), error.reason())
+}
+
+fn human_error(text: &str) -> (usize, &'static str) {
+    let error: ParseHumanDurationError = Duration::parse_human(text).unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn display() {
+    assert_eq!("PT0S", Duration::ZERO.to_string());
+    assert_eq!(
+        "PT8H6M12.345S",
+        Duration::of_seconds_and_adjustment(
+            8 * SECONDS_IN_HOUR + 6 * SECONDS_IN_MINUTE + 12,
+            345_000_000
+        )
+        .to_string()
+    );
+    assert_eq!("PT48H", Duration::of_days(2).to_string());
+    assert_eq!(
+        "PT-6H-3M",
+        Duration::of_minutes(-6 * MINUTES_IN_HOUR - 3).to_string()
+    );
+    assert_eq!("PT-0.5S", Duration::of_millis(-500).to_string());
+    assert_eq!("PT-1.5S", Duration::of_millis(-1_500).to_string());
+    assert_eq!("PT0.000000001S", Duration::of_nanos(1).to_string());
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Ok(Duration::of_millis(20_345)), "PT20.345S".parse());
+    assert_eq!(Ok(Duration::of_minutes(15)), "PT15M".parse());
+    assert_eq!(Ok(Duration::of_hours(10)), "PT10H".parse());
+    assert_eq!(Ok(Duration::of_days(2)), "P2D".parse());
+    assert_eq!(
+        Ok(Duration::of_minutes(2 * 24 * 60 + 3 * 60 + 4)),
+        "P2DT3H4M".parse()
+    );
+    assert_eq!(Ok(Duration::of_minutes(-6 * 60 + 3)), "PT-6H3M".parse());
+    assert_eq!(Ok(Duration::of_minutes(-6 * 60 - 3)), "-PT6H3M".parse());
+    assert_eq!(Ok(Duration::of_minutes(6 * 60 - 3)), "-PT-6H+3M".parse());
+    assert_eq!(Ok(Duration::of_millis(-500)), "PT-0.5S".parse());
+    assert_eq!(Ok(Duration::of_seconds(1)), "pt1s".parse());
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!((0, "expected 'P'"), error(""));
+    assert_eq!((1, "expected an amount"), error("P"));
+    assert_eq!((2, "expected an amount"), error("PT"));
+    assert_eq!((2, "expected a unit"), error("P1H"));
+    assert_eq!((5, "only seconds may have a fraction"), error("PT1.5M"));
+    assert_eq!((5, "expected a unit"), error("PT1S1M"));
+    assert_eq!((2, "invalid amount"), error("PT9223372036854775808S"));
+    assert_eq!((0, "duration out of range"), error("P106751991167301D"));
+}
+
+proptest! {
+    #[test]
+    fn round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(duration), duration.to_string().parse());
+    }
+}
+
+#[test]
+fn parse_human() {
+    assert_eq!(
+        Ok(Duration::of_minutes(90)),
+        Duration::parse_human("1h 30m")
+    );
+    assert_eq!(Ok(Duration::of_seconds(90)), Duration::parse_human("90s"));
+    assert_eq!(Ok(Duration::of_hours(52)), Duration::parse_human("2d4h"));
+    assert_eq!(Ok(Duration::of_days(14)), Duration::parse_human("2w"));
+    assert_eq!(Ok(Duration::of_minutes(90)), Duration::parse_human("1.5h"));
+    assert_eq!(Ok(Duration::of_millis(500)), Duration::parse_human(".5s"));
+    assert_eq!(
+        Ok(Duration::of_seconds(-90)),
+        Duration::parse_human("-1m30s")
+    );
+    assert_eq!(
+        Ok(Duration::of_seconds(90)),
+        Duration::parse_human("+1m  30s")
+    );
+    assert_eq!(
+        Ok(Duration::of_nanos(1_500)),
+        Duration::parse_human("1us500ns")
+    );
+    assert_eq!(
+        Ok(Duration::of_nanos(2_000)),
+        Duration::parse_human("1µs1μs")
+    );
+    assert_eq!(Ok(Duration::of_millis(-2)), Duration::parse_human("-2ms"));
+    assert_eq!(Ok(Duration::ZERO), Duration::parse_human("0"));
+    assert_eq!(Ok(Duration::ZERO), Duration::parse_human("-0"));
+    assert_eq!(
+        Ok(Duration::of_nanos(1)),
+        Duration::parse_human("0.0000000019999s")
+    );
+    assert_eq!(
+        Ok(Duration::MIN),
+        Duration::parse_human("-9223372036854775808s")
+    );
+}
+
+#[test]
+fn parse_human_invalid() {
+    assert_eq!((0, "expected an amount"), human_error(""));
+    assert_eq!((1, "expected an amount"), human_error("-"));
+    assert_eq!((0, "expected an amount"), human_error(" 1s"));
+    assert_eq!((3, "expected an amount"), human_error("1s "));
+    assert_eq!((2, "expected a unit"), human_error("90"));
+    assert_eq!((4, "expected a unit"), human_error("1h30"));
+    assert_eq!(
+        (1, "ambiguous unit 'M', use 'm' for minutes"),
+        human_error("1M")
+    );
+    assert_eq!(
+        (1, "months and years have no fixed length"),
+        human_error("1y")
+    );
+    assert_eq!(
+        (2, "months and years have no fixed length"),
+        human_error("10mo")
+    );
+    assert_eq!((1, "unknown unit"), human_error("1H"));
+    assert_eq!((1, "unknown unit"), human_error("1sec"));
+    assert_eq!(
+        (0, "amount out of range"),
+        human_error("18446744073709551616s")
+    );
+    assert_eq!(
+        (0, "duration out of range"),
+        human_error("9223372036854775808s")
+    );
+    assert_eq!(
+        (0, "duration out of range"),
+        human_error("9223372036854775807s 1s")
+    );
+    assert_eq!(
+        (21, "duration out of range"),
+        human_error("9223372036854775807s 2s")
+    );
+}
+
+#[test]
+fn to_human_string() {
+    let duration = Duration::of_seconds_and_adjustment(
+        2 * SECONDS_IN_DAY + 4 * SECONDS_IN_HOUR + 15,
+        250_000_000,
+    );
+    let human = |duration: Duration, largest, smallest, precision| {
+        duration.to_human_string(largest, smallest, precision)
+    };
+
+    assert_eq!(
+        "2d 4h 15s 250ms",
+        human(duration, HumanUnit::Weeks, HumanUnit::Nanos, 0)
+    );
+    assert_eq!(
+        "52h 15s",
+        human(duration, HumanUnit::Hours, HumanUnit::Seconds, 0)
+    );
+    assert_eq!(
+        "52h 15.2s",
+        human(duration, HumanUnit::Hours, HumanUnit::Seconds, 1)
+    );
+    assert_eq!(
+        "2.16d",
+        human(duration, HumanUnit::Days, HumanUnit::Days, 2)
+    );
+    assert_eq!(
+        "-1m 30s",
+        human(
+            Duration::of_seconds(-90),
+            HumanUnit::Weeks,
+            HumanUnit::Nanos,
+            9
+        )
+    );
+    assert_eq!(
+        "-1.5m",
+        human(
+            Duration::of_seconds(-90),
+            HumanUnit::Minutes,
+            HumanUnit::Minutes,
+            3
+        )
+    );
+    assert_eq!(
+        "2w",
+        human(
+            Duration::of_days(14),
+            HumanUnit::Weeks,
+            HumanUnit::Seconds,
+            0
+        )
+    );
+    assert_eq!(
+        "0s",
+        human(Duration::ZERO, HumanUnit::Hours, HumanUnit::Seconds, 0)
+    );
+    assert_eq!(
+        "0s",
+        human(
+            Duration::of_millis(-5),
+            HumanUnit::Hours,
+            HumanUnit::Seconds,
+            2
+        )
+    );
+    assert_eq!(
+        "1500ms",
+        human(
+            Duration::of_millis(1_500),
+            HumanUnit::Millis,
+            HumanUnit::Millis,
+            0
+        )
+    );
+}
+
+#[test]
+fn to_human_string_invalid_units() {
+    expect_panic("largest unit is smaller than smallest", || {
+        Duration::ZERO.to_human_string(HumanUnit::Seconds, HumanUnit::Hours, 0)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn human_round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let text = duration.to_human_string(HumanUnit::Weeks, HumanUnit::Seconds, 9);
+
+        prop_assert_eq!(Ok(duration), Duration::parse_human(&text));
+    }
+}
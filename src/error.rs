@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::{
+    ChronoField, ChronoUnit, ParseDateTimeError, ParseHumanDurationError, ParseInternetDateError,
+    ParseIsoError,
+};
+
+#[cfg(test)]
+pub mod constructors;
+
+/// An error obtaining a date, time or amount of time, returned by the `try_*` constructors.
+///
+/// Each panicking constructor, such as [`LocalDate::of()`], has a `try_*` counterpart, such as [`LocalDate::try_of()`],
+/// for values that come from outside the program. The parse errors of each text format convert into this with `?`.
+///
+/// [`LocalDate::of()`]: struct.LocalDate.html#method.of
+/// [`LocalDate::try_of()`]: struct.LocalDate.html#method.try_of
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DateTimeError {
+    /// The value would be outside the range that can be held, such as a duration of more than `i64::MAX` seconds.
+    Overflow,
+    /// A field was given a value outside its range, such as a month of 13.
+    InvalidField {
+        /// The field that was given.
+        field: ChronoField,
+        /// The value it was given.
+        value: i64,
+        /// The values the field may have.
+        range: RangeInclusive<i64>,
+    },
+    /// An argument that is not a date or time field is out of range, such as a latitude of 91 degrees.
+    ///
+    /// This holds what was wrong, such as 'latitude out of range'.
+    InvalidArgument(&'static str),
+    /// The value does not have the field, such as the hour of the day of a date.
+    UnsupportedField(ChronoField),
+    /// The value can't be measured or moved in the unit, such as hours for a date.
+    UnsupportedUnit(ChronoUnit),
+    /// Text could not be parsed.
+    Parse {
+        /// The (byte) position in the text the error was found at.
+        position: usize,
+        /// A short description of what was wrong.
+        reason: &'static str,
+    },
+    /// A time-zone ID is not known.
+    ///
+    /// There are no time-zone rules in this crate, so nothing returns this yet; only offsets from UTC can be resolved.
+    UnknownZone,
+    /// The TAI - UTC table has expired before the instant, so it can't be related to UTC.
+    ///
+    /// Tables carry their last offset forward, so nothing returns this yet.
+    LeapTableExpired,
+    /// The instant is before UTC was defined, at the start of the TAI - UTC table in 1961,
+    /// so it can't be related to UTC.
+    BeforeUtc,
+}
+
+impl DateTimeError {
+    // Checks that a field is within its range.
+    pub(crate) fn check(
+        field: ChronoField,
+        value: i64,
+        range: RangeInclusive<i64>,
+    ) -> Result<i64, DateTimeError> {
+        if range.contains(&value) {
+            Ok(value)
+        } else {
+            Err(DateTimeError::InvalidField {
+                field,
+                value,
+                range,
+            })
+        }
+    }
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateTimeError::Overflow => write!(f, "value out of range"),
+            DateTimeError::InvalidField {
+                field,
+                value,
+                range,
+            } => write!(
+                f,
+                "invalid value for {:?} (valid values {} - {}): {}",
+                field,
+                range.start(),
+                range.end(),
+                value
+            ),
+            DateTimeError::InvalidArgument(reason) => write!(f, "invalid argument: {}", reason),
+            DateTimeError::UnsupportedField(field) => write!(f, "unsupported field: {:?}", field),
+            DateTimeError::UnsupportedUnit(unit) => write!(f, "unsupported unit: {:?}", unit),
+            DateTimeError::Parse { position, reason } => {
+                write!(f, "invalid text at position {}: {}", position, reason)
+            }
+            DateTimeError::UnknownZone => write!(f, "unknown time-zone"),
+            DateTimeError::LeapTableExpired => {
+                write!(f, "instant is not covered by the TAI - UTC table")
+            }
+            DateTimeError::BeforeUtc => write!(f, "instant is before UTC was defined"),
+        }
+    }
+}
+
+impl Error for DateTimeError {}
+
+macro_rules! from_parse_error {
+    ($error:ty) => {
+        impl From<$error> for DateTimeError {
+            fn from(error: $error) -> DateTimeError {
+                DateTimeError::Parse {
+                    position: error.position(),
+                    reason: error.reason(),
+                }
+            }
+        }
+    };
+}
+
+from_parse_error!(ParseIsoError);
+from_parse_error!(ParseHumanDurationError);
+from_parse_error!(ParseDateTimeError);
+from_parse_error!(ParseInternetDateError);
@@ -0,0 +1,327 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{
+    CcsCalendar, CcsFormat, CdsFormat, CdsResolution, ChronoField, CucFormat, DateTimeError,
+    DayOfWeek, Duration, Instant, LinearSmear, LocalDate, ModeledUniversalTime, Observer,
+    OffsetDateTime, Period, PtpTimestamp, TaiUtcTable, UtcInstant,
+};
+
+fn invalid(field: ChronoField, value: i64, min: i64, max: i64) -> DateTimeError {
+    DateTimeError::InvalidField {
+        field,
+        value,
+        range: min..=max,
+    }
+}
+
+#[test]
+fn durations() {
+    assert_eq!(Ok(Duration::of_days(2)), Duration::try_of_days(2));
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_days(i64::MAX)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_hours(i64::MIN)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_minutes(i64::MAX)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_seconds_and_adjustment(i64::MAX, NANOSECONDS_IN_SECOND)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_total_nanos(Duration::MAX.to_total_nanos() + 1)
+    );
+    assert_eq!(
+        Ok(Duration::of_millis(1_500)),
+        Duration::try_of_seconds_f64(1.5)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Duration::try_of_seconds_f64(f64::NAN)
+    );
+    assert_eq!(Err(DateTimeError::Overflow), Period::try_of_weeks(i32::MAX));
+}
+
+#[test]
+fn instants() {
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Instant::try_of_epoch_second_and_adjustment(i64::MIN, -1)
+    );
+    assert_eq!(
+        Ok(Instant::MAX),
+        Instant::try_of_epoch_nano(Instant::MAX.to_epoch_nano())
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Instant::try_of_epoch_nano(i128::MAX)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Instant::try_of_julian_ephemeris_day(f64::INFINITY)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        ModeledUniversalTime::try_of_epoch_second_and_adjustment(i64::MAX, NANOSECONDS_IN_SECOND)
+    );
+    assert_eq!(
+        Ok(ModeledUniversalTime::of_epoch_second_and_adjustment(
+            -1,
+            NANOSECONDS_IN_SECOND + 1
+        )),
+        ModeledUniversalTime::try_of_epoch_second_and_adjustment(-1, NANOSECONDS_IN_SECOND + 1)
+    );
+    assert_eq!(
+        Err(invalid(
+            ChronoField::NanoOfDay,
+            -1,
+            0,
+            NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND - 1
+        )),
+        UtcInstant::try_of_modified_julian_day(0, -1)
+    );
+    assert_eq!(
+        Err(invalid(
+            ChronoField::InstantSeconds,
+            1 << 48,
+            0,
+            (1 << 48) - 1
+        )),
+        PtpTimestamp::try_of(1 << 48, 0)
+    );
+    assert_eq!(
+        Err(invalid(
+            ChronoField::NanoOfSecond,
+            NANOSECONDS_IN_SECOND,
+            0,
+            NANOSECONDS_IN_SECOND - 1
+        )),
+        PtpTimestamp::try_of(0, NANOSECONDS_IN_SECOND as u32)
+    );
+}
+
+#[test]
+fn dates() {
+    assert_eq!(
+        Ok(LocalDate::of(2008, 2, 29)),
+        LocalDate::try_of(2008, 2, 29)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::Year, MAX_YEAR + 1, MIN_YEAR, MAX_YEAR)),
+        LocalDate::try_of(MAX_YEAR + 1, 1, 1)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::MonthOfYear, 13, 1, 12)),
+        LocalDate::try_of(2007, 13, 1)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::DayOfMonth, 29, 1, 28)),
+        LocalDate::try_of(2007, 2, 29)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::DayOfYear, 366, 1, 365)),
+        LocalDate::try_of_year_day(2007, 366)
+    );
+    assert_eq!(
+        Err(invalid(
+            ChronoField::EpochDay,
+            i64::MAX,
+            LocalDate::MIN.to_epoch_day(),
+            LocalDate::MAX.to_epoch_day()
+        )),
+        LocalDate::try_of_epoch_day(i64::MAX)
+    );
+    assert_eq!(Ok(DayOfWeek::Sunday), DayOfWeek::try_of(7));
+    assert_eq!(
+        Err(invalid(ChronoField::DayOfWeek, 0, 1, 7)),
+        DayOfWeek::try_of(0)
+    );
+}
+
+#[test]
+fn date_times() {
+    let date = LocalDate::of(2016, 12, 31);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(date, 23, 59, 60, 0, 0)),
+        OffsetDateTime::try_of(date, 23, 59, 60, 0, 0)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::HourOfDay, 24, 0, 23)),
+        OffsetDateTime::try_of(date, 24, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::SecondOfMinute, 61, 0, 60)),
+        OffsetDateTime::try_of(date, 0, 0, 61, 0, 0)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::SecondOfMinute, 60, 0, 59)),
+        OffsetDateTime::try_of(date, 12, 0, 60, 0, 0)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::OffsetSeconds, 64_801, -64_800, 64_800)),
+        OffsetDateTime::try_of(date, 0, 0, 0, 0, 64_801)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        OffsetDateTime::try_of_utc(
+            UtcInstant::of_modified_julian_day(
+                LocalDate::MAX.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+                NANOSECONDS_IN_DAY - 1
+            ),
+            3_600
+        )
+    );
+
+    let table = TaiUtcTable::default();
+    assert_eq!(
+        Err(DateTimeError::BeforeUtc),
+        OffsetDateTime::try_of_instant(Instant::MIN, 0, &table)
+    );
+    // Around '1957-10-04T19:28:34Z', before UTC was defined.
+    assert_eq!(
+        Err(DateTimeError::BeforeUtc),
+        OffsetDateTime::try_of_instant(Instant::of_epoch_second(-386_310_686), 0, &table)
+    );
+    assert_eq!(
+        "instant is before UTC was defined",
+        DateTimeError::BeforeUtc.to_string()
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of_utc(
+            UtcInstant::of_modified_julian_day(40_587, 0),
+            0
+        )),
+        OffsetDateTime::try_of_instant(
+            table
+                .to_instant(UtcInstant::of_modified_julian_day(40_587, 0))
+                .unwrap(),
+            0,
+            &table
+        )
+    );
+}
+
+#[test]
+fn parse_errors_convert() {
+    fn parse(text: &str) -> Result<LocalDate, DateTimeError> {
+        Ok(text.parse::<LocalDate>()?)
+    }
+
+    assert_eq!(
+        Err(DateTimeError::Parse {
+            position: 4,
+            reason: "expected '-'"
+        }),
+        parse("2007/12/03")
+    );
+    assert_eq!(
+        "invalid value for MonthOfYear (valid values 1 - 12): 13",
+        LocalDate::try_of(2007, 13, 1).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn other_arguments() {
+    let argument = DateTimeError::InvalidArgument;
+    assert_eq!(
+        Err(argument("smear window out of range")),
+        LinearSmear::try_of(Duration::ZERO, Duration::ZERO)
+    );
+    assert_eq!(
+        Err(argument("smear window out of range")),
+        LinearSmear::try_of(
+            Duration::of_seconds(1),
+            Duration::of_seconds_and_adjustment(SECONDS_IN_DAY, 1)
+        )
+    );
+    assert_eq!(
+        Ok(LinearSmear::of(Duration::of_days(1), Duration::ZERO)),
+        LinearSmear::try_of(Duration::of_days(1), Duration::ZERO)
+    );
+    assert_eq!(
+        Err(argument("latitude out of range")),
+        Observer::try_of(90.5, 0.0)
+    );
+    assert_eq!(
+        Err(argument("longitude out of range")),
+        Observer::try_of(0.0, -180.5)
+    );
+    assert_eq!(
+        Err(argument("latitude out of range")),
+        Observer::try_of(f64::NAN, 0.0)
+    );
+    let observer = Observer::of(51.5, -0.1);
+    assert_eq!(Ok(observer), Observer::try_of(51.5, -0.1));
+    assert_eq!(
+        Err(argument("elevation out of range")),
+        observer.try_with_elevation(-1.0)
+    );
+    assert_eq!(
+        Err(argument("elevation out of range")),
+        observer.try_with_elevation(f64::INFINITY)
+    );
+    assert_eq!(
+        Ok(observer.with_elevation(100.0)),
+        observer.try_with_elevation(100.0)
+    );
+    assert_eq!(
+        Err(argument("refraction out of range")),
+        observer.try_with_refraction(f64::NAN)
+    );
+    assert_eq!(
+        Ok(observer.with_refraction(0.0)),
+        observer.try_with_refraction(0.0)
+    );
+    assert_eq!(
+        Err(argument("octet count out of range")),
+        CucFormat::try_of(0, 0)
+    );
+    assert_eq!(
+        Err(argument("octet count out of range")),
+        CucFormat::try_of(8, 0)
+    );
+    assert_eq!(
+        Err(argument("octet count out of range")),
+        CucFormat::try_of(4, 11)
+    );
+    assert_eq!(Ok(CucFormat::of(7, 10)), CucFormat::try_of(7, 10));
+    assert_eq!(
+        Err(argument("octet count out of range")),
+        CdsFormat::try_of(4, CdsResolution::Milliseconds)
+    );
+    assert_eq!(
+        Ok(CdsFormat::of(3, CdsResolution::Picoseconds)),
+        CdsFormat::try_of(3, CdsResolution::Picoseconds)
+    );
+    assert_eq!(
+        Err(argument("octet count out of range")),
+        CcsFormat::try_of(CcsCalendar::MonthDay, 7)
+    );
+    assert_eq!(
+        Ok(CcsFormat::of(CcsCalendar::DayOfYear, 6)),
+        CcsFormat::try_of(CcsCalendar::DayOfYear, 6)
+    );
+    assert_eq!(
+        "invalid argument: latitude out of range",
+        Observer::try_of(-91.0, 0.0).unwrap_err().to_string()
+    );
+}
+
+proptest! {
+    #[test]
+    fn try_of_date_agrees_with_of(year in MIN_YEAR..=MAX_YEAR, month in 0u32..=13, day in 0u32..=32) {
+        match LocalDate::of_checked(year, month, day) {
+            Some(date) => prop_assert_eq!(Ok(date), LocalDate::try_of(year, month, day)),
+            None => prop_assert!(LocalDate::try_of(year, month, day).is_err()),
+        }
+    }
+}
@@ -0,0 +1,943 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+
+use crate::calendar::*;
+use crate::constants::*;
+#[cfg(feature = "locales")]
+use crate::locale::FormatStyle;
+use crate::locale::{LocaleData, ROOT};
+use crate::offset_date_time::MAX_OFFSET_SECONDS;
+use crate::{ChronoField, LocalDate, OffsetDateTime, TemporalAccessor};
+
+#[cfg(feature = "locales")]
+mod localized;
+#[cfg(test)]
+pub mod parsing;
+#[cfg(test)]
+pub mod patterns;
+mod resolver;
+mod strftime;
+
+use self::resolver::Fields;
+pub use self::resolver::{ParseDateTimeError, Parsed, ResolverStyle};
+
+const MAX_WIDTH: usize = 19;
+const NANO_DIGITS: usize = 9;
+// Two digit years are read as from 2000 to 2099, as in java.time.
+const REDUCED_YEAR_BASE: i64 = 2000;
+// The offset patterns of one to five 'X' or 'x': '+HHmm', '+HHMM', '+HH:MM', '+HHMMss' and '+HH:MM:ss'.
+const OFFSET_PATTERNS: [usize; 5] = [1, 3, 4, 5, 6];
+
+/// A formatter for dates, times and instants, compiled from a java.time style pattern such as 'uuuu-MM-dd HH:mm'.
+///
+/// Compile a pattern once with [`of_pattern`](#method.of_pattern), then use it to format anything implementing [`TemporalAccessor`].
+///
+/// The pattern letters are those of java.time's `DateTimeFormatter`:
+///
+/// | Letter | Meaning | Examples |
+/// |---|---|---|
+/// | `G` | era | AD; Anno Domini; A |
+/// | `u` | year | 2004; 04 |
+/// | `y` | year of era | 2004; 04 |
+/// | `D` | day of year | 189 |
+/// | `g` | modified Julian day | 51334 |
+/// | `M`/`L` | month of year | 7; 07; Jul; July; J |
+/// | `d` | day of month | 10 |
+/// | `Q` | quarter of year | 3; 03; Q3; 3rd quarter |
+/// | `Y` | week-based year | 1996; 96 |
+/// | `w` | week of week-based year | 27 |
+/// | `W` | week of month | 4 |
+/// | `E` | day of week | Tue; Tuesday; T |
+/// | `e` | localized day of week | 2; 02; Tue; Tuesday; T |
+/// | `a` | am-pm of day | PM |
+/// | `h` | clock hour of am-pm (1-12) | 12 |
+/// | `K` | hour of am-pm (0-11) | 0 |
+/// | `k` | clock hour of day (1-24) | 24 |
+/// | `H` | hour of day (0-23) | 0 |
+/// | `m` | minute of hour | 30 |
+/// | `s` | second of minute | 55 |
+/// | `S` | fraction of second | 978 |
+/// | `A` | milli of day | 1234 |
+/// | `n` | nano of second | 987654321 |
+/// | `N` | nano of day | 1234000000 |
+/// | `V` | time-zone ID | America/Los_Angeles |
+/// | `z` | time-zone name | America/Los_Angeles |
+/// | `O` | localized offset | GMT+8; GMT+08:00 |
+/// | `X` | offset, 'Z' for zero | Z; -08; -0830; -08:30 |
+/// | `x` | offset | +0000; -08; -0830; -08:30 |
+/// | `Z` | offset | +0000; -0800; -08:00 |
+///
+/// Text in single quotes is copied as is (with `''` for a quote), as are characters other than letters.
+/// Square brackets mark an optional section, left out when the value formatted lacks any of its fields.
+/// Weeks follow ISO-8601, starting on Monday, and names are in English.
+/// There is no time-zone name data, so `z` gives the ID.
+///
+/// Parsing matches the same pattern, reading names case-insensitively, then resolves the fields found
+/// into a date, time and offset according to the formatter's [`ResolverStyle`](enum.ResolverStyle.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DateTimeFormatter {
+    printers: Vec<Printer>,
+    resolver_style: ResolverStyle,
+    locale: &'static LocaleData,
+}
+
+/// An error compiling a formatter pattern.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParsePatternError {
+    position: usize,
+    reason: &'static str,
+}
+
+/// An error formatting a value, which lacks a field the pattern uses.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FormatError {
+    /// The value does not have the field.
+    UnsupportedField(ChronoField),
+    /// The value does not have a time-zone.
+    UnsupportedZone,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Field {
+    Chrono(ChronoField),
+    ModifiedJulianDay,
+    // The seconds since '1970-01-01T00:00:00Z' ignoring leap seconds, as strftime's '%s'.
+    UnixSeconds,
+    QuarterOfYear,
+    WeekBasedYear(Weeks),
+    WeekOfWeekBasedYear(Weeks),
+    WeekOfMonth,
+    LocalizedDayOfWeek,
+}
+
+// Whether weeks follow ISO-8601 or the rules of the formatter's locale.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Weeks {
+    Iso,
+    Localized,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TextStyle {
+    Full,
+    Short,
+    Narrow,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Printer {
+    Literal(String),
+    Value {
+        field: Field,
+        min_width: usize,
+        max_width: usize,
+        // Whether the value may have a sign, which is required if it is wider than the minimum.
+        signed: bool,
+        exceeds_pad: bool,
+        // Whether to pad with spaces rather than zeros, as strftime's '%e' does.
+        space_pad: bool,
+    },
+    // Two digits of the year, as in '2004' -> '04', and the first year they are read as.
+    ReducedValue(Field, i64),
+    Fraction(usize),
+    Text(Field, TextStyle),
+    // The index of one of java.time's offset patterns, from '+HH' to '+HH:MM:SS', and the text for a zero offset.
+    Offset(usize, &'static str),
+    LocalizedOffset(TextStyle),
+    ZoneId,
+    Optional(Vec<Printer>),
+    // The localized date and time styles, and the printers for them in the formatter's locale.
+    #[cfg(feature = "locales")]
+    Localized(Option<FormatStyle>, Option<FormatStyle>, Vec<Printer>),
+}
+
+impl DateTimeFormatter {
+    /// Compiles a formatter from a pattern, such as 'uuuu-MM-dd HH:mm:ss.SSS XXX'.
+    ///
+    /// # Parameters
+    ///  - `pattern`: the pattern, using the letters in the table above.
+    pub fn of_pattern(pattern: &str) -> Result<DateTimeFormatter, ParsePatternError> {
+        let chars: Vec<(usize, char)> = pattern.char_indices().collect();
+        let mut sections = vec![Vec::new()];
+        let mut i = 0;
+        while i < chars.len() {
+            let (position, c) = chars[i];
+            let error = |reason| ParsePatternError { position, reason };
+            let printer = if c.is_ascii_alphabetic() {
+                let count = chars[i..].iter().take_while(|&&(_, d)| d == c).count();
+                i += count;
+                letter(c, count).map_err(error)?
+            } else if c == '\'' {
+                i += 1;
+                let mut text = String::new();
+                if chars.get(i).map(|&(_, d)| d) == Some('\'') {
+                    text.push('\'');
+                    i += 1;
+                } else {
+                    loop {
+                        match chars.get(i) {
+                            None => return Err(error("unterminated quote")),
+                            Some(&(_, '\'')) if chars.get(i + 1).map(|&(_, d)| d) == Some('\'') => {
+                                text.push('\'');
+                                i += 2;
+                            }
+                            Some(&(_, '\'')) => {
+                                i += 1;
+                                break;
+                            }
+                            Some(&(_, d)) => {
+                                text.push(d);
+                                i += 1;
+                            }
+                        }
+                    }
+                }
+                Printer::Literal(text)
+            } else if c == '[' {
+                i += 1;
+                sections.push(Vec::new());
+                continue;
+            } else if c == ']' {
+                i += 1;
+                if sections.len() == 1 {
+                    return Err(error("unmatched ']'"));
+                }
+                Printer::Optional(sections.pop().unwrap_or_default())
+            } else if c == '{' || c == '}' || c == '#' {
+                return Err(error("reserved character"));
+            } else {
+                i += 1;
+                Printer::Literal(c.to_string())
+            };
+            sections.last_mut().unwrap().push(printer);
+        }
+        // Like java.time, optional sections left open close at the end.
+        while sections.len() > 1 {
+            let section = sections.pop().unwrap_or_default();
+            sections
+                .last_mut()
+                .unwrap()
+                .push(Printer::Optional(section));
+        }
+        Ok(DateTimeFormatter {
+            printers: sections.pop().unwrap_or_default(),
+            resolver_style: ResolverStyle::default(),
+            locale: &ROOT,
+        })
+    }
+
+    /// Returns a copy of this formatter that resolves parsed fields with the given style.
+    ///
+    /// # Parameters
+    ///  - `resolver_style`: how strictly to resolve fields, by default `ResolverStyle::Smart`.
+    pub fn with_resolver_style(&self, resolver_style: ResolverStyle) -> DateTimeFormatter {
+        DateTimeFormatter {
+            printers: self.printers.clone(),
+            resolver_style,
+            locale: self.locale,
+        }
+    }
+
+    /// Gets the style used to resolve parsed fields.
+    pub const fn resolver_style(&self) -> ResolverStyle {
+        self.resolver_style
+    }
+
+    /// Formats a date, time or instant.
+    ///
+    /// # Parameters
+    ///  - `temporal`: the value to format.
+    ///
+    /// # Returns
+    /// The text, or an error if the value lacks a field the pattern uses (outside an optional section).
+    pub fn format<T: TemporalAccessor + ?Sized>(
+        &self,
+        temporal: &T,
+    ) -> Result<String, FormatError> {
+        let mut text = String::new();
+        format_all(&self.printers, temporal, self.locale, &mut text)?;
+        Ok(text)
+    }
+
+    /// Parses text, then resolves the fields found.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse, all of which must match the pattern.
+    ///
+    /// # Returns
+    /// The resolved fields, or an error giving where and why the text could not be parsed or resolved.
+    pub fn parse(&self, text: &str) -> Result<Parsed, ParseDateTimeError> {
+        let mut fields = Fields::default();
+        let position = parse_all(&self.printers, text, 0, self.locale, &mut fields)?;
+        if position < text.len() {
+            return Err(ParseDateTimeError::of(position, "unexpected trailing text"));
+        }
+        fields.resolve(self.resolver_style, self.locale)
+    }
+
+    /// Parses text that gives a date.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse, all of which must match the pattern.
+    pub fn parse_local_date(&self, text: &str) -> Result<LocalDate, ParseDateTimeError> {
+        self.parse(text)?
+            .date()
+            .ok_or_else(|| ParseDateTimeError::of(0, "text does not give a date"))
+    }
+
+    /// Parses text that gives a date, time and offset.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse, all of which must match the pattern.
+    pub fn parse_offset_date_time(&self, text: &str) -> Result<OffsetDateTime, ParseDateTimeError> {
+        self.parse(text)?
+            .to_offset_date_time()
+            .ok_or_else(|| ParseDateTimeError::of(0, "text does not give a valid offset date-time"))
+    }
+}
+
+impl ParsePatternError {
+    /// Gets the (byte) position in the pattern the error was found at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets a short description of what was wrong.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParsePatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid pattern at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl Error for ParsePatternError {}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::UnsupportedField(field) => write!(f, "unsupported field: {:?}", field),
+            FormatError::UnsupportedZone => write!(f, "unsupported field: time-zone"),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+// Compiles a run of `count` of the same pattern letter.
+fn letter(c: char, count: usize) -> Result<Printer, &'static str> {
+    // As in java.time, a single letter is as wide as needed, and more letters a fixed width.
+    let value = |field, max_count| {
+        if count > max_count {
+            Err("too many pattern letters")
+        } else {
+            Ok(Printer::Value {
+                field,
+                min_width: count,
+                max_width: if count == 1 { MAX_WIDTH } else { count },
+                signed: false,
+                exceeds_pad: false,
+                space_pad: false,
+            })
+        }
+    };
+    let wide_value = |field, signed| {
+        if count > MAX_WIDTH {
+            Err("too many pattern letters")
+        } else {
+            Ok(Printer::Value {
+                field,
+                min_width: count,
+                max_width: MAX_WIDTH,
+                signed,
+                exceeds_pad: signed && count >= 4,
+                space_pad: false,
+            })
+        }
+    };
+    let year = |field| {
+        if count == 2 {
+            Ok(Printer::ReducedValue(field, REDUCED_YEAR_BASE))
+        } else {
+            wide_value(field, true)
+        }
+    };
+    let number_or_text = |field| match count {
+        1 | 2 => value(field, 2),
+        _ => text(field, count),
+    };
+
+    match c {
+        'G' => text(Field::Chrono(ChronoField::Era), count.max(3)),
+        'u' => year(Field::Chrono(ChronoField::Year)),
+        'y' => year(Field::Chrono(ChronoField::YearOfEra)),
+        'Y' => year(Field::WeekBasedYear(Weeks::Localized)),
+        'M' | 'L' => number_or_text(Field::Chrono(ChronoField::MonthOfYear)),
+        'Q' => number_or_text(Field::QuarterOfYear),
+        'e' => number_or_text(Field::LocalizedDayOfWeek),
+        'E' => text(Field::Chrono(ChronoField::DayOfWeek), count.max(3)),
+        'a' if count == 1 => Ok(Printer::Text(
+            Field::Chrono(ChronoField::AmPmOfDay),
+            TextStyle::Short,
+        )),
+        'd' => value(Field::Chrono(ChronoField::DayOfMonth), 2),
+        'D' if count == 2 => Ok(Printer::Value {
+            field: Field::Chrono(ChronoField::DayOfYear),
+            min_width: 2,
+            max_width: 3,
+            signed: false,
+            exceeds_pad: false,
+            space_pad: false,
+        }),
+        'D' => value(Field::Chrono(ChronoField::DayOfYear), 3),
+        'g' => wide_value(Field::ModifiedJulianDay, true),
+        'w' => value(Field::WeekOfWeekBasedYear(Weeks::Localized), 2),
+        'W' => value(Field::WeekOfMonth, 1),
+        'H' => value(Field::Chrono(ChronoField::HourOfDay), 2),
+        'k' => value(Field::Chrono(ChronoField::ClockHourOfDay), 2),
+        'K' => value(Field::Chrono(ChronoField::HourOfAmPm), 2),
+        'h' => value(Field::Chrono(ChronoField::ClockHourOfAmPm), 2),
+        'm' => value(Field::Chrono(ChronoField::MinuteOfHour), 2),
+        's' => value(Field::Chrono(ChronoField::SecondOfMinute), 2),
+        'S' if count <= NANO_DIGITS => Ok(Printer::Fraction(count)),
+        'n' => wide_value(Field::Chrono(ChronoField::NanoOfSecond), false),
+        'N' => wide_value(Field::Chrono(ChronoField::NanoOfDay), false),
+        'A' => wide_value(Field::Chrono(ChronoField::MilliOfDay), false),
+        'V' if count == 2 => Ok(Printer::ZoneId),
+        'V' => Err("pattern letter 'V' must be used twice"),
+        'z' if count <= 4 => Ok(Printer::ZoneId),
+        'O' if count == 1 => Ok(Printer::LocalizedOffset(TextStyle::Short)),
+        'O' if count == 4 => Ok(Printer::LocalizedOffset(TextStyle::Full)),
+        'O' => Err("pattern letter 'O' must be used once or four times"),
+        'X' if count <= 5 => Ok(Printer::Offset(OFFSET_PATTERNS[count - 1], "Z")),
+        'x' if count <= 5 => Ok(Printer::Offset(
+            OFFSET_PATTERNS[count - 1],
+            ["+00", "+0000", "+00:00", "+0000", "+00:00"][count - 1],
+        )),
+        'Z' if count <= 3 => Ok(Printer::Offset(3, "+0000")),
+        'Z' if count == 4 => Ok(Printer::LocalizedOffset(TextStyle::Full)),
+        'Z' if count == 5 => Ok(Printer::Offset(6, "Z")),
+        'a' | 'S' | 'z' | 'X' | 'x' | 'Z' => Err("too many pattern letters"),
+        _ => Err("unknown pattern letter"),
+    }
+}
+
+fn text(field: Field, count: usize) -> Result<Printer, &'static str> {
+    match count {
+        3 => Ok(Printer::Text(field, TextStyle::Short)),
+        4 => Ok(Printer::Text(field, TextStyle::Full)),
+        5 => Ok(Printer::Text(field, TextStyle::Narrow)),
+        _ => Err("too many pattern letters"),
+    }
+}
+
+fn format_all<T: TemporalAccessor + ?Sized>(
+    printers: &[Printer],
+    temporal: &T,
+    locale: &LocaleData,
+    text: &mut String,
+) -> Result<(), FormatError> {
+    for printer in printers {
+        match printer {
+            Printer::Literal(literal) => text.push_str(literal),
+            Printer::Value {
+                field,
+                min_width,
+                exceeds_pad,
+                space_pad,
+                ..
+            } => {
+                let value = field_value(temporal, *field, locale)?;
+                if *space_pad {
+                    let _ = write!(text, "{:>1$}", value, min_width);
+                    continue;
+                }
+                if value < 0 {
+                    text.push('-');
+                } else if *exceeds_pad && value.to_string().len() > *min_width {
+                    text.push('+');
+                }
+                push_padded(text, value.unsigned_abs(), *min_width);
+            }
+            Printer::ReducedValue(field, _) => {
+                push_padded(
+                    text,
+                    field_value(temporal, *field, locale)?.unsigned_abs() % 100,
+                    2,
+                );
+            }
+            Printer::Fraction(digits) => {
+                let nano = field_value(temporal, Field::Chrono(ChronoField::NanoOfSecond), locale)?;
+                let fraction = nano as u64 / 10u64.pow((NANO_DIGITS - digits) as u32);
+                push_padded(text, fraction, *digits);
+            }
+            Printer::Text(field, style) => {
+                // Names of days are in ISO order, so look them up by the ISO day of the week;
+                // the localized number is only printed by 'e' and 'ee'.
+                let value = match field {
+                    Field::LocalizedDayOfWeek => {
+                        field_value(temporal, Field::Chrono(ChronoField::DayOfWeek), locale)?
+                    }
+                    _ => field_value(temporal, *field, locale)?,
+                };
+                match name(locale, *field, *style, value) {
+                    Some(name) => text.push_str(name),
+                    None => text.push_str(&value.to_string()),
+                }
+            }
+            Printer::Offset(pattern, zero) => {
+                let offset = temporal
+                    .get(ChronoField::OffsetSeconds)
+                    .ok_or(FormatError::UnsupportedField(ChronoField::OffsetSeconds))?;
+                push_offset(text, offset, *pattern, zero);
+            }
+            Printer::LocalizedOffset(style) => {
+                let offset = temporal
+                    .get(ChronoField::OffsetSeconds)
+                    .ok_or(FormatError::UnsupportedField(ChronoField::OffsetSeconds))?;
+                push_localized_offset(text, offset, *style);
+            }
+            Printer::ZoneId => {
+                text.push_str(temporal.zone_id().ok_or(FormatError::UnsupportedZone)?);
+            }
+            Printer::Optional(printers) => {
+                let mut section = String::new();
+                if format_all(printers, temporal, locale, &mut section).is_ok() {
+                    text.push_str(&section);
+                }
+            }
+            #[cfg(feature = "locales")]
+            Printer::Localized(_, _, printers) => format_all(printers, temporal, locale, text)?,
+        }
+    }
+    Ok(())
+}
+
+// Parses text against the printers from a (byte) position, adding the fields found, and returns the position reached.
+fn parse_all(
+    printers: &[Printer],
+    text: &str,
+    mut position: usize,
+    locale: &LocaleData,
+    fields: &mut Fields,
+) -> Result<usize, ParseDateTimeError> {
+    let bytes = text.as_bytes();
+    for (i, printer) in printers.iter().enumerate() {
+        let error = |position, reason| Err(ParseDateTimeError::of(position, reason));
+        match printer {
+            Printer::Literal(literal) => {
+                if !text[position..].starts_with(literal.as_str()) {
+                    return error(position, "unexpected text");
+                }
+                position += literal.len();
+            }
+            Printer::Value {
+                field,
+                min_width,
+                max_width,
+                signed,
+                exceeds_pad,
+                space_pad,
+            } => {
+                let start = position;
+                let mut min_width = *min_width;
+                let mut max_width = *max_width;
+                if *space_pad {
+                    let spaces = bytes[position..]
+                        .iter()
+                        .take(min_width - 1)
+                        .take_while(|&&c| c == b' ')
+                        .count();
+                    position += spaces;
+                    min_width -= spaces;
+                    max_width -= spaces;
+                }
+                let sign = match bytes.get(position) {
+                    Some(b'-') if *signed => Some(b'-'),
+                    Some(b'+') if *exceeds_pad => Some(b'+'),
+                    _ => None,
+                };
+                if sign.is_some() {
+                    position += 1;
+                }
+                // As java.time does for 'uuuuMMdd', leave the digits of fixed width values straight after this one to them.
+                let reserved: usize = printers[i + 1..]
+                    .iter()
+                    .map(fixed_width)
+                    .take_while(Option::is_some)
+                    .flatten()
+                    .sum();
+                let width = digits(bytes, position)
+                    .saturating_sub(reserved)
+                    .min(max_width);
+                if width < min_width {
+                    return error(position, "expected digits");
+                }
+                if *exceeds_pad && (sign == Some(b'+')) != (width > min_width) {
+                    return error(
+                        start,
+                        "expected '+' only before a value wider than the pattern",
+                    );
+                }
+                let value = match text[position..position + width].parse::<i64>() {
+                    Ok(value) if sign == Some(b'-') => -value,
+                    Ok(value) => value,
+                    Err(_) => return error(start, "value out of range"),
+                };
+                position += width;
+                match field {
+                    Field::ModifiedJulianDay => match value
+                        .checked_sub(MODIFIED_JULIAN_DAY_OF_EPOCH)
+                    {
+                        Some(epoch_day) => {
+                            fields.set(Field::Chrono(ChronoField::EpochDay), epoch_day, start)?
+                        }
+                        None => return error(start, "value out of range"),
+                    },
+                    Field::LocalizedDayOfWeek if (1..=DAYS_IN_WEEK_ISO).contains(&value) => {
+                        fields.set(*field, locale.iso_day_of_week(value), start)?
+                    }
+                    _ => fields.set(*field, value, start)?,
+                }
+            }
+            Printer::ReducedValue(field, base) => {
+                if digits(bytes, position) < 2 {
+                    return error(position, "expected digits");
+                }
+                let value =
+                    (bytes[position] - b'0') as i64 * 10 + (bytes[position + 1] - b'0') as i64;
+                // The first year on or after the base that ends in the digits.
+                let mut year = base - base.rem_euclid(100) + value;
+                if year < *base {
+                    year += 100;
+                }
+                fields.set(*field, year, position)?;
+                position += 2;
+            }
+            Printer::Fraction(width) => {
+                if digits(bytes, position) < *width {
+                    return error(position, "expected digits");
+                }
+                let fraction: i64 = text[position..position + width].parse().unwrap_or_default();
+                let nano = fraction * 10i64.pow((NANO_DIGITS - width) as u32);
+                fields.set(Field::Chrono(ChronoField::NanoOfSecond), nano, position)?;
+                position += width;
+            }
+            Printer::Text(field, style) => {
+                // Take the longest name that matches, so 'May' is not read as 'M'.
+                let found = (0..=MONTHS_IN_YEAR)
+                    .filter_map(|value| Some((value, name(locale, *field, *style, value)?)))
+                    .filter(|(_, name)| {
+                        bytes.len() >= position + name.len()
+                            && bytes[position..position + name.len()]
+                                .eq_ignore_ascii_case(name.as_bytes())
+                    })
+                    .max_by_key(|(value, name)| (name.len(), -value));
+                match found {
+                    Some((value, name)) => {
+                        fields.set(*field, value, position)?;
+                        position += name.len();
+                    }
+                    None => return error(position, "unknown name"),
+                }
+            }
+            Printer::Offset(pattern, zero) => {
+                position = match parse_offset(bytes, position, *pattern) {
+                    Some((offset, end)) => {
+                        fields.set(Field::Chrono(ChronoField::OffsetSeconds), offset, position)?;
+                        end
+                    }
+                    None if text[position..].starts_with(zero) => {
+                        fields.set(Field::Chrono(ChronoField::OffsetSeconds), 0, position)?;
+                        position + zero.len()
+                    }
+                    None => return error(position, "invalid offset"),
+                };
+            }
+            Printer::LocalizedOffset(_) => {
+                if !text[position..].starts_with("GMT") {
+                    return error(position, "expected 'GMT'");
+                }
+                let (offset, end) = parse_localized_offset(bytes, position + 3)
+                    .ok_or_else(|| ParseDateTimeError::of(position, "invalid offset"))?;
+                fields.set(Field::Chrono(ChronoField::OffsetSeconds), offset, position)?;
+                position = end;
+            }
+            Printer::ZoneId => {
+                let length = match bytes.get(position) {
+                    Some(c) if c.is_ascii_alphabetic() => bytes[position..]
+                        .iter()
+                        .take_while(|&&c| c.is_ascii_alphanumeric() || b"~/._+-".contains(&c))
+                        .count(),
+                    _ => return error(position, "expected time-zone ID"),
+                };
+                fields.set_zone_id(&text[position..position + length], position)?;
+                position += length;
+            }
+            Printer::Optional(printers) => {
+                let mut section = fields.clone();
+                if let Ok(end) = parse_all(printers, text, position, locale, &mut section) {
+                    *fields = section;
+                    position = end;
+                }
+            }
+            #[cfg(feature = "locales")]
+            Printer::Localized(_, _, printers) => {
+                position = parse_all(printers, text, position, locale, fields)?;
+            }
+        }
+    }
+    Ok(position)
+}
+
+// The number of digits a printer always reads, if it is numeric and has a fixed width.
+fn fixed_width(printer: &Printer) -> Option<usize> {
+    match printer {
+        Printer::Value {
+            min_width,
+            max_width,
+            ..
+        } if min_width == max_width => Some(*min_width),
+        Printer::ReducedValue(..) => Some(2),
+        Printer::Fraction(width) => Some(*width),
+        _ => None,
+    }
+}
+
+fn digits(bytes: &[u8], position: usize) -> usize {
+    bytes[position..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count()
+}
+
+// Reads `width` digits, if there are that many.
+fn number(bytes: &[u8], position: usize, width: usize) -> Option<i64> {
+    if digits(bytes, position) < width {
+        return None;
+    }
+    Some(
+        bytes[position..position + width]
+            .iter()
+            .fold(0, |value, &c| value * 10 + (c - b'0') as i64),
+    )
+}
+
+// Parses an offset in one of the patterns `push_offset` formats, returning it in seconds and the position after it.
+fn parse_offset(bytes: &[u8], position: usize, pattern: usize) -> Option<(i64, usize)> {
+    let sign = offset_sign(bytes, position)?;
+    let separator: &[u8] = if matches!(pattern, 2 | 4 | 6 | 8) {
+        b":"
+    } else {
+        b""
+    };
+    let hours = number(bytes, position + 1, 2)?;
+    let mut end = position + 3;
+    let (mut minutes, mut seconds) = (0, 0);
+    if pattern >= 1 {
+        match offset_part(bytes, end, separator) {
+            Some((value, next)) => {
+                minutes = value;
+                end = next;
+                if pattern >= 5 {
+                    match offset_part(bytes, end, separator) {
+                        Some((value, next)) => {
+                            seconds = value;
+                            end = next;
+                        }
+                        None if pattern >= 7 => return None,
+                        None => {}
+                    }
+                }
+            }
+            None if pattern >= 3 => return None,
+            None => {}
+        }
+    }
+    offset_of(sign, hours, minutes, seconds).map(|offset| (offset, end))
+}
+
+// Parses the part of a localized offset after 'GMT': nothing, or a sign then hours with optional minutes and seconds.
+fn parse_localized_offset(bytes: &[u8], position: usize) -> Option<(i64, usize)> {
+    let sign = match offset_sign(bytes, position) {
+        Some(sign) => sign,
+        None => return Some((0, position)),
+    };
+    let width = digits(bytes, position + 1).min(2);
+    let hours = number(bytes, position + 1, width.max(1))?;
+    let mut end = position + 1 + width;
+    let (mut minutes, mut seconds) = (0, 0);
+    if let Some((value, next)) = offset_part(bytes, end, b":") {
+        minutes = value;
+        end = next;
+        if let Some((value, next)) = offset_part(bytes, end, b":") {
+            seconds = value;
+            end = next;
+        }
+    }
+    offset_of(sign, hours, minutes, seconds).map(|offset| (offset, end))
+}
+
+fn offset_sign(bytes: &[u8], position: usize) -> Option<i64> {
+    match bytes.get(position)? {
+        b'+' => Some(1),
+        b'-' => Some(-1),
+        _ => None,
+    }
+}
+
+// Reads a separator then two digits, returning them and the position after.
+fn offset_part(bytes: &[u8], position: usize, separator: &[u8]) -> Option<(i64, usize)> {
+    if !bytes[position..].starts_with(separator) {
+        return None;
+    }
+    let start = position + separator.len();
+    number(bytes, start, 2).map(|value| (value, start + 2))
+}
+
+fn offset_of(sign: i64, hours: i64, minutes: i64, seconds: i64) -> Option<i64> {
+    let offset = (hours * MINUTES_IN_HOUR + minutes) * SECONDS_IN_MINUTE + seconds;
+    if minutes >= MINUTES_IN_HOUR
+        || seconds >= SECONDS_IN_MINUTE
+        || offset > MAX_OFFSET_SECONDS as i64
+    {
+        None
+    } else {
+        Some(sign * offset)
+    }
+}
+
+fn field_value<T: TemporalAccessor + ?Sized>(
+    temporal: &T,
+    field: Field,
+    locale: &LocaleData,
+) -> Result<i64, FormatError> {
+    let get = |field| {
+        temporal
+            .get(field)
+            .ok_or(FormatError::UnsupportedField(field))
+    };
+    match field {
+        Field::Chrono(field) => get(field),
+        Field::ModifiedJulianDay => Ok(get(ChronoField::EpochDay)? + MODIFIED_JULIAN_DAY_OF_EPOCH),
+        Field::UnixSeconds => Ok(get(ChronoField::EpochDay)? * SECONDS_IN_DAY
+            + get(ChronoField::SecondOfDay)?
+            - get(ChronoField::OffsetSeconds)?),
+        Field::QuarterOfYear => Ok((get(ChronoField::MonthOfYear)? - 1) / 3 + 1),
+        Field::LocalizedDayOfWeek => Ok(locale.localized_day_of_week(get(ChronoField::DayOfWeek)?)),
+        Field::WeekBasedYear(weeks) => {
+            let (first_day_of_week, minimal_days) = week_rules(weeks, locale);
+            Ok(week_of_epoch_day(get(ChronoField::EpochDay)?, first_day_of_week, minimal_days).0)
+        }
+        Field::WeekOfWeekBasedYear(weeks) => {
+            let (first_day_of_week, minimal_days) = week_rules(weeks, locale);
+            Ok(week_of_epoch_day(get(ChronoField::EpochDay)?, first_day_of_week, minimal_days).1)
+        }
+        Field::WeekOfMonth => {
+            // As java.time's `WeekFields`: a week belongs to the month if it has enough days in it,
+            // so the first days of a month can be in week 0.
+            let epoch_day = get(ChronoField::EpochDay)?;
+            let (_, _, day_of_month) = date_of_epoch_day(epoch_day);
+            let start = first_week_start(
+                epoch_day - (day_of_month as i64 - 1),
+                locale.first_day_of_week,
+                locale.minimal_days,
+            );
+            Ok((epoch_day - start).div_euclid(DAYS_IN_WEEK_ISO) + 1)
+        }
+    }
+}
+
+// The first day of the week and the minimal days in the first week.
+fn week_rules(weeks: Weeks, locale: &LocaleData) -> (i64, i64) {
+    match weeks {
+        Weeks::Iso => (ROOT.first_day_of_week, ROOT.minimal_days),
+        Weeks::Localized => (locale.first_day_of_week, locale.minimal_days),
+    }
+}
+
+fn name(locale: &LocaleData, field: Field, style: TextStyle, value: i64) -> Option<&'static str> {
+    let style = style as usize;
+    let (names, first) = match field {
+        Field::Chrono(ChronoField::MonthOfYear) => (locale.months[style], 1),
+        Field::Chrono(ChronoField::DayOfWeek) | Field::LocalizedDayOfWeek => {
+            (locale.days[style], 1)
+        }
+        Field::QuarterOfYear => (locale.quarters[style], 1),
+        Field::Chrono(ChronoField::Era) => (locale.eras[style], 0),
+        Field::Chrono(ChronoField::AmPmOfDay) => (locale.am_pm, 0),
+        _ => return None,
+    };
+    LocaleData::name(names, usize::try_from(value - first).ok()?)
+}
+
+fn push_padded(text: &mut String, value: u64, width: usize) {
+    let _ = write!(text, "{:01$}", value, width);
+}
+
+// Formats an offset using one of '+HH', '+HHmm', '+HH:mm', '+HHMM', '+HH:MM', '+HHMMss', '+HH:MM:ss', '+HHMMSS' or '+HH:MM:SS',
+// where lower case minutes and seconds are left out if zero.
+fn push_offset(text: &mut String, offset_seconds: i64, pattern: usize, zero: &str) {
+    if offset_seconds == 0 {
+        text.push_str(zero);
+        return;
+    }
+    let seconds = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (
+        seconds / SECONDS_IN_HOUR as u64,
+        seconds / SECONDS_IN_MINUTE as u64 % MINUTES_IN_HOUR as u64,
+        seconds % SECONDS_IN_MINUTE as u64,
+    );
+    let separator = if matches!(pattern, 2 | 4 | 6 | 8) {
+        ":"
+    } else {
+        ""
+    };
+    let _ = write!(
+        text,
+        "{}{:02}",
+        if offset_seconds < 0 { '-' } else { '+' },
+        hours
+    );
+    if pattern >= 3 || (pattern >= 1 && minutes > 0) {
+        let _ = write!(text, "{}{:02}", separator, minutes);
+        if pattern >= 7 || (pattern >= 5 && seconds > 0) {
+            let _ = write!(text, "{}{:02}", separator, seconds);
+        }
+    }
+}
+
+// Formats an offset as 'GMT+8' or 'GMT+08:00'.
+fn push_localized_offset(text: &mut String, offset_seconds: i64, style: TextStyle) {
+    text.push_str("GMT");
+    if offset_seconds == 0 {
+        return;
+    }
+    let seconds = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (
+        seconds / SECONDS_IN_HOUR as u64,
+        seconds / SECONDS_IN_MINUTE as u64 % MINUTES_IN_HOUR as u64,
+        seconds % SECONDS_IN_MINUTE as u64,
+    );
+    text.push(if offset_seconds < 0 { '-' } else { '+' });
+    if style == TextStyle::Full {
+        let _ = write!(text, "{:02}:{:02}", hours, minutes);
+    } else {
+        let _ = write!(text, "{}", hours);
+        if minutes > 0 || seconds > 0 {
+            let _ = write!(text, ":{:02}", minutes);
+        }
+    }
+    if seconds > 0 {
+        let _ = write!(text, ":{:02}", seconds);
+    }
+}
@@ -0,0 +1,90 @@
+use super::Printer;
+use crate::locale::{FormatStyle, Locale, LocaleData, ROOT};
+use crate::{DateTimeFormatter, ResolverStyle};
+
+impl DateTimeFormatter {
+    /// Obtains a formatter for dates in a localized style, such as 'Jul 1, 2003' in English.
+    ///
+    /// The formatter starts with the root locale; use [`with_locale`](#method.with_locale) to choose another.
+    ///
+    /// # Parameters
+    ///  - `date_style`: the length of the date.
+    pub fn of_localized_date(date_style: FormatStyle) -> DateTimeFormatter {
+        DateTimeFormatter::of_localized(Some(date_style), None)
+    }
+
+    /// Obtains a formatter for times in a localized style, such as '10:52:37 AM' in English.
+    ///
+    /// # Parameters
+    ///  - `time_style`: the length of the time.
+    pub fn of_localized_time(time_style: FormatStyle) -> DateTimeFormatter {
+        DateTimeFormatter::of_localized(None, Some(time_style))
+    }
+
+    /// Obtains a formatter for dates and times in localized styles, such as 'Jul 1, 2003, 10:52:37 AM' in English.
+    ///
+    /// # Parameters
+    ///  - `date_style`: the length of the date, which also chooses how the date and time are joined.
+    ///  - `time_style`: the length of the time.
+    pub fn of_localized_date_time(
+        date_style: FormatStyle,
+        time_style: FormatStyle,
+    ) -> DateTimeFormatter {
+        DateTimeFormatter::of_localized(Some(date_style), Some(time_style))
+    }
+
+    fn of_localized(
+        date_style: Option<FormatStyle>,
+        time_style: Option<FormatStyle>,
+    ) -> DateTimeFormatter {
+        DateTimeFormatter {
+            printers: vec![localized(date_style, time_style, &ROOT)],
+            resolver_style: ResolverStyle::default(),
+            locale: &ROOT,
+        }
+    }
+
+    /// Returns a copy of this formatter using the names, week rules and localized styles of a locale.
+    ///
+    /// # Parameters
+    ///  - `locale`: the locale to use.
+    pub fn with_locale(&self, locale: Locale) -> DateTimeFormatter {
+        DateTimeFormatter {
+            printers: self
+                .printers
+                .iter()
+                .map(|printer| match printer {
+                    Printer::Localized(date_style, time_style, _) => {
+                        localized(*date_style, *time_style, locale.0)
+                    }
+                    printer => printer.clone(),
+                })
+                .collect(),
+            resolver_style: self.resolver_style,
+            locale: locale.0,
+        }
+    }
+
+    /// Gets the locale used for names, week rules and localized styles.
+    pub fn locale(&self) -> Locale {
+        Locale(self.locale)
+    }
+}
+
+// Compiles the locale's pattern for the styles.
+fn localized(
+    date_style: Option<FormatStyle>,
+    time_style: Option<FormatStyle>,
+    locale: &'static LocaleData,
+) -> Printer {
+    let pattern = match (date_style, time_style) {
+        (Some(date_style), Some(time_style)) => locale.date_time_patterns[date_style as usize]
+            .replace("{1}", locale.date_patterns[date_style as usize])
+            .replace("{0}", locale.time_patterns[time_style as usize]),
+        (Some(date_style), None) => locale.date_patterns[date_style as usize].to_owned(),
+        (None, Some(time_style)) => locale.time_patterns[time_style as usize].to_owned(),
+        (None, None) => String::new(),
+    };
+    let formatter = DateTimeFormatter::of_pattern(&pattern).expect("invalid localized pattern");
+    Printer::Localized(date_style, time_style, formatter.printers)
+}
@@ -0,0 +1,421 @@
+use crate::{
+    ChronoField, DateTimeFormatter, LocalDate, OffsetDateTime, ParseDateTimeError, Parsed,
+    ResolverStyle, TemporalAccessor,
+};
+
+fn formatter(pattern: &str, style: ResolverStyle) -> DateTimeFormatter {
+    DateTimeFormatter::of_pattern(pattern)
+        .unwrap()
+        .with_resolver_style(style)
+}
+
+fn parse(pattern: &str, text: &str) -> Parsed {
+    formatter(pattern, ResolverStyle::Smart)
+        .parse(text)
+        .unwrap()
+}
+
+fn date(pattern: &str, style: ResolverStyle, text: &str) -> Result<LocalDate, ParseDateTimeError> {
+    formatter(pattern, style).parse_local_date(text)
+}
+
+fn error(pattern: &str, style: ResolverStyle, text: &str) -> (usize, &'static str) {
+    let error = formatter(pattern, style).parse(text).unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn round_trip() {
+    let pattern = "EEE, d MMM uuuu HH:mm:ss.SSSSSSSSS XXX";
+    let date_time = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 123_456_789, 7_200);
+    let formatter = DateTimeFormatter::of_pattern(pattern).unwrap();
+
+    let text = formatter.format(&date_time).unwrap();
+
+    assert_eq!(Ok(date_time), formatter.parse_offset_date_time(&text));
+}
+
+#[test]
+fn day_of_month_by_style() {
+    let pattern = "uuuu-MM-dd";
+
+    assert_eq!(
+        Err(ParseDateTimeError::of(8, "day of month out of range")),
+        date(pattern, ResolverStyle::Strict, "2003-02-30")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 2, 28)),
+        date(pattern, ResolverStyle::Smart, "2003-02-30")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 3, 2)),
+        date(pattern, ResolverStyle::Lenient, "2003-02-30")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2004, 2, 29)),
+        date(pattern, ResolverStyle::Strict, "2004-02-29")
+    );
+
+    assert_eq!(
+        (8, "day of month out of range"),
+        error(pattern, ResolverStyle::Smart, "2003-02-32")
+    );
+    assert_eq!(
+        (5, "month out of range"),
+        error(pattern, ResolverStyle::Smart, "2003-13-01")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2004, 1, 1)),
+        date(pattern, ResolverStyle::Lenient, "2003-13-01")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2002, 12, 31)),
+        date(pattern, ResolverStyle::Lenient, "2003-01-00")
+    );
+}
+
+#[test]
+fn year_of_era() {
+    assert_eq!(
+        None,
+        formatter("yyyy-MM-dd", ResolverStyle::Strict)
+            .parse("2003-07-01")
+            .unwrap()
+            .date()
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        date("yyyy-MM-dd", ResolverStyle::Smart, "2003-07-01")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(-43, 3, 15)),
+        date("yyyy-MM-dd G", ResolverStyle::Strict, "0044-03-15 BC")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2014, 7, 1)),
+        date("yy-MM-dd", ResolverStyle::Smart, "14-07-01")
+    );
+}
+
+#[test]
+fn end_of_day() {
+    let pattern = "uuuu-MM-dd HH:mm";
+    let next_day = OffsetDateTime::of(LocalDate::of(2004, 1, 1), 0, 0, 0, 0, 0);
+    let resolve = |style, text| {
+        let parsed = formatter(pattern, style).parse(text)?;
+        Ok((parsed.date(), parsed.time()))
+    };
+
+    assert_eq!(
+        Ok((Some(next_day.date()), Some((0, 0, 0, 0)))),
+        resolve(ResolverStyle::Smart, "2003-12-31 24:00")
+    );
+    assert_eq!(
+        Err(ParseDateTimeError::of(11, "time out of range")),
+        resolve(ResolverStyle::Strict, "2003-12-31 24:00")
+    );
+    assert_eq!(
+        Err(ParseDateTimeError::of(11, "time out of range")),
+        resolve(ResolverStyle::Smart, "2003-12-31 24:01")
+    );
+    assert_eq!(
+        Ok((Some(next_day.date()), Some((0, 1, 0, 0)))),
+        resolve(ResolverStyle::Lenient, "2003-12-31 24:01")
+    );
+    assert_eq!(
+        Ok((Some(LocalDate::of(2003, 12, 31)), Some((11, 15, 0, 0)))),
+        resolve(ResolverStyle::Lenient, "2003-12-31 10:75")
+    );
+
+    let time = parse("HH:mm", "24:00");
+    assert_eq!(
+        (None, Some((0, 0, 0, 0)), 1),
+        (time.date(), time.time(), time.excess_days())
+    );
+}
+
+#[test]
+fn leap_second() {
+    let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd HH:mm:ss XXX")
+        .unwrap()
+        .with_resolver_style(ResolverStyle::Strict);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2016, 12, 31),
+            23,
+            59,
+            60,
+            0,
+            0
+        )),
+        formatter.parse_offset_date_time("2016-12-31 23:59:60 Z")
+    );
+    assert_eq!(
+        Err(ParseDateTimeError::of(
+            0,
+            "text does not give a valid offset date-time"
+        )),
+        formatter.parse_offset_date_time("2016-12-31 22:59:60 Z")
+    );
+}
+
+#[test]
+fn week_based_dates() {
+    let pattern = "YYYY-'W'ww-e";
+
+    assert_eq!(
+        Ok(LocalDate::of(2004, 12, 27)),
+        date(pattern, ResolverStyle::Strict, "2004-W53-1")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2009, 1, 1)),
+        date(pattern, ResolverStyle::Strict, "2009-W01-4")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2008, 12, 29)),
+        date(pattern, ResolverStyle::Smart, "2009-W01-1")
+    );
+    assert_eq!(
+        (6, "week of week-based year out of range"),
+        error(pattern, ResolverStyle::Strict, "2003-W53-1")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 12, 29)),
+        date(pattern, ResolverStyle::Smart, "2003-W53-1")
+    );
+    assert_eq!(
+        (6, "week of week-based year out of range"),
+        error(pattern, ResolverStyle::Smart, "2003-W54-1")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2004, 1, 5)),
+        date(pattern, ResolverStyle::Lenient, "2003-W54-1")
+    );
+    assert_eq!(
+        (9, "day of week out of range"),
+        error(pattern, ResolverStyle::Smart, "2003-W01-8")
+    );
+}
+
+#[test]
+fn week_of_month() {
+    let pattern = "uuuu-MM W EEE";
+
+    assert_eq!(
+        Ok(LocalDate::of(2003, 6, 1)),
+        date(pattern, ResolverStyle::Strict, "2003-06 0 Sun")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 6, 2)),
+        date(pattern, ResolverStyle::Strict, "2003-06 1 Mon")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        date(pattern, ResolverStyle::Strict, "2003-07 1 Tue")
+    );
+    assert_eq!(
+        (8, "week of month out of range"),
+        error(pattern, ResolverStyle::Strict, "2003-06 0 Sat")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 5, 31)),
+        date(pattern, ResolverStyle::Smart, "2003-06 0 Sat")
+    );
+}
+
+#[test]
+fn day_of_year() {
+    assert_eq!(
+        Ok(LocalDate::of(2004, 12, 31)),
+        date("uuuu-DDD", ResolverStyle::Strict, "2004-366")
+    );
+    assert_eq!(
+        (5, "day of year out of range"),
+        error("uuuu-DDD", ResolverStyle::Smart, "2003-366")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2004, 1, 1)),
+        date("uuuu-DDD", ResolverStyle::Lenient, "2003-366")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        date("uuuu-D", ResolverStyle::Strict, "2003-182")
+    );
+}
+
+#[test]
+fn epoch_day() {
+    assert_eq!(
+        Ok(LocalDate::of(1999, 6, 2)),
+        date("g", ResolverStyle::Strict, "51331")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(1858, 11, 16)),
+        date("g", ResolverStyle::Strict, "-1")
+    );
+    assert_eq!(
+        "51331",
+        DateTimeFormatter::of_pattern("g")
+            .unwrap()
+            .format(&LocalDate::of(1999, 6, 2))
+            .unwrap()
+    );
+}
+
+#[test]
+fn adjacent_values() {
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        date("uuuuMMdd", ResolverStyle::Strict, "20030701")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(12_003, 7, 1)),
+        date("uuuuMMdd", ResolverStyle::Strict, "+120030701")
+    );
+    assert_eq!(
+        (0, "expected '+' only before a value wider than the pattern"),
+        error("uuuuMMdd", ResolverStyle::Strict, "120030701")
+    );
+    assert_eq!(
+        (0, "expected digits"),
+        error("uuuuMMdd", ResolverStyle::Strict, "2003071")
+    );
+    assert_eq!(
+        Some((10, 52, 37, 120_000_000)),
+        parse("HHmmssSS", "10523712").time()
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        date("d/M/u", ResolverStyle::Strict, "1/7/2003")
+    );
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        Ok(LocalDate::of(2003, 5, 1)),
+        date("d MMM uuuu", ResolverStyle::Strict, "1 may 2003")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 5, 1)),
+        date("d MMMM uuuu", ResolverStyle::Strict, "1 MAY 2003")
+    );
+    assert_eq!(
+        (2, "unknown name"),
+        error("d MMM uuuu", ResolverStyle::Strict, "1 Mai 2003")
+    );
+    assert_eq!(
+        (0, "conflicting values"),
+        error("EEE, d MMM uuuu", ResolverStyle::Strict, "Wed, 1 Jul 2003")
+    );
+    assert_eq!(Some((22, 30, 0, 0)), parse("h:mm a", "10:30 pm").time());
+    assert_eq!(Some((0, 30, 0, 0)), parse("h:mm a", "12:30 AM").time());
+    assert_eq!(
+        (8, "conflicting values"),
+        error("uuuu-MM QQQ", ResolverStyle::Smart, "2003-07 Q2")
+    );
+    assert_eq!(
+        Some(7),
+        parse("uuuu-MM QQQ", "2003-07 Q3").get(ChronoField::MonthOfYear)
+    );
+}
+
+#[test]
+fn time_fields() {
+    assert_eq!(
+        Some((10, 52, 37, 123_000_000)),
+        parse("A", "39157123").time()
+    );
+    assert_eq!(
+        Some((10, 52, 37, 123_456_789)),
+        parse("N", "39157123456789").time()
+    );
+    assert_eq!(Some((0, 5, 0, 0)), parse("kk:mm", "24:05").time());
+    assert_eq!(Some((10, 0, 0, 0)), parse("HH", "10").time());
+    assert_eq!(None, parse("HH:ss", "10:37").time());
+    assert_eq!(
+        Some(10),
+        parse("HH:ss", "10:37").get(ChronoField::HourOfDay)
+    );
+    assert_eq!(
+        (0, "time out of range"),
+        error("HH:mm", ResolverStyle::Strict, "25:00")
+    );
+}
+
+#[test]
+fn offsets() {
+    let offset = |pattern, text| parse(pattern, text).offset_seconds();
+
+    assert_eq!(Some(0), offset("XXX", "Z"));
+    assert_eq!(Some(-30_600), offset("XXX", "-08:30"));
+    assert_eq!(Some(-28_800), offset("X", "-08"));
+    assert_eq!(Some(-30_600), offset("X", "-0830"));
+    assert_eq!(Some(0), offset("xx", "+0000"));
+    assert_eq!(Some(3_723), offset("xxxxx", "+01:02:03"));
+    assert_eq!(Some(3_720), offset("XXXXX", "+01:02"));
+    assert_eq!(Some(19_800), offset("Z", "+0530"));
+    assert_eq!(Some(28_800), offset("O", "GMT+8"));
+    assert_eq!(Some(-30_600), offset("OOOO", "GMT-08:30"));
+    assert_eq!(Some(0), offset("O", "GMT"));
+    assert_eq!(
+        (0, "invalid offset"),
+        error("XXX", ResolverStyle::Smart, "+19:00")
+    );
+    assert_eq!(
+        (0, "invalid offset"),
+        error("XXX", ResolverStyle::Smart, "+0100")
+    );
+}
+
+#[test]
+fn optional_sections() {
+    let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd['T'HH:mm[:ss]][XXX]").unwrap();
+
+    let parsed = formatter.parse("2003-07-01T10:52+02:00").unwrap();
+    assert_eq!(Some((10, 52, 0, 0)), parsed.time());
+    assert_eq!(Some(7_200), parsed.offset_seconds());
+
+    let parsed = formatter.parse("2003-07-01").unwrap();
+    assert_eq!(Some(LocalDate::of(2003, 7, 1)), parsed.date());
+    assert_eq!(None, parsed.time());
+}
+
+#[test]
+fn zone_id() {
+    let parsed = parse("uuuu-MM-dd VV", "2003-07-01 America/Los_Angeles");
+
+    assert_eq!(Some("America/Los_Angeles"), parsed.zone_id());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        (10, "unexpected trailing text"),
+        error("uuuu-MM-dd", ResolverStyle::Smart, "2003-07-01T")
+    );
+    assert_eq!(
+        (4, "unexpected text"),
+        error("uuuu-MM-dd", ResolverStyle::Smart, "2003/07/01")
+    );
+    assert_eq!(
+        (5, "expected digits"),
+        error("uuuu-MM-dd", ResolverStyle::Smart, "2003-7-01")
+    );
+    assert_eq!(
+        (11, "conflicting values"),
+        error("uuuu-MM-dd uuuu", ResolverStyle::Smart, "2003-07-01 2004")
+    );
+    assert_eq!(
+        (0, "year out of range"),
+        error("uuuu-MM-dd", ResolverStyle::Lenient, "+1000000000-01-01")
+    );
+    assert_eq!(
+        "invalid date-time at position 5: month out of range",
+        formatter("uuuu-MM-dd", ResolverStyle::Strict)
+            .parse("2003-00-01")
+            .unwrap_err()
+            .to_string()
+    );
+}
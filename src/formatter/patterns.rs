@@ -0,0 +1,224 @@
+use crate::{
+    ChronoField, DateTimeFormatter, FormatError, Instant, LocalDate, OffsetDateTime,
+    TemporalAccessor,
+};
+
+fn format<T: TemporalAccessor>(pattern: &str, temporal: &T) -> String {
+    DateTimeFormatter::of_pattern(pattern)
+        .unwrap()
+        .format(temporal)
+        .unwrap()
+}
+
+fn example() -> OffsetDateTime {
+    OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 123_456_789, 7_200)
+}
+
+fn offset(offset_seconds: i32) -> OffsetDateTime {
+    OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 0, offset_seconds)
+}
+
+#[test]
+fn date_and_time() {
+    assert_eq!(
+        "2003-07-01 10:52:37.123 +02:00",
+        format("uuuu-MM-dd HH:mm:ss.SSS XXX", &example())
+    );
+    assert_eq!("03 2003 2003", format("yy y u", &example()));
+    assert_eq!(
+        "7 7 07 Jul July J",
+        format("M L MM MMM MMMM MMMMM", &example())
+    );
+    assert_eq!("1 01 182 182", format("d dd D DDD", &example()));
+    assert_eq!(
+        "Tue Tuesday T 2 02 Tue",
+        format("E EEEE EEEEE e ee eee", &example())
+    );
+    assert_eq!("3 03 Q3 3rd quarter", format("Q QQ QQQ QQQQ", &example()));
+    assert_eq!("AD Anno Domini A", format("G GGGG GGGGG", &example()));
+    assert_eq!("AM 10 10 10 10", format("a h K k H", &example()));
+    assert_eq!(
+        "1 123456789 123456789 0123456789 39157123",
+        format("S SSSSSSSSS n nnnnnnnnnn A", &example())
+    );
+    assert_eq!("39157123456789", format("N", &example()));
+}
+
+#[test]
+fn clock_hours() {
+    let midnight = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 0, 5, 0, 0, 0);
+    let noon = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 12, 5, 0, 0, 0);
+
+    assert_eq!("12:05 AM 0 24", format("hh:mm a K k", &midnight));
+    assert_eq!("12:05 PM 0 12", format("hh:mm a K k", &noon));
+}
+
+#[test]
+fn years() {
+    let date = |year| LocalDate::of(year, 1, 1);
+
+    assert_eq!("+10000 +10000 00", format("uuuu yyyy yy", &date(10_000)));
+    assert_eq!("-0001 0002 BC 02", format("uuuu yyyy G yy", &date(-1)));
+    assert_eq!("0000 0001 BC", format("uuuu yyyy G", &date(0)));
+    assert_eq!("00012 12", format("uuuuu y", &date(12)));
+}
+
+#[test]
+fn weeks() {
+    assert_eq!("2003-W27-2 1", format("YYYY-'W'ww-e W", &example()));
+    assert_eq!(
+        "2009-W01-1 09",
+        format("YYYY-'W'ww-e YY", &LocalDate::of(2008, 12, 29))
+    );
+    assert_eq!(
+        "2009-W53-7",
+        format("YYYY-'W'ww-e", &LocalDate::of(2010, 1, 3))
+    );
+    // The first days of a month are in week 0 unless at least four days of that week are in the month.
+    assert_eq!("0", format("W", &LocalDate::of(2010, 1, 3)));
+    assert_eq!("1", format("W", &LocalDate::of(2010, 1, 4)));
+    assert_eq!("0", format("W", &LocalDate::of(2003, 6, 1)));
+}
+
+#[test]
+fn offsets() {
+    assert_eq!(
+        "+02 +0200 +02:00 +0200 +02:00",
+        format("X XX XXX XXXX XXXXX", &example())
+    );
+    assert_eq!(
+        "+02 +0200 +02:00 +0200 +02:00",
+        format("x xx xxx xxxx xxxxx", &example())
+    );
+    assert_eq!(
+        "+0200 +0200 GMT+02:00 +02:00 GMT+2 GMT+02:00",
+        format("Z ZZZ ZZZZ ZZZZZ O OOOO", &example())
+    );
+    assert_eq!("Z Z Z Z Z", format("X XX XXX XXXX XXXXX", &offset(0)));
+    assert_eq!(
+        "+00 +0000 +00:00 +0000 +00:00",
+        format("x xx xxx xxxx xxxxx", &offset(0))
+    );
+    assert_eq!("+0000 GMT Z GMT", format("Z ZZZZ ZZZZZ O", &offset(0)));
+    assert_eq!(
+        "-0330 -0330 -03:30 GMT-3:30 GMT-03:30",
+        format("X XX XXX O OOOO", &offset(-12_600))
+    );
+    assert_eq!(
+        "+0530 +053015 +05:30:15 +05:30:15 GMT+5:30:15",
+        format("X XXXX XXXXX ZZZZZ O", &offset(19_815))
+    );
+}
+
+#[test]
+fn literals() {
+    assert_eq!(
+        "2003-07-01T10:52 o'clock, 'quoted'",
+        format("uuuu-MM-dd'T'HH:mm 'o''clock', '''quoted'''", &example())
+    );
+    assert_eq!("at 10 h", format("'at' H 'h'", &example()));
+    assert_eq!("''", format("''''", &example()));
+    assert_eq!("ü 2003", format("ü u", &example()));
+}
+
+#[test]
+fn optional_sections() {
+    let pattern = "uuuu-MM-dd['T'HH:mm[:ss]][XXX]";
+
+    assert_eq!("2003-07-01", format(pattern, &LocalDate::of(2003, 7, 1)));
+    assert_eq!("2003-07-01T10:52:37+02:00", format(pattern, &example()));
+    // An unclosed section ends with the pattern.
+    assert_eq!(
+        "2003-07-01",
+        format("uuuu-MM-dd[ HH:mm", &LocalDate::of(2003, 7, 1))
+    );
+}
+
+#[test]
+fn leap_second() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2017, 1, 1), 1, 59, 60, 0, 7_200);
+
+    assert_eq!(
+        "2017-01-01 01:59:60 +02:00",
+        format("uuuu-MM-dd HH:mm:ss XXX", &date_time)
+    );
+}
+
+#[test]
+fn unsupported_fields() {
+    let formatter = |pattern| DateTimeFormatter::of_pattern(pattern).unwrap();
+
+    assert_eq!(
+        Err(FormatError::UnsupportedField(ChronoField::HourOfDay)),
+        formatter("uuuu-MM-dd HH:mm").format(&LocalDate::of(2003, 7, 1))
+    );
+    assert_eq!(
+        Err(FormatError::UnsupportedField(ChronoField::OffsetSeconds)),
+        formatter("XXX").format(&LocalDate::of(2003, 7, 1))
+    );
+    assert_eq!(
+        Err(FormatError::UnsupportedField(ChronoField::Year)),
+        formatter("uuuu").format(&Instant::EPOCH)
+    );
+    assert_eq!(
+        Err(FormatError::UnsupportedField(ChronoField::EpochDay)),
+        formatter("ww").format(&Instant::EPOCH)
+    );
+    assert_eq!(
+        Err(FormatError::UnsupportedZone),
+        formatter("VV").format(&example())
+    );
+    assert_eq!(
+        "unsupported field: HourOfDay",
+        FormatError::UnsupportedField(ChronoField::HourOfDay).to_string()
+    );
+}
+
+#[test]
+fn zones() {
+    struct Zoned(OffsetDateTime);
+
+    impl TemporalAccessor for Zoned {
+        fn get(&self, field: ChronoField) -> Option<i64> {
+            self.0.get(field)
+        }
+
+        fn zone_id(&self) -> Option<&str> {
+            Some("Europe/Paris")
+        }
+    }
+
+    assert_eq!(
+        "10:52 Europe/Paris Europe/Paris",
+        format("HH:mm VV z", &Zoned(example()))
+    );
+}
+
+#[test]
+fn invalid_patterns() {
+    for &(pattern, position, reason) in &[
+        ("uuuu-MM-dd 'unterminated", 11, "unterminated quote"),
+        ("uuuu]", 4, "unmatched ']'"),
+        ("uuuu-MM-dd bb", 11, "unknown pattern letter"),
+        ("aa", 0, "too many pattern letters"),
+        ("ddd", 0, "too many pattern letters"),
+        ("MMMMMM", 0, "too many pattern letters"),
+        ("SSSSSSSSSS", 0, "too many pattern letters"),
+        ("HH:mm V", 6, "pattern letter 'V' must be used twice"),
+        (
+            "OO",
+            0,
+            "pattern letter 'O' must be used once or four times",
+        ),
+        ("XXXXXX", 0, "too many pattern letters"),
+        ("HH{", 2, "reserved character"),
+    ] {
+        let error = DateTimeFormatter::of_pattern(pattern).unwrap_err();
+        assert_eq!(
+            (position, reason),
+            (error.position(), error.reason()),
+            "{}",
+            pattern
+        );
+    }
+}
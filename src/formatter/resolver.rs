@@ -0,0 +1,680 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use super::{field_value, week_rules, Field, Weeks};
+use crate::calendar::*;
+use crate::constants::*;
+use crate::locale::LocaleData;
+use crate::temporal::{date_field, time_field};
+use crate::{ChronoField, LocalDate, OffsetDateTime, TemporalAccessor};
+
+/// How strictly parsed fields are resolved into a date and time, following java.time's `ResolverStyle`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ResolverStyle {
+    /// Every field must be in range for the others, so '2003-02-30' is an error.
+    ///
+    /// As in java.time, a year of era ('y') without an era ('G') is left unresolved, so use 'u' for years.
+    Strict,
+    /// Fields must be in their outer range, but the day of the month is clamped to the end of the month,
+    /// so '2003-02-30' gives '2003-02-28', and '24:00' gives midnight at the start of the next day.
+    #[default]
+    Smart,
+    /// Fields may be out of range and roll over, so '2003-02-30' gives '2003-03-02' and '10:75' gives '11:15'.
+    Lenient,
+}
+
+/// An error parsing text with a [`DateTimeFormatter`](struct.DateTimeFormatter.html), or resolving the fields found.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseDateTimeError {
+    position: usize,
+    reason: &'static str,
+}
+
+/// The result of parsing text with a [`DateTimeFormatter`](struct.DateTimeFormatter.html).
+///
+/// This has the date, time, offset and time-zone ID given by the text, when it gives enough fields to resolve them,
+/// as well as any other fields parsed that were not needed to resolve them, such as a year without a month and day.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parsed {
+    date: Option<LocalDate>,
+    time: Option<(u32, u32, u32, u32)>,
+    offset_seconds: Option<i32>,
+    zone_id: Option<String>,
+    excess_days: i64,
+    fields: Vec<(Field, i64)>,
+}
+
+// The fields found while parsing, each with the position it was found at.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Fields {
+    values: Vec<(Field, i64, usize)>,
+    zone_id: Option<String>,
+}
+
+impl ParseDateTimeError {
+    pub(super) const fn of(position: usize, reason: &'static str) -> ParseDateTimeError {
+        ParseDateTimeError { position, reason }
+    }
+
+    /// Gets the (byte) position in the text the error was found at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets a short description of what was wrong.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid date-time at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl Error for ParseDateTimeError {}
+
+impl Parsed {
+    /// Gets the date, if the text gives one.
+    pub const fn date(&self) -> Option<LocalDate> {
+        self.date
+    }
+
+    /// Gets the time of day as the hour, minute, second (60 during a leap second) and nanosecond, if the text gives one.
+    pub const fn time(&self) -> Option<(u32, u32, u32, u32)> {
+        self.time
+    }
+
+    /// Gets the offset from UTC in seconds, if the text gives one.
+    pub const fn offset_seconds(&self) -> Option<i32> {
+        self.offset_seconds
+    }
+
+    /// Gets the days to add to the date, from a time of '24:00' or one that rolled over, when the text gives no date.
+    ///
+    /// When the text does give a date, these days have already been added to it.
+    pub const fn excess_days(&self) -> i64 {
+        self.excess_days
+    }
+
+    /// Combines the date, time and offset.
+    ///
+    /// # Returns
+    /// The date-time, or `None` if the text lacks any of them, or has a leap second that does not end a UTC day.
+    pub fn to_offset_date_time(&self) -> Option<OffsetDateTime> {
+        let (hour, minute, second, nano) = self.time?;
+        OffsetDateTime::of_checked(self.date?, hour, minute, second, nano, self.offset_seconds?)
+    }
+}
+
+impl TemporalAccessor for Parsed {
+    fn get(&self, field: ChronoField) -> Option<i64> {
+        if field == ChronoField::OffsetSeconds {
+            return self.offset_seconds.map(i64::from);
+        }
+        self.date
+            .and_then(|date| date_field(&date, field))
+            .or_else(|| {
+                let (hour, minute, second, nano) = self.time?;
+                time_field(
+                    hour as i64,
+                    minute as i64,
+                    second as i64,
+                    nano as i64,
+                    field,
+                )
+            })
+            .or_else(|| {
+                self.fields
+                    .iter()
+                    .find(|&&(found, _)| found == Field::Chrono(field))
+                    .map(|&(_, value)| value)
+            })
+    }
+
+    fn zone_id(&self) -> Option<&str> {
+        self.zone_id.as_deref()
+    }
+}
+
+impl Fields {
+    // Adds a field, which must agree with any value already found for it.
+    pub(super) fn set(
+        &mut self,
+        field: Field,
+        value: i64,
+        position: usize,
+    ) -> Result<(), ParseDateTimeError> {
+        // Localized days of the week are found as ISO ones: numbers are converted as they are parsed
+        // and names are in ISO order.
+        let field = match field {
+            Field::LocalizedDayOfWeek => Field::Chrono(ChronoField::DayOfWeek),
+            _ => field,
+        };
+        match self.get(field) {
+            Some((found, _)) if found != value => {
+                Err(ParseDateTimeError::of(position, "conflicting values"))
+            }
+            Some(_) => Ok(()),
+            None => {
+                self.values.push((field, value, position));
+                Ok(())
+            }
+        }
+    }
+
+    pub(super) fn set_zone_id(
+        &mut self,
+        zone_id: &str,
+        position: usize,
+    ) -> Result<(), ParseDateTimeError> {
+        match &self.zone_id {
+            Some(found) if found != zone_id => {
+                Err(ParseDateTimeError::of(position, "conflicting values"))
+            }
+            _ => {
+                self.zone_id = Some(zone_id.to_owned());
+                Ok(())
+            }
+        }
+    }
+
+    fn get(&self, field: Field) -> Option<(i64, usize)> {
+        self.values
+            .iter()
+            .find(|&&(found, _, _)| found == field)
+            .map(|&(_, value, position)| (value, position))
+    }
+
+    fn take(&mut self, field: Field) -> Option<(i64, usize)> {
+        let index = self
+            .values
+            .iter()
+            .position(|&(found, _, _)| found == field)?;
+        let (_, value, position) = self.values.remove(index);
+        Some((value, position))
+    }
+
+    fn has(&self, fields: &[Field]) -> bool {
+        fields.iter().all(|&field| self.get(field).is_some())
+    }
+
+    // Takes fields known to be there.
+    fn take_all<const N: usize>(&mut self, fields: [Field; N]) -> [(i64, usize); N] {
+        fields.map(|field| self.take(field).unwrap_or_default())
+    }
+
+    // Resolves the fields into a date, time and offset, then checks the rest agree with them.
+    pub(super) fn resolve(
+        mut self,
+        style: ResolverStyle,
+        locale: &LocaleData,
+    ) -> Result<Parsed, ParseDateTimeError> {
+        let mut offset_seconds = self
+            .take(CHRONO_OFFSET_SECONDS)
+            .map(|(offset, _)| offset as i32);
+        if let Some((seconds, position)) = self.take(Field::UnixSeconds) {
+            // These count UTC, so give the date and time at the offset, or in UTC if there is none.
+            let offset = *offset_seconds.get_or_insert(0);
+            let local = seconds as i128 + offset as i128;
+            let epoch_day = i64::try_from(local.div_euclid(SECONDS_IN_DAY as i128))
+                .map_err(|_| ParseDateTimeError::of(position, "value out of range"))?;
+            let second_of_day = local.rem_euclid(SECONDS_IN_DAY as i128) as i64;
+            self.set(Field::Chrono(ChronoField::EpochDay), epoch_day, position)?;
+            self.set(
+                Field::Chrono(ChronoField::HourOfDay),
+                second_of_day / SECONDS_IN_HOUR,
+                position,
+            )?;
+            self.set_time_parts(second_of_day, position)?;
+        }
+        let time = self.resolve_time(style)?;
+        let (time, mut excess_days, time_position) = match time {
+            Some((time, excess_days, position)) => (Some(time), excess_days, position),
+            None => (None, 0, 0),
+        };
+        let mut date = self.resolve_date(style, locale)?;
+        if let Some(found) = date {
+            if excess_days != 0 {
+                date = Some(date_of(
+                    found.to_epoch_day() as i128 + excess_days as i128,
+                    time_position,
+                )?);
+                excess_days = 0;
+            }
+        }
+        let mut parsed = Parsed {
+            date,
+            time,
+            offset_seconds,
+            zone_id: self.zone_id,
+            excess_days,
+            fields: Vec::new(),
+        };
+        for (field, value, position) in self.values {
+            match field_value(&parsed, field, locale) {
+                Ok(resolved) if resolved != value => {
+                    return Err(ParseDateTimeError::of(position, "conflicting values"))
+                }
+                Ok(_) => {}
+                Err(_) => parsed.fields.push((field, value)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    // Resolves the time of day, returning it with any days it rolled over and its position.
+    #[allow(clippy::type_complexity)]
+    fn resolve_time(
+        &mut self,
+        style: ResolverStyle,
+    ) -> Result<Option<((u32, u32, u32, u32), i64, usize)>, ParseDateTimeError> {
+        use ChronoField::*;
+        if let Some((nano_of_day, position)) = self.take(Field::Chrono(NanoOfDay)) {
+            let nano_of_day = check(
+                style,
+                nano_of_day,
+                position,
+                NANOSECONDS_IN_DAY - 1,
+                "nano of day out of range",
+            )?;
+            self.set(
+                Field::Chrono(HourOfDay),
+                nano_of_day.div_euclid(NANOSECONDS_IN_HOUR),
+                position,
+            )?;
+            self.set_time_parts(nano_of_day.div_euclid(NANOSECONDS_IN_SECOND), position)?;
+            self.set(
+                Field::Chrono(NanoOfSecond),
+                nano_of_day.rem_euclid(NANOSECONDS_IN_SECOND),
+                position,
+            )?;
+        }
+        if let Some((milli_of_day, position)) = self.take(Field::Chrono(MilliOfDay)) {
+            let milli_of_day = check(
+                style,
+                milli_of_day,
+                position,
+                SECONDS_IN_DAY * MILLISECONDS_IN_SECOND - 1,
+                "milli of day out of range",
+            )?;
+            self.set(
+                Field::Chrono(HourOfDay),
+                milli_of_day.div_euclid(SECONDS_IN_HOUR * MILLISECONDS_IN_SECOND),
+                position,
+            )?;
+            self.set_time_parts(milli_of_day.div_euclid(MILLISECONDS_IN_SECOND), position)?;
+            let milli = milli_of_day.rem_euclid(MILLISECONDS_IN_SECOND);
+            match self.get(Field::Chrono(NanoOfSecond)) {
+                Some((nano, _)) if nano / NANOSECONDS_IN_MILLISECOND != milli => {
+                    return Err(ParseDateTimeError::of(position, "conflicting values"))
+                }
+                Some(_) => {}
+                None => self.set(
+                    Field::Chrono(NanoOfSecond),
+                    milli * NANOSECONDS_IN_MILLISECOND,
+                    position,
+                )?,
+            }
+        }
+        if let Some((clock_hour, position)) = self.take(Field::Chrono(ClockHourOfDay)) {
+            let clock_hour = check(
+                style,
+                clock_hour - 1,
+                position,
+                23,
+                "clock hour of day out of range",
+            )? + 1;
+            let hour = if clock_hour == HOURS_IN_DAY {
+                0
+            } else {
+                clock_hour
+            };
+            self.set(Field::Chrono(HourOfDay), hour, position)?;
+        }
+        if let Some((clock_hour, position)) = self.take(Field::Chrono(ClockHourOfAmPm)) {
+            let clock_hour = check(
+                style,
+                clock_hour - 1,
+                position,
+                11,
+                "clock hour of am-pm out of range",
+            )? + 1;
+            let hour = if clock_hour == 12 { 0 } else { clock_hour };
+            self.set(Field::Chrono(HourOfAmPm), hour, position)?;
+        }
+        if self.has(&[Field::Chrono(AmPmOfDay), Field::Chrono(HourOfAmPm)]) {
+            let [(am_pm, am_pm_position), (hour, position)] =
+                self.take_all([Field::Chrono(AmPmOfDay), Field::Chrono(HourOfAmPm)]);
+            let am_pm = check(style, am_pm, am_pm_position, 1, "am-pm of day out of range")?;
+            let hour = check(style, hour, position, 11, "hour of am-pm out of range")?;
+            let hour = am_pm
+                .checked_mul(12)
+                .and_then(|hours| hours.checked_add(hour))
+                .ok_or_else(|| ParseDateTimeError::of(position, "value out of range"))?;
+            self.set(Field::Chrono(HourOfDay), hour, position)?;
+        }
+
+        // As in java.time, missing parts of the time are taken as zero, unless that would leave a gap.
+        let (minute, second, nano) = (
+            self.get(Field::Chrono(MinuteOfHour)),
+            self.get(Field::Chrono(SecondOfMinute)),
+            self.get(Field::Chrono(NanoOfSecond)),
+        );
+        if self.get(Field::Chrono(HourOfDay)).is_none()
+            || (minute.is_none() && (second.is_some() || nano.is_some()))
+            || (second.is_none() && minute.is_some() && nano.is_some())
+        {
+            return Ok(None);
+        }
+        let [hour, minute, second, nano] = [HourOfDay, MinuteOfHour, SecondOfMinute, NanoOfSecond]
+            .map(|field| self.take(Field::Chrono(field)));
+        let position = hour.unwrap_or_default().1;
+        let [hour, minute, second, nano] =
+            [hour, minute, second, nano].map(|part| part.unwrap_or((0, position)));
+
+        let in_range = (0..HOURS_IN_DAY).contains(&hour.0)
+            && (0..MINUTES_IN_HOUR).contains(&minute.0)
+            && (0..=SECONDS_IN_MINUTE).contains(&second.0)
+            && (0..NANOSECONDS_IN_SECOND).contains(&nano.0);
+        if in_range {
+            let time = (
+                hour.0 as u32,
+                minute.0 as u32,
+                second.0 as u32,
+                nano.0 as u32,
+            );
+            return Ok(Some((time, 0, position)));
+        }
+        match style {
+            ResolverStyle::Smart
+                if hour.0 == HOURS_IN_DAY && minute.0 == 0 && second.0 == 0 && nano.0 == 0 =>
+            {
+                Ok(Some(((0, 0, 0, 0), 1, position)))
+            }
+            ResolverStyle::Lenient => {
+                let nanos = ((hour.0 as i128 * MINUTES_IN_HOUR as i128 + minute.0 as i128)
+                    * SECONDS_IN_MINUTE as i128
+                    + second.0 as i128)
+                    * NANOSECONDS_IN_SECOND as i128
+                    + nano.0 as i128;
+                let excess_days = i64::try_from(nanos.div_euclid(NANOSECONDS_IN_DAY as i128))
+                    .map_err(|_| ParseDateTimeError::of(position, "value out of range"))?;
+                let nano_of_day = nanos.rem_euclid(NANOSECONDS_IN_DAY as i128) as i64;
+                let second_of_day = nano_of_day / NANOSECONDS_IN_SECOND;
+                let time = (
+                    (second_of_day / SECONDS_IN_HOUR) as u32,
+                    (second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR) as u32,
+                    (second_of_day % SECONDS_IN_MINUTE) as u32,
+                    (nano_of_day % NANOSECONDS_IN_SECOND) as u32,
+                );
+                Ok(Some((time, excess_days, position)))
+            }
+            _ => {
+                let (_, position) = [hour, minute, second, nano]
+                    .iter()
+                    .zip(&[
+                        HOURS_IN_DAY,
+                        MINUTES_IN_HOUR,
+                        SECONDS_IN_MINUTE + 1,
+                        NANOSECONDS_IN_SECOND,
+                    ])
+                    .find(|((value, _), &end)| !(0..end).contains(value))
+                    .map_or((0, position), |(&part, _)| part);
+                Err(ParseDateTimeError::of(position, "time out of range"))
+            }
+        }
+    }
+
+    // Sets the minute and second from a second of the day.
+    fn set_time_parts(
+        &mut self,
+        second_of_day: i64,
+        position: usize,
+    ) -> Result<(), ParseDateTimeError> {
+        self.set(
+            Field::Chrono(ChronoField::MinuteOfHour),
+            second_of_day
+                .div_euclid(SECONDS_IN_MINUTE)
+                .rem_euclid(MINUTES_IN_HOUR),
+            position,
+        )?;
+        self.set(
+            Field::Chrono(ChronoField::SecondOfMinute),
+            second_of_day.rem_euclid(SECONDS_IN_MINUTE),
+            position,
+        )
+    }
+
+    fn resolve_date(
+        &mut self,
+        style: ResolverStyle,
+        locale: &LocaleData,
+    ) -> Result<Option<LocalDate>, ParseDateTimeError> {
+        use ChronoField::*;
+        let era = self.take(Field::Chrono(Era));
+        if let Some((era, position)) = era {
+            check(style, era, position, 1, "era out of range")?;
+        }
+        match (self.get(Field::Chrono(YearOfEra)), era) {
+            (Some(_), None) if style == ResolverStyle::Strict => {}
+            (Some((year_of_era, position)), _) => {
+                self.take(Field::Chrono(YearOfEra));
+                let year_of_era = check(
+                    style,
+                    year_of_era - 1,
+                    position,
+                    i64::MAX - 1,
+                    "year of era out of range",
+                )? + 1;
+                let year = match era {
+                    Some((0, _)) => 1i64.checked_sub(year_of_era).ok_or_else(|| {
+                        ParseDateTimeError::of(position, "year of era out of range")
+                    })?,
+                    _ => year_of_era,
+                };
+                self.set(Field::Chrono(Year), year, position)?;
+            }
+            (None, _) => {}
+        }
+        if let Some((era, position)) = era {
+            // Left to be checked against the date.
+            self.values.push((Field::Chrono(Era), era, position));
+        }
+
+        let year = Field::Chrono(Year);
+        let month = Field::Chrono(MonthOfYear);
+        let day_of_week = Field::Chrono(DayOfWeek);
+        if let Some((epoch_day, position)) = self.take(Field::Chrono(EpochDay)) {
+            return date_of(epoch_day as i128, position).map(Some);
+        }
+        for &weeks in &[Weeks::Iso, Weeks::Localized] {
+            let fields = [
+                Field::WeekBasedYear(weeks),
+                Field::WeekOfWeekBasedYear(weeks),
+                day_of_week,
+            ];
+            if !self.has(&fields) {
+                continue;
+            }
+            let [(year, position), (week, week_position), (day, day_position)] =
+                self.take_all(fields);
+            let (first_day_of_week, minimal_days) = week_rules(weeks, locale);
+            let year = check_year(year, position)?;
+            let start = first_week_start(epoch_day_of(year, 1, 1), first_day_of_week, minimal_days);
+            let weeks_in_year = match style {
+                ResolverStyle::Strict => {
+                    (first_week_start(
+                        epoch_day_of(year + 1, 1, 1),
+                        first_day_of_week,
+                        minimal_days,
+                    ) - start)
+                        / DAYS_IN_WEEK_ISO
+                }
+                _ => 53,
+            };
+            let week = check(
+                style,
+                week - 1,
+                week_position,
+                weeks_in_year - 1,
+                "week of week-based year out of range",
+            )?;
+            let day = check(
+                style,
+                day - 1,
+                day_position,
+                DAYS_IN_WEEK_ISO - 1,
+                "day of week out of range",
+            )? + 1;
+            return date_of(
+                start as i128
+                    + week as i128 * DAYS_IN_WEEK_ISO as i128
+                    + days_into_week(day, first_day_of_week) as i128,
+                position,
+            )
+            .map(Some);
+        }
+        if self.has(&[year, month, Field::Chrono(DayOfMonth)]) {
+            let [(year, position), month, (day, day_position)] =
+                self.take_all([year, month, Field::Chrono(DayOfMonth)]);
+            let (year, month) = year_month(style, year, position, month)?;
+            let day = check(
+                style,
+                day - 1,
+                day_position,
+                30,
+                "day of month out of range",
+            )? + 1;
+            let length = days_in_month(year, month) as i64;
+            let day = match style {
+                ResolverStyle::Strict if day > length => {
+                    return Err(ParseDateTimeError::of(
+                        day_position,
+                        "day of month out of range",
+                    ))
+                }
+                ResolverStyle::Smart => day.min(length),
+                _ => day,
+            };
+            return date_of(
+                epoch_day_of(year, month, 1) as i128 + day as i128 - 1,
+                position,
+            )
+            .map(Some);
+        }
+        if self.has(&[year, month, Field::WeekOfMonth, day_of_week]) {
+            let [(year, position), month, (week, week_position), (day, day_position)] =
+                self.take_all([year, month, Field::WeekOfMonth, day_of_week]);
+            let (year, month) = year_month(style, year, position, month)?;
+            let week = check(style, week, week_position, 5, "week of month out of range")?;
+            let day = check(
+                style,
+                day - 1,
+                day_position,
+                DAYS_IN_WEEK_ISO - 1,
+                "day of week out of range",
+            )?;
+            // Week 1 is the first to have enough days in the month, as in `field_value`.
+            let start = first_week_start(
+                epoch_day_of(year, month, 1),
+                locale.first_day_of_week,
+                locale.minimal_days,
+            );
+            let date = date_of(
+                start as i128
+                    + (week as i128 - 1) * DAYS_IN_WEEK_ISO as i128
+                    + days_into_week(day + 1, locale.first_day_of_week) as i128,
+                position,
+            )?;
+            if style == ResolverStyle::Strict && date.month_value() != month {
+                return Err(ParseDateTimeError::of(
+                    week_position,
+                    "week of month out of range",
+                ));
+            }
+            return Ok(Some(date));
+        }
+        if self.has(&[year, Field::Chrono(DayOfYear)]) {
+            let [(year, position), (day, day_position)] =
+                self.take_all([year, Field::Chrono(DayOfYear)]);
+            let year = check_year(year, position)?;
+            let day = check(
+                style,
+                day - 1,
+                day_position,
+                days_in_year(year) - 1,
+                "day of year out of range",
+            )?;
+            return date_of(epoch_day_of(year, 1, 1) as i128 + day as i128, position).map(Some);
+        }
+        Ok(None)
+    }
+}
+
+const CHRONO_OFFSET_SECONDS: Field = Field::Chrono(ChronoField::OffsetSeconds);
+
+// Checks a value is from 0 to `max`, unless resolving leniently.
+fn check(
+    style: ResolverStyle,
+    value: i64,
+    position: usize,
+    max: i64,
+    reason: &'static str,
+) -> Result<i64, ParseDateTimeError> {
+    if style != ResolverStyle::Lenient && !(0..=max).contains(&value) {
+        Err(ParseDateTimeError::of(position, reason))
+    } else {
+        Ok(value)
+    }
+}
+
+fn check_year(year: i64, position: usize) -> Result<i64, ParseDateTimeError> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+        Ok(year)
+    } else {
+        Err(ParseDateTimeError::of(position, "year out of range"))
+    }
+}
+
+// Checks the year and month, rolling months over into years when resolving leniently.
+fn year_month(
+    style: ResolverStyle,
+    year: i64,
+    position: usize,
+    (month, month_position): (i64, usize),
+) -> Result<(i64, u32), ParseDateTimeError> {
+    let month = check(
+        style,
+        month - 1,
+        month_position,
+        MONTHS_IN_YEAR - 1,
+        "month out of range",
+    )?;
+    let months = year as i128 * MONTHS_IN_YEAR as i128 + month as i128;
+    let year = i64::try_from(months.div_euclid(MONTHS_IN_YEAR as i128))
+        .map_err(|_| ParseDateTimeError::of(position, "year out of range"))?;
+    Ok((
+        check_year(year, position)?,
+        months.rem_euclid(MONTHS_IN_YEAR as i128) as u32 + 1,
+    ))
+}
+
+// The days from the start of the week to an ISO-8601 day of the week, which rolls over into other weeks if out of range.
+fn days_into_week(day_of_week: i64, first_day_of_week: i64) -> i64 {
+    (day_of_week - first_day_of_week).rem_euclid(DAYS_IN_WEEK_ISO)
+        + (day_of_week - 1).div_euclid(DAYS_IN_WEEK_ISO) * DAYS_IN_WEEK_ISO
+}
+
+fn date_of(epoch_day: i128, position: usize) -> Result<LocalDate, ParseDateTimeError> {
+    i64::try_from(epoch_day)
+        .ok()
+        .and_then(LocalDate::of_epoch_day_checked)
+        .ok_or_else(|| ParseDateTimeError::of(position, "date out of range"))
+}
@@ -0,0 +1,248 @@
+use super::{Field, Printer, TextStyle, Weeks, MAX_WIDTH, NANO_DIGITS};
+use crate::locale::ROOT;
+use crate::{ChronoField, DateTimeFormatter, ParsePatternError, ResolverStyle};
+
+#[cfg(test)]
+pub mod conversions;
+
+// As POSIX strptime, two digit years are read as from 1969 to 2068.
+const REDUCED_YEAR_BASE: i64 = 1969;
+
+impl DateTimeFormatter {
+    /// Compiles a formatter from a C `strftime` format, such as '%Y-%m-%dT%H:%M:%S%z'.
+    ///
+    /// This gives the same formatter a pattern would, so it formats the same values and parses with the same resolution.
+    /// The conversions are those of GNU `strftime` and `date`, except those depending on the locale or a Sunday-based week:
+    ///
+    /// | Conversion | Meaning | Examples |
+    /// |---|---|---|
+    /// | `%Y` | year | 2004 |
+    /// | `%y` | year in the century, read as 1969 to 2068 | 04 |
+    /// | `%G` | ISO week-based year | 2004 |
+    /// | `%g` | ISO week-based year in the century | 04 |
+    /// | `%m` | month of year | 07 |
+    /// | `%b`/`%h` | abbreviated month name | Jul |
+    /// | `%B` | month name | July |
+    /// | `%d` | day of month | 01 |
+    /// | `%e` | day of month, padded with a space | ␣1 |
+    /// | `%j` | day of year | 182 |
+    /// | `%V` | ISO week of week-based year | 27 |
+    /// | `%u` | ISO day of week, 1 (Monday) to 7 | 2 |
+    /// | `%a` | abbreviated day name | Tue |
+    /// | `%A` | day name | Tuesday |
+    /// | `%H` | hour of day (00-23) | 10 |
+    /// | `%k` | hour of day, padded with a space | 10 |
+    /// | `%I` | clock hour of am-pm (01-12) | 10 |
+    /// | `%l` | clock hour of am-pm, padded with a space | 10 |
+    /// | `%p` | am-pm of day | AM |
+    /// | `%M` | minute of hour | 52 |
+    /// | `%S` | second of minute | 37 |
+    /// | `%N` | nanoseconds, or `%3N` for milliseconds | 123456789 |
+    /// | `%s` | seconds since '1970-01-01T00:00:00Z', ignoring leap seconds | 1057045957 |
+    /// | `%z` | offset | +0200 |
+    /// | `%:z` | offset with a colon | +02:00 |
+    /// | `%::z` | offset with seconds | +02:00:00 |
+    /// | `%Z` | time-zone ID | Europe/Paris |
+    /// | `%F` | same as `%Y-%m-%d` | 2004-07-01 |
+    /// | `%T` | same as `%H:%M:%S` | 10:52:37 |
+    /// | `%R` | same as `%H:%M` | 10:52 |
+    /// | `%D` | same as `%m/%d/%y` | 07/01/04 |
+    /// | `%r` | same as `%I:%M:%S %p` | 10:52:37 AM |
+    /// | `%n`, `%t`, `%%` | newline, tab, '%' | |
+    ///
+    /// Numbers can be given a flag and a width after the '%': `-` leaves out padding, `_` pads with spaces,
+    /// `0` pads with zeros and a width such as `%4Y` pads to that width.
+    ///
+    /// # Parameters
+    ///  - `format`: the format, using the conversions in the table above.
+    pub fn of_strftime(format: &str) -> Result<DateTimeFormatter, ParsePatternError> {
+        let mut printers = Vec::new();
+        compile(format, 0, &mut printers)?;
+        Ok(DateTimeFormatter {
+            printers,
+            resolver_style: ResolverStyle::default(),
+            locale: &ROOT,
+        })
+    }
+}
+
+// Compiles a format, reporting errors at positions from `offset`, the position of the format within the one given.
+fn compile(
+    format: &str,
+    offset: usize,
+    printers: &mut Vec<Printer>,
+) -> Result<(), ParsePatternError> {
+    let bytes = format.as_bytes();
+    let mut literal = String::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            let end = format[i..].find('%').map_or(bytes.len(), |end| i + end);
+            literal.push_str(&format[i..end]);
+            i = end;
+            continue;
+        }
+        let position = offset + i;
+        let error = |reason| ParsePatternError { position, reason };
+        i += 1;
+        let flag = match bytes.get(i) {
+            Some(&flag @ (b'-' | b'_' | b'0')) => {
+                i += 1;
+                Some(flag)
+            }
+            _ => None,
+        };
+        let digits = bytes[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let width = match format[i..i + digits].parse::<usize>() {
+            Ok(width) if (1..=MAX_WIDTH).contains(&width) => Some(width),
+            Ok(_) => return Err(error("width out of range")),
+            Err(_) => None,
+        };
+        i += digits;
+        let colons = bytes[i..]
+            .iter()
+            .take(3)
+            .take_while(|&&c| c == b':')
+            .count();
+        i += colons;
+        let conversion = match format[i..].chars().next() {
+            Some(conversion) => conversion,
+            None => return Err(error("incomplete conversion")),
+        };
+        i += conversion.len_utf8();
+
+        let expansion = match conversion {
+            'F' => Some("%Y-%m-%d"),
+            'T' => Some("%H:%M:%S"),
+            'R' => Some("%H:%M"),
+            'D' => Some("%m/%d/%y"),
+            'r' => Some("%I:%M:%S %p"),
+            _ => None,
+        };
+        let text = match conversion {
+            'n' => Some("\n"),
+            't' => Some("\t"),
+            '%' => Some("%"),
+            _ => None,
+        };
+        if let Some(text) = text {
+            literal.push_str(text);
+            continue;
+        }
+        if !literal.is_empty() {
+            printers.push(Printer::Literal(std::mem::take(&mut literal)));
+        }
+        if let Some(expansion) = expansion {
+            compile(expansion, position, printers)?;
+            continue;
+        }
+        if colons > 0 && conversion != 'z' {
+            return Err(error("unknown conversion"));
+        }
+        let printer = conversion_printer(conversion, colons, width)
+            .ok_or_else(|| error("unknown conversion"))?;
+        printers.push(match printer {
+            Printer::Value {
+                field,
+                min_width,
+                max_width,
+                signed,
+                exceeds_pad,
+                space_pad,
+            } => {
+                let min_width = width.unwrap_or(min_width);
+                Printer::Value {
+                    field,
+                    min_width: if flag == Some(b'-') { 1 } else { min_width },
+                    max_width: max_width.max(min_width),
+                    signed,
+                    exceeds_pad,
+                    space_pad: match flag {
+                        Some(b'_') => true,
+                        Some(_) => false,
+                        None => space_pad,
+                    },
+                }
+            }
+            printer => printer,
+        });
+    }
+    if !literal.is_empty() {
+        printers.push(Printer::Literal(literal));
+    }
+    Ok(())
+}
+
+fn conversion_printer(conversion: char, colons: usize, width: Option<usize>) -> Option<Printer> {
+    let number = |field, width| Printer::Value {
+        field: Field::Chrono(field),
+        min_width: width,
+        max_width: width,
+        signed: false,
+        exceeds_pad: false,
+        space_pad: false,
+    };
+    let spaced = |field| Printer::Value {
+        field: Field::Chrono(field),
+        min_width: 2,
+        max_width: 2,
+        signed: false,
+        exceeds_pad: false,
+        space_pad: true,
+    };
+    let year = |field| Printer::Value {
+        field,
+        min_width: 4,
+        max_width: MAX_WIDTH,
+        signed: true,
+        exceeds_pad: false,
+        space_pad: false,
+    };
+    Some(match conversion {
+        'Y' => year(Field::Chrono(ChronoField::Year)),
+        'y' => Printer::ReducedValue(Field::Chrono(ChronoField::Year), REDUCED_YEAR_BASE),
+        'G' => year(Field::WeekBasedYear(Weeks::Iso)),
+        'g' => Printer::ReducedValue(Field::WeekBasedYear(Weeks::Iso), REDUCED_YEAR_BASE),
+        'm' => number(ChronoField::MonthOfYear, 2),
+        'b' | 'h' => Printer::Text(Field::Chrono(ChronoField::MonthOfYear), TextStyle::Short),
+        'B' => Printer::Text(Field::Chrono(ChronoField::MonthOfYear), TextStyle::Full),
+        'd' => number(ChronoField::DayOfMonth, 2),
+        'e' => spaced(ChronoField::DayOfMonth),
+        'j' => number(ChronoField::DayOfYear, 3),
+        'V' => Printer::Value {
+            field: Field::WeekOfWeekBasedYear(Weeks::Iso),
+            min_width: 2,
+            max_width: 2,
+            signed: false,
+            exceeds_pad: false,
+            space_pad: false,
+        },
+        'u' => number(ChronoField::DayOfWeek, 1),
+        'a' => Printer::Text(Field::Chrono(ChronoField::DayOfWeek), TextStyle::Short),
+        'A' => Printer::Text(Field::Chrono(ChronoField::DayOfWeek), TextStyle::Full),
+        'H' => number(ChronoField::HourOfDay, 2),
+        'k' => spaced(ChronoField::HourOfDay),
+        'I' => number(ChronoField::ClockHourOfAmPm, 2),
+        'l' => spaced(ChronoField::ClockHourOfAmPm),
+        'p' => Printer::Text(Field::Chrono(ChronoField::AmPmOfDay), TextStyle::Short),
+        'M' => number(ChronoField::MinuteOfHour, 2),
+        'S' => number(ChronoField::SecondOfMinute, 2),
+        'N' => Printer::Fraction(width.unwrap_or(NANO_DIGITS).min(NANO_DIGITS)),
+        's' => Printer::Value {
+            field: Field::UnixSeconds,
+            min_width: 1,
+            max_width: MAX_WIDTH,
+            signed: true,
+            exceeds_pad: false,
+            space_pad: false,
+        },
+        'z' => match colons {
+            0 => Printer::Offset(3, "+0000"),
+            1 => Printer::Offset(4, "+00:00"),
+            2 => Printer::Offset(8, "+00:00:00"),
+            _ => return None,
+        },
+        'Z' => Printer::ZoneId,
+        _ => return None,
+    })
+}
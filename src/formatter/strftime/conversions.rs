@@ -0,0 +1,166 @@
+use crate::{DateTimeFormatter, LocalDate, OffsetDateTime, ResolverStyle};
+
+fn strftime(format: &str) -> DateTimeFormatter {
+    DateTimeFormatter::of_strftime(format).unwrap()
+}
+
+fn format(format: &str, date_time: &OffsetDateTime) -> String {
+    strftime(format).format(date_time).unwrap()
+}
+
+fn example() -> OffsetDateTime {
+    OffsetDateTime::of(LocalDate::of(2003, 7, 1), 9, 52, 37, 123_456_789, 7_200)
+}
+
+#[test]
+fn formats() {
+    assert_eq!(
+        "2003-07-01T09:52:37+0200",
+        format("%Y-%m-%dT%H:%M:%S%z", &example())
+    );
+    assert_eq!("+02:00 +02:00:00", format("%:z %::z", &example()));
+    assert_eq!("Tue, 01 Jul 2003", format("%a, %d %b %Y", &example()));
+    assert_eq!("Tuesday July 2 182", format("%A %B %u %j", &example()));
+    assert_eq!(
+        " 1|1|01| 9|09| 9",
+        format("%e|%-d|%0e|%k|%I|%l", &example())
+    );
+    assert_eq!("2003-W27 03", format("%G-W%V %g", &example()));
+    assert_eq!("09:52:37 AM 07/01/03 09:52", format("%r %D %R", &example()));
+    assert_eq!("2003-07-01 09:52:37", format("%F %T", &example()));
+    assert_eq!("123456789 123\n", format("%N %3N%n", &example()));
+    assert_eq!("100%\t", format("100%%%t", &example()));
+    assert_eq!("1057045957", format("%s", &example()));
+}
+
+#[test]
+fn unix_seconds() {
+    let leap_second = OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 60, 0, 0);
+    let formatter = strftime("%s");
+
+    assert_eq!("1483228800", formatter.format(&leap_second).unwrap());
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2003, 7, 1),
+            7,
+            52,
+            37,
+            0,
+            0
+        )),
+        formatter.parse_offset_date_time("1057045957")
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2003, 7, 1),
+            9,
+            52,
+            37,
+            123_456_789,
+            7_200
+        )),
+        strftime("%s.%N%z").parse_offset_date_time("1057045957.123456789+0200")
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(1969, 12, 31),
+            23,
+            59,
+            59,
+            0,
+            0
+        )),
+        formatter.parse_offset_date_time("-1")
+    );
+}
+
+#[test]
+fn parses() {
+    let formatter = strftime("%Y-%m-%dT%H:%M:%S%z");
+
+    assert_eq!(
+        Ok(example().with_offset_seconds(7_200)),
+        strftime("%Y-%m-%dT%H:%M:%S.%N%:z")
+            .parse_offset_date_time("2003-07-01T09:52:37.123456789+02:00")
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2003, 7, 1),
+            9,
+            52,
+            37,
+            0,
+            -18_000
+        )),
+        formatter.parse_offset_date_time("2003-07-01T09:52:37-0500")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%Y%m%d").parse_local_date("20030701")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%-d/%-m/%Y").parse_local_date("1/7/2003")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%e %b %Y").parse_local_date(" 1 jul 2003")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%e %b %Y").parse_local_date("01 Jul 2003")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%Y-%j").parse_local_date("2003-182")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        strftime("%G-W%V-%u").parse_local_date("2003-W27-2")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(1969, 7, 1)),
+        strftime("%D").parse_local_date("07/01/69")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2068, 7, 1)),
+        strftime("%D").parse_local_date("07/01/68")
+    );
+    assert_eq!(
+        Some((21, 52, 37, 0)),
+        strftime("%r").parse("09:52:37 PM").unwrap().time()
+    );
+}
+
+#[test]
+fn resolves_as_patterns() {
+    let formatter = strftime("%F").with_resolver_style(ResolverStyle::Strict);
+
+    assert_eq!(
+        "invalid date-time at position 8: day of month out of range",
+        formatter
+            .parse_local_date("2003-02-30")
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        DateTimeFormatter::of_pattern("uuuu'-'MM'-'dd")
+            .unwrap()
+            .format(&example()),
+        strftime("%4Y-%m-%d").format(&example())
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |format| {
+        let error = DateTimeFormatter::of_strftime(format).unwrap_err();
+        (error.position(), error.reason())
+    };
+
+    assert_eq!((3, "unknown conversion"), error("%Y-%Q"));
+    assert_eq!((3, "incomplete conversion"), error("%Y-%"));
+    assert_eq!((0, "unknown conversion"), error("%:Y"));
+    assert_eq!((0, "unknown conversion"), error("%:::z"));
+    assert_eq!((0, "width out of range"), error("%20Y"));
+}
@@ -1,17 +1,33 @@
-use std::i64;
+use std::fmt;
+use std::str::FromStr;
 
+use crate::calendar::*;
 use crate::constants::*;
+use crate::iso::*;
+use crate::rounding::*;
 use crate::seconds_nanos::*;
 use crate::util::const_expect;
+use crate::{ChronoUnit, DateTimeError, Duration};
 
 #[cfg(test)]
+#[allow(clippy::legacy_numeric_constants)]
 pub mod factories;
+#[cfg(test)]
+pub mod julian_days;
+#[cfg(test)]
+pub mod rounding;
+#[cfg(test)]
+pub mod text;
 
 /// An instantaneous point in time along the timeline.
 ///
 /// This is explicitly a TAI instant.
 /// This means that, among other things, the civil time an instant maps to
 /// (a normal calendar date and time) changes over time, as leap seconds are added to the civil clock.
+/// Seconds are counted from '1970-01-01 00:00:00' on the TAI time scale;
+/// use a [`TaiUtcTable`] to relate instants to UTC.
+///
+/// [`TaiUtcTable`]: struct.TaiUtcTable.html
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Instant {
     epoch_second: i64,
@@ -84,12 +100,18 @@ impl Instant {
         )
     }
 
-    const fn of_epoch_second_and_adjustment_checked(
-        seconds: i64,
+    /// Obtains an Instant using seconds and an adjustment in nanoseconds since '1970-01-01 00:00:00.000000000Z',
+    /// or `None` if the adjusted seconds would overflow the instant.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    pub const fn of_epoch_second_and_adjustment_checked(
+        epoch_seconds: i64,
         nano_adjustment: i64,
     ) -> Option<Instant> {
         // TODO: switch back to map when constant is stable
-        match of_seconds_and_adjustment_checked(seconds, nano_adjustment) {
+        match of_seconds_and_adjustment_checked(epoch_seconds, nano_adjustment) {
             None => None,
             Some((seconds, nanos)) => Some(Instant {
                 epoch_second: seconds,
@@ -98,6 +120,113 @@ impl Instant {
         }
     }
 
+    /// Obtains an Instant using seconds and an adjustment in nanoseconds since '1970-01-01 00:00:00.000000000Z',
+    /// or an error if the adjusted seconds would overflow the instant.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    pub fn try_of_epoch_second_and_adjustment(
+        epoch_seconds: i64,
+        nano_adjustment: i64,
+    ) -> Result<Instant, DateTimeError> {
+        Instant::of_epoch_second_and_adjustment_checked(epoch_seconds, nano_adjustment)
+            .ok_or(DateTimeError::Overflow)
+    }
+
+    /// Obtains an Instant using microseconds since '1970-01-01 00:00:00.000000Z'.
+    ///
+    /// # Parameters
+    ///  - `epoch_microseconds`: the microseconds since the epoch.
+    pub const fn of_epoch_micro(epoch_microseconds: i64) -> Instant {
+        Instant {
+            epoch_second: epoch_microseconds.div_euclid(MICROSECONDS_IN_SECOND),
+            nanosecond_of_second: (epoch_microseconds.rem_euclid(MICROSECONDS_IN_SECOND)
+                * NANOSECONDS_IN_MICROSECOND) as u32,
+        }
+    }
+
+    /// Obtains an Instant using nanoseconds since '1970-01-01 00:00:00.000000000Z'.
+    ///
+    /// This is the inverse of [`to_epoch_nano()`].
+    ///
+    /// # Parameters
+    ///  - `epoch_nanoseconds`: the nanoseconds since the epoch.
+    ///
+    /// # Panics
+    /// - if the nanoseconds would overflow the instant.
+    ///
+    /// [`to_epoch_nano()`]: struct.Instant.html#method.to_epoch_nano
+    pub const fn of_epoch_nano(epoch_nanoseconds: i128) -> Instant {
+        const_expect!(
+            Instant::of_epoch_nano_checked(epoch_nanoseconds),
+            "nanos would overflow instant"
+        )
+    }
+
+    /// Obtains an Instant using nanoseconds since '1970-01-01 00:00:00.000000000Z',
+    /// or an error if they would overflow the instant.
+    ///
+    /// # Parameters
+    ///  - `epoch_nanoseconds`: the nanoseconds since the epoch.
+    pub fn try_of_epoch_nano(epoch_nanoseconds: i128) -> Result<Instant, DateTimeError> {
+        Instant::of_epoch_nano_checked(epoch_nanoseconds).ok_or(DateTimeError::Overflow)
+    }
+
+    const fn of_epoch_nano_checked(epoch_nanoseconds: i128) -> Option<Instant> {
+        match of_total_nanos_checked(epoch_nanoseconds) {
+            None => None,
+            Some((epoch_second, nanosecond_of_second)) => Some(Instant {
+                epoch_second,
+                nanosecond_of_second,
+            }),
+        }
+    }
+
+    /// Obtains an Instant from a Julian Ephemeris Day, a (fractional) Julian Day on the Terrestrial Time scale.
+    ///
+    /// This is the time argument of most astronomical algorithms.
+    /// Being a floating point number, it is only precise to around a microsecond for dates in recent millennia.
+    ///
+    /// # Parameters
+    ///  - `julian_ephemeris_day`: the Julian Day in Terrestrial Time.
+    ///
+    /// # Panics
+    /// - if the day is not finite, or would overflow the instant.
+    pub fn of_julian_ephemeris_day(julian_ephemeris_day: f64) -> Instant {
+        const_expect!(
+            Instant::of_julian_ephemeris_day_checked(julian_ephemeris_day),
+            "julian day would overflow instant"
+        )
+    }
+
+    /// Obtains an Instant from a Julian Ephemeris Day,
+    /// or an error if the day is not finite or would overflow the instant.
+    ///
+    /// # Parameters
+    ///  - `julian_ephemeris_day`: the Julian Day in Terrestrial Time.
+    pub fn try_of_julian_ephemeris_day(
+        julian_ephemeris_day: f64,
+    ) -> Result<Instant, DateTimeError> {
+        Instant::of_julian_ephemeris_day_checked(julian_ephemeris_day)
+            .ok_or(DateTimeError::Overflow)
+    }
+
+    fn of_julian_ephemeris_day_checked(julian_ephemeris_day: f64) -> Option<Instant> {
+        let seconds = (julian_ephemeris_day - JULIAN_DAY_OF_EPOCH) * SECONDS_IN_DAY as f64;
+        let whole_seconds = seconds.floor();
+        // `as` saturates, so anything out of range would otherwise silently clamp.
+        if !(whole_seconds > i64::MIN as f64 && whole_seconds < i64::MAX as f64) {
+            return None;
+        }
+        let nanos = ((seconds - whole_seconds) * NANOSECONDS_IN_SECOND as f64).round() as i64;
+
+        Instant::of_epoch_second_and_adjustment_checked(
+            whole_seconds as i64,
+            nanos - TERRESTRIAL_TIME_OFFSET_FROM_TAI_NANOSECONDS,
+        )
+    }
+
     /// Gets the number of seconds before or after the epoch.
     ///
     /// [`nanos()`]: struct.Instant.html#method.nanos
@@ -111,4 +240,152 @@ impl Instant {
     pub const fn nano(&self) -> u32 {
         self.nanosecond_of_second
     }
+
+    /// Gets the number of whole milliseconds since the epoch.
+    ///
+    /// Any smaller part is dropped, so the milliseconds are always at or before this instant,
+    /// as in java.time, even before the epoch.
+    ///
+    /// # Panics
+    /// - if the milliseconds would overflow an `i64`, which is possible more than about 292 million years from the epoch.
+    pub const fn to_epoch_milli(&self) -> i64 {
+        const_expect!(self.to_epoch_milli_checked(), "millis would overflow")
+    }
+
+    /// Gets the number of whole milliseconds since the epoch, or `None` if they would overflow an `i64`.
+    pub const fn to_epoch_milli_checked(&self) -> Option<i64> {
+        i64_of(
+            self.to_epoch_nano()
+                .div_euclid(NANOSECONDS_IN_MILLISECOND as i128),
+        )
+    }
+
+    /// Gets the number of whole microseconds since the epoch, dropping any smaller part as for [`to_epoch_milli()`].
+    ///
+    /// # Panics
+    /// - if the microseconds would overflow an `i64`, which is possible more than about 292 thousand years from the epoch.
+    ///
+    /// [`to_epoch_milli()`]: struct.Instant.html#method.to_epoch_milli
+    pub const fn to_epoch_micro(&self) -> i64 {
+        const_expect!(self.to_epoch_micro_checked(), "micros would overflow")
+    }
+
+    /// Gets the number of whole microseconds since the epoch, or `None` if they would overflow an `i64`.
+    pub const fn to_epoch_micro_checked(&self) -> Option<i64> {
+        i64_of(
+            self.to_epoch_nano()
+                .div_euclid(NANOSECONDS_IN_MICROSECOND as i128),
+        )
+    }
+
+    /// Gets the exact number of nanoseconds since the epoch.
+    ///
+    /// Every instant fits in an `i128` of nanoseconds, so this never overflows.
+    pub const fn to_epoch_nano(&self) -> i128 {
+        total_nanos(self.epoch_second, self.nanosecond_of_second)
+    }
+
+    /// Gets the Julian Ephemeris Day of this instant, a (fractional) Julian Day on the Terrestrial Time scale.
+    ///
+    /// [`of_julian_ephemeris_day()`]: struct.Instant.html#method.of_julian_ephemeris_day
+    pub fn to_julian_ephemeris_day(&self) -> f64 {
+        let nanos = self.nanosecond_of_second as i64 + TERRESTRIAL_TIME_OFFSET_FROM_TAI_NANOSECONDS;
+        let seconds = self.epoch_second as f64 + nanos as f64 / NANOSECONDS_IN_SECOND as f64;
+        JULIAN_DAY_OF_EPOCH + seconds / SECONDS_IN_DAY as f64
+    }
+
+    /// Returns a copy of this instant truncated to a unit, such as the start of its minute.
+    ///
+    /// The unit must fit in a day, from nanoseconds to days, and truncation is always earlier in time,
+    /// so instants before the epoch truncate the same way as those after it.
+    /// Days are counted in TAI from the epoch, without leap seconds.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit to truncate to.
+    ///
+    /// # Panics
+    /// - if the unit is longer than a day.
+    /// - if the truncated instant would overflow.
+    pub const fn truncated_to(&self, unit: ChronoUnit) -> Instant {
+        let granularity = truncation_nanos(unit);
+        self.rounded_to_nanos(granularity, RoundingMode::Floor)
+    }
+
+    /// Returns a copy of this instant rounded to a multiple of a duration since the epoch, such as 15 minutes.
+    ///
+    /// # Parameters
+    ///  - `granularity`: the duration to round to a multiple of, which must be positive.
+    ///  - `mode`: how to round instants between two multiples.
+    ///
+    /// # Panics
+    /// - if the granularity is not positive.
+    /// - if the rounded instant would overflow.
+    pub const fn rounded_to(&self, granularity: Duration, mode: RoundingMode) -> Instant {
+        let granularity = total_nanos(granularity.seconds(), granularity.nano());
+        if granularity <= 0 {
+            panic!("granularity must be positive");
+        }
+        self.rounded_to_nanos(granularity, mode)
+    }
+
+    const fn rounded_to_nanos(&self, granularity: i128, mode: RoundingMode) -> Instant {
+        let nanos = round(
+            total_nanos(self.epoch_second, self.nanosecond_of_second),
+            granularity,
+            mode,
+        );
+        match of_total_nanos_checked(nanos) {
+            None => panic!("instant would overflow"),
+            Some((epoch_second, nanosecond_of_second)) => Instant {
+                epoch_second,
+                nanosecond_of_second,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Instant {
+    /// Formats this instant as an ISO-8601 date-time on the TAI time scale, such as '2007-12-03T10:15:30 TAI'.
+    ///
+    /// The fraction of the second is omitted when zero, and otherwise uses three, six or nine digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = date_of_epoch_day(self.epoch_second.div_euclid(SECONDS_IN_DAY));
+        format_date(f, year, month, day)?;
+        write!(f, "T")?;
+        format_time(
+            f,
+            self.epoch_second.rem_euclid(SECONDS_IN_DAY),
+            self.nanosecond_of_second,
+        )?;
+        write!(f, " TAI")
+    }
+}
+
+impl FromStr for Instant {
+    type Err = ParseIsoError;
+
+    /// Parses an ISO-8601 date-time on the TAI time scale, such as '2007-12-03T10:15:30 TAI'.
+    ///
+    /// Text labelled as UTC (such as '2007-12-03T10:15:30Z') is rejected,
+    /// as converting it requires a [`TaiUtcTable`].
+    ///
+    /// [`TaiUtcTable`]: struct.TaiUtcTable.html
+    fn from_str(text: &str) -> Result<Instant, ParseIsoError> {
+        let mut parser = Parser::of(text);
+        let (epoch_day, second_of_day, nano) = parser.date_time(false)?;
+        let label = parser.position();
+        if parser.scale()? != Scale::Tai {
+            return Err(parser.error_at(label, "expected ' TAI'"));
+        }
+        parser.end()?;
+
+        epoch_day
+            .checked_mul(SECONDS_IN_DAY)
+            .and_then(|seconds| seconds.checked_add(second_of_day))
+            .map(|epoch_second| Instant {
+                epoch_second,
+                nanosecond_of_second: nano,
+            })
+            .ok_or_else(|| parser.error_at(0, "instant out of range"))
+    }
 }
@@ -127,3 +127,87 @@ proptest! {
         prop_assert_eq!(seconds, instant.epoch_second());
     }
 }
+
+proptest! {
+    #[test]
+    fn of_epoch_second_and_adjustment_checked_overflow((seconds, nanoseconds) in adjustment_overflow()) {
+        prop_assert_eq!(None, Instant::of_epoch_second_and_adjustment_checked(seconds, nanoseconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_epoch_second_and_adjustment_checked((seconds, adjustment) in seconds_and_adjustment()) {
+        prop_assert_eq!(
+            Some(Instant::of_epoch_second_and_adjustment(seconds, adjustment)),
+            Instant::of_epoch_second_and_adjustment_checked(seconds, adjustment)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn epoch_milli_round_trip(millis in prop::num::i64::ANY) {
+        prop_assert_eq!(millis, Instant::of_epoch_milli(millis).to_epoch_milli());
+    }
+}
+
+proptest! {
+    #[test]
+    fn epoch_micro_round_trip(micros in prop::num::i64::ANY) {
+        let instant = Instant::of_epoch_micro(micros);
+
+        prop_assert_eq!(micros, instant.to_epoch_micro());
+        prop_assert_eq!(micros as i128 * NANOSECONDS_IN_MICROSECOND as i128, instant.to_epoch_nano());
+    }
+}
+
+proptest! {
+    #[test]
+    fn epoch_nano_round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(instant, Instant::of_epoch_nano(instant.to_epoch_nano()));
+    }
+}
+
+#[test]
+fn to_epoch_units_before_epoch() {
+    let instant = Instant::of_epoch_second_and_adjustment(-1, 999_999_999);
+
+    assert_eq!(-1, instant.to_epoch_milli());
+    assert_eq!(-1, instant.to_epoch_micro());
+    assert_eq!(-1, instant.to_epoch_nano());
+}
+
+#[test]
+fn to_epoch_units_overflow() {
+    assert_eq!(None, Instant::MAX.to_epoch_milli_checked());
+    assert_eq!(None, Instant::MIN.to_epoch_micro_checked());
+    assert_eq!(
+        Some(i64::MAX),
+        Instant::of_epoch_milli(i64::MAX).to_epoch_milli_checked()
+    );
+    expect_panic("millis would overflow", || Instant::MIN.to_epoch_milli()).unwrap();
+    expect_panic("micros would overflow", || Instant::MAX.to_epoch_micro()).unwrap();
+}
+
+#[test]
+fn of_epoch_nano_overflow() {
+    assert_eq!(
+        Instant::MAX,
+        Instant::of_epoch_nano(Instant::MAX.to_epoch_nano())
+    );
+    assert_eq!(
+        Instant::MIN,
+        Instant::of_epoch_nano(Instant::MIN.to_epoch_nano())
+    );
+    expect_panic("nanos would overflow instant", || {
+        Instant::of_epoch_nano(Instant::MAX.to_epoch_nano() + 1)
+    })
+    .unwrap();
+    expect_panic("nanos would overflow instant", || {
+        Instant::of_epoch_nano(Instant::MIN.to_epoch_nano() - 1)
+    })
+    .unwrap();
+}
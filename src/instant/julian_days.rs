@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::Instant;
+
+use crate::assert::expect_panic;
+
+#[test]
+fn to_julian_ephemeris_day_of_epoch() {
+    let expected = JULIAN_DAY_OF_EPOCH + 32.184 / SECONDS_IN_DAY as f64;
+
+    assert!((expected - Instant::EPOCH.to_julian_ephemeris_day()).abs() < 1e-9);
+}
+
+#[test]
+fn of_julian_ephemeris_day_of_j2000() {
+    // J2000.0 is '2000-01-01 12:00:00' TT, or '2000-01-01 11:59:27.816' TAI.
+    let instant = Instant::of_julian_ephemeris_day(2_451_545.0);
+
+    assert_eq!(946_727_967, instant.epoch_second());
+    assert_eq!(816_000_000, instant.nano());
+}
+
+proptest! {
+    #[test]
+    fn julian_ephemeris_day_round_trip(seconds in -100_000_000_000i64..100_000_000_000, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let round_trip = Instant::of_julian_ephemeris_day(instant.to_julian_ephemeris_day());
+
+        let difference = (round_trip.epoch_second() - instant.epoch_second()) * NANOSECONDS_IN_SECOND
+            + round_trip.nano() as i64 - instant.nano() as i64;
+        prop_assert!(difference.abs() < 100_000, "difference of {} nanoseconds", difference);
+    }
+}
+
+#[test]
+fn of_julian_ephemeris_day_overflow() {
+    expect_panic("julian day would overflow instant", || {
+        Instant::of_julian_ephemeris_day(f64::NAN)
+    })
+    .unwrap();
+    expect_panic("julian day would overflow instant", || {
+        Instant::of_julian_ephemeris_day(1e30)
+    })
+    .unwrap();
+}
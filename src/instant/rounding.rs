@@ -0,0 +1,96 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{ChronoUnit, Duration, Instant, RoundingMode, Temporal};
+
+#[test]
+fn truncated_to() {
+    let instant = Instant::of_epoch_second_and_adjustment(SECONDS_IN_DAY + 3_723, 456_789_012);
+
+    assert_eq!(instant, instant.truncated_to(ChronoUnit::Nanos));
+    assert_eq!(
+        Instant::of_epoch_second_and_adjustment(SECONDS_IN_DAY + 3_723, 456_789_000),
+        instant.truncated_to(ChronoUnit::Micros)
+    );
+    assert_eq!(
+        Instant::of_epoch_second(SECONDS_IN_DAY + 3_720),
+        instant.truncated_to(ChronoUnit::Minutes)
+    );
+    assert_eq!(
+        Instant::of_epoch_second(SECONDS_IN_DAY),
+        instant.truncated_to(ChronoUnit::HalfDays)
+    );
+    assert_eq!(
+        Instant::of_epoch_second(SECONDS_IN_DAY),
+        instant.truncated_to(ChronoUnit::Days)
+    );
+    // Before the epoch, truncation is still earlier in time.
+    assert_eq!(
+        Instant::of_epoch_second(-2),
+        Instant::of_epoch_milli(-1_500).truncated_to(ChronoUnit::Seconds)
+    );
+    assert_eq!(
+        Instant::of_epoch_second(-SECONDS_IN_HOUR),
+        Instant::of_epoch_second(-1).truncated_to(ChronoUnit::Hours)
+    );
+}
+
+#[test]
+fn truncated_to_invalid() {
+    expect_panic("unit is too large for truncation", || {
+        Instant::EPOCH.truncated_to(ChronoUnit::Months)
+    })
+    .unwrap();
+    expect_panic("unit is too large for truncation", || {
+        Instant::EPOCH.truncated_to(ChronoUnit::Forever)
+    })
+    .unwrap();
+    expect_panic("instant would overflow", || {
+        Instant::MIN.truncated_to(ChronoUnit::Minutes)
+    })
+    .unwrap();
+}
+
+#[test]
+fn rounded_to() {
+    let quarter = Duration::of_minutes(15);
+    let at = |seconds| Instant::of_epoch_second(seconds);
+
+    assert_eq!(at(900), at(1_349).rounded_to(quarter, RoundingMode::HalfUp));
+    assert_eq!(
+        at(1_800),
+        at(1_350).rounded_to(quarter, RoundingMode::HalfUp)
+    );
+    assert_eq!(at(-900), at(-450).rounded_to(quarter, RoundingMode::HalfUp));
+    assert_eq!(at(0), at(-450).rounded_to(quarter, RoundingMode::HalfEven));
+    assert_eq!(at(-900), at(-1).rounded_to(quarter, RoundingMode::Floor));
+    assert_eq!(at(0), at(-1).rounded_to(quarter, RoundingMode::Ceiling));
+    expect_panic("granularity must be positive", || {
+        at(0).rounded_to(Duration::ZERO, RoundingMode::Floor)
+    })
+    .unwrap();
+    expect_panic("instant would overflow", || {
+        Instant::MAX.rounded_to(quarter, RoundingMode::Ceiling)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn truncated_to_is_floor(
+        seconds in -1_000_000_000_000..1_000_000_000_000i64,
+        nanos in 0..NANOSECONDS_IN_SECOND,
+    ) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        for &unit in &[ChronoUnit::Millis, ChronoUnit::Seconds, ChronoUnit::Hours, ChronoUnit::Days] {
+            let truncated = instant.truncated_to(unit);
+
+            prop_assert_eq!(instant.rounded_to(unit.duration(), RoundingMode::Floor), truncated);
+            prop_assert!(truncated <= instant);
+            prop_assert_eq!(Ok(0), truncated.until(&instant, unit));
+        }
+    }
+}
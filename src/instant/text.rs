@@ -0,0 +1,96 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{Instant, ParseIsoError};
+
+fn error(text: &str) -> (usize, &'static str) {
+    let error: ParseIsoError = text.parse::<Instant>().unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn display() {
+    assert_eq!("1970-01-01T00:00:00 TAI", Instant::EPOCH.to_string());
+    assert_eq!(
+        "2007-12-03T10:15:30 TAI",
+        Instant::of_epoch_second(1_196_676_930).to_string()
+    );
+    assert_eq!(
+        "1970-01-01T00:00:00.500 TAI",
+        Instant::of_epoch_milli(500).to_string()
+    );
+    assert_eq!(
+        "1970-01-01T00:00:00.000001 TAI",
+        Instant::of_epoch_second_and_adjustment(0, 1_000).to_string()
+    );
+    assert_eq!(
+        "1969-12-31T23:59:59.999999999 TAI",
+        Instant::of_epoch_second_and_adjustment(0, -1).to_string()
+    );
+    assert_eq!(
+        "-0001-12-31T00:00:00 TAI",
+        Instant::of_epoch_second(-62_167_219_200 - SECONDS_IN_DAY).to_string()
+    );
+    assert_eq!(
+        "+10000-01-01T00:00:00 TAI",
+        Instant::of_epoch_second(253_402_300_800).to_string()
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        Ok(Instant::of_epoch_second(1_196_676_930)),
+        "2007-12-03T10:15:30 TAI".parse()
+    );
+    assert_eq!(
+        Ok(Instant::of_epoch_second(1_196_676_900)),
+        "2007-12-03T10:15 TAI".parse()
+    );
+    assert_eq!(
+        Ok(Instant::of_epoch_milli(1_196_676_930_500)),
+        "2007-12-03t10:15:30,5 tai".parse()
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!((19, "expected ' TAI'"), error("2007-12-03T10:15:30Z"));
+    assert_eq!((19, "expected ' TAI' or 'Z'"), error("2007-12-03T10:15:30"));
+    assert_eq!(
+        (0, "years beyond 9999 must be signed"),
+        error("20070-12-03T10:15:30 TAI")
+    );
+    assert_eq!((5, "month out of range"), error("2007-13-03T10:15:30 TAI"));
+    assert_eq!(
+        (8, "day of month out of range"),
+        error("2007-02-29T10:15:30 TAI")
+    );
+    assert_eq!((11, "hour out of range"), error("2007-12-03T24:00:00 TAI"));
+    assert_eq!(
+        (17, "second out of range"),
+        error("2016-12-31T23:59:60 TAI")
+    );
+    assert_eq!(
+        (20, "invalid fraction of second"),
+        error("2007-12-03T10:15:30. TAI")
+    );
+    assert_eq!(
+        (23, "unexpected trailing text"),
+        error("2007-12-03T10:15:30 TAI ")
+    );
+    assert_eq!(
+        (0, "instant out of range"),
+        error("+999999999999-01-01T00:00:00 TAI")
+    );
+}
+
+proptest! {
+    #[test]
+    fn round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(instant), instant.to_string().parse());
+    }
+}
@@ -0,0 +1,399 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::calendar::*;
+use crate::constants::*;
+use crate::iso::*;
+use crate::{LocalDate, OffsetDateTime};
+
+#[cfg(test)]
+pub mod formats;
+
+// The date-time formats of internet mail (RFC 2822) and HTTP (RFC 7231),
+// along with the older forms HTTP recipients still have to accept.
+
+const DAY_NAMES: [&str; DAYS_IN_WEEK_ISO as usize] =
+    ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const LONG_DAY_NAMES: [&str; DAYS_IN_WEEK_ISO as usize] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const MONTH_NAMES: [&str; MONTHS_IN_YEAR as usize] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+// The obsolete North American zone names of RFC 2822, with their offsets in hours.
+const ZONE_NAMES: [(&str, i32); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+const MAX_YEAR_DIGITS: usize = 9;
+
+/// An error parsing an RFC 2822 or HTTP date-time.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseInternetDateError {
+    position: usize,
+    reason: &'static str,
+}
+
+impl ParseInternetDateError {
+    /// Gets the (byte) position in the text the error was found at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets a short description of what was wrong.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseInternetDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid date-time at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl Error for ParseInternetDateError {}
+
+impl ParseInternetDateError {
+    fn of(error: ParseIsoError) -> ParseInternetDateError {
+        ParseInternetDateError {
+            position: error.position(),
+            reason: error.reason(),
+        }
+    }
+}
+
+impl OffsetDateTime {
+    /// Parses an RFC 2822 date-time, such as 'Tue, 1 Jul 2003 10:52:37 +0200'.
+    ///
+    /// The obsolete syntax is accepted too: two and three digit years, the North American zone names
+    /// (such as 'EST'), military zones (read as '+0000', since their meaning was never agreed),
+    /// and comments and folding whitespace between the parts.
+    /// A leap second (':60') is accepted at the end of any UTC day, and is checked when converting to an instant.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse.
+    pub fn parse_rfc2822(text: &str) -> Result<OffsetDateTime, ParseInternetDateError> {
+        rfc2822(&mut Parser::of(text)).map_err(ParseInternetDateError::of)
+    }
+
+    /// Formats this date-time as RFC 2822, such as 'Tue, 1 Jul 2003 10:52:37 +0200'.
+    ///
+    /// The fraction of the second is dropped.
+    ///
+    /// # Returns
+    /// The text, or `None` if the year is before 1900, or the offset is not a whole number of minutes.
+    pub fn to_rfc2822(&self) -> Option<String> {
+        let date = self.date();
+        if date.year() < 1900 || self.offset_seconds() as i64 % SECONDS_IN_MINUTE != 0 {
+            return None;
+        }
+        let offset_minutes = self.offset_seconds().unsigned_abs() as i64 / SECONDS_IN_MINUTE;
+        Some(format!(
+            "{}, {} {} {} {:02}:{:02}:{:02} {}{:02}{:02}",
+            DAY_NAMES[date.day_of_week().value() as usize - 1],
+            date.day_of_month(),
+            MONTH_NAMES[date.month_value() as usize - 1],
+            date.year(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            if self.offset_seconds() < 0 { '-' } else { '+' },
+            offset_minutes / MINUTES_IN_HOUR,
+            offset_minutes % MINUTES_IN_HOUR
+        ))
+    }
+
+    /// Parses an HTTP date, in any of the three forms RFC 7231 requires recipients to accept:
+    /// IMF-fixdate ('Sun, 06 Nov 1994 08:49:37 GMT'), RFC 850 ('Sunday, 06-Nov-94 08:49:37 GMT'),
+    /// or the C `asctime()` format ('Sun Nov  6 08:49:37 1994').
+    ///
+    /// The two digit years of RFC 850 dates are read as 1950 to 2049, rather than relative to the current date.
+    /// A leap second (':60') is accepted at the end of the day, and is checked when converting to an instant.
+    ///
+    /// # Parameters
+    ///  - `text`: the text to parse.
+    ///
+    /// # Returns
+    /// The date-time, which is always in UTC (with a zero offset).
+    pub fn parse_http_date(text: &str) -> Result<OffsetDateTime, ParseInternetDateError> {
+        http_date(&mut Parser::of(text)).map_err(ParseInternetDateError::of)
+    }
+
+    /// Formats this date-time as an HTTP IMF-fixdate, such as 'Sun, 06 Nov 1994 08:49:37 GMT'.
+    ///
+    /// The date-time is converted to UTC, and the fraction of the second is dropped.
+    ///
+    /// # Returns
+    /// The text, or `None` if the year (in UTC) is not between 0 and 9999.
+    pub fn to_http_date(&self) -> Option<String> {
+        let utc = self.with_offset_seconds(0);
+        let date = utc.date();
+        if !(0..=9999).contains(&date.year()) {
+            return None;
+        }
+        Some(format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            DAY_NAMES[date.day_of_week().value() as usize - 1],
+            date.day_of_month(),
+            MONTH_NAMES[date.month_value() as usize - 1],
+            date.year(),
+            utc.hour(),
+            utc.minute(),
+            utc.second()
+        ))
+    }
+}
+
+fn rfc2822(parser: &mut Parser<'_>) -> Result<OffsetDateTime, ParseIsoError> {
+    comments_and_whitespace(parser)?;
+    let day_of_week = if matches!(parser.peek(), Some(c) if c.is_ascii_alphabetic()) {
+        let start = parser.position();
+        let day_of_week = name(parser, &DAY_NAMES, "invalid day of week")?;
+        comments_and_whitespace(parser)?;
+        parser.expect(b',', "expected ','")?;
+        comments_and_whitespace(parser)?;
+        Some((start, day_of_week))
+    } else {
+        None
+    };
+
+    let day_start = parser.position();
+    let (day, _) = parser.digits(1, 2, "invalid day of month")?;
+    comments_and_whitespace(parser)?;
+    let month = name(parser, &MONTH_NAMES, "invalid month")?;
+    comments_and_whitespace(parser)?;
+    let year = match parser.digits(2, MAX_YEAR_DIGITS, "invalid year")? {
+        (year, 2) => two_digit_year(year),
+        (year, 3) => year + 1900,
+        (year, _) => year,
+    };
+    if !comments_and_whitespace(parser)? {
+        return Err(parser.error("expected whitespace"));
+    }
+
+    let time_start = parser.position();
+    let (hour, _) = parser.digits(2, 2, "invalid hour")?;
+    comments_and_whitespace(parser)?;
+    parser.expect(b':', "expected ':'")?;
+    comments_and_whitespace(parser)?;
+    let (minute, _) = parser.digits(2, 2, "invalid minute")?;
+    comments_and_whitespace(parser)?;
+    let second = if parser.eat(b':') {
+        comments_and_whitespace(parser)?;
+        parser.digits(2, 2, "invalid second")?.0
+    } else {
+        0
+    };
+    comments_and_whitespace(parser)?;
+    let offset_seconds = rfc2822_zone(parser)?;
+    comments_and_whitespace(parser)?;
+    parser.end()?;
+
+    let date = date(parser, day_start, year, month, day)?;
+    if let Some((start, day_of_week)) = day_of_week {
+        if date.day_of_week().value() != day_of_week {
+            return Err(parser.error_at(start, "day of week does not match date"));
+        }
+    }
+    let time = (hour, minute, second);
+    date_time(parser, date, time_start, time, offset_seconds)
+}
+
+fn http_date(parser: &mut Parser<'_>) -> Result<OffsetDateTime, ParseIsoError> {
+    let day_of_week_start = parser.position();
+    let day_of_week_name = parser.letters();
+
+    let (day_of_week, day_start, month, day, year) = if parser.eat(b',') {
+        parser.expect(b' ', "expected ' '")?;
+        let day_start = parser.position();
+        let (day, _) = parser.digits(2, 2, "invalid day of month")?;
+        if parser.eat(b' ') {
+            // IMF-fixdate
+            let month = name(parser, &MONTH_NAMES, "invalid month")?;
+            parser.expect(b' ', "expected ' '")?;
+            let (year, _) = parser.digits(4, 4, "invalid year")?;
+            let day_of_week = index(day_of_week_name, &DAY_NAMES);
+            (day_of_week, day_start, month, day, Some(year))
+        } else {
+            // RFC 850
+            parser.expect(b'-', "expected '-'")?;
+            let month = name(parser, &MONTH_NAMES, "invalid month")?;
+            parser.expect(b'-', "expected '-'")?;
+            let (year, _) = parser.digits(2, 2, "invalid year")?;
+            let day_of_week = index(day_of_week_name, &LONG_DAY_NAMES);
+            (
+                day_of_week,
+                day_start,
+                month,
+                day,
+                Some(two_digit_year(year)),
+            )
+        }
+    } else {
+        // asctime, where the year comes last
+        parser.expect(b' ', "expected ' '")?;
+        let month = name(parser, &MONTH_NAMES, "invalid month")?;
+        parser.expect(b' ', "expected ' '")?;
+        parser.eat(b' ');
+        let day_start = parser.position();
+        let (day, _) = parser.digits(1, 2, "invalid day of month")?;
+        let day_of_week = index(day_of_week_name, &DAY_NAMES);
+        (day_of_week, day_start, month, day, None)
+    };
+    let day_of_week =
+        day_of_week.ok_or_else(|| parser.error_at(day_of_week_start, "invalid day of week"))?;
+
+    parser.expect(b' ', "expected ' '")?;
+    let time_start = parser.position();
+    let (hour, _) = parser.digits(2, 2, "invalid hour")?;
+    parser.expect(b':', "expected ':'")?;
+    let (minute, _) = parser.digits(2, 2, "invalid minute")?;
+    parser.expect(b':', "expected ':'")?;
+    let (second, _) = parser.digits(2, 2, "invalid second")?;
+    parser.expect(b' ', "expected ' '")?;
+    let year = match year {
+        Some(year) => {
+            let zone_start = parser.position();
+            if !parser.letters().eq_ignore_ascii_case(b"GMT") {
+                return Err(parser.error_at(zone_start, "expected 'GMT'"));
+            }
+            year
+        }
+        None => parser.digits(4, 4, "invalid year")?.0,
+    };
+    parser.end()?;
+
+    let date = date(parser, day_start, year, month, day)?;
+    if date.day_of_week().value() != day_of_week {
+        return Err(parser.error_at(day_of_week_start, "day of week does not match date"));
+    }
+    date_time(parser, date, time_start, (hour, minute, second), 0)
+}
+
+// Skips whitespace (including line folding) and comments, returning whether there were any.
+fn comments_and_whitespace(parser: &mut Parser<'_>) -> Result<bool, ParseIsoError> {
+    let start = parser.position();
+    loop {
+        if parser.eat(b' ') || parser.eat(b'\t') || parser.eat(b'\r') || parser.eat(b'\n') {
+            continue;
+        }
+        if !parser.eat(b'(') {
+            return Ok(parser.position() > start);
+        }
+        let mut depth = 1;
+        while depth > 0 {
+            match parser.next() {
+                None => return Err(parser.error("unterminated comment")),
+                Some(b'(') => depth += 1,
+                Some(b')') => depth -= 1,
+                Some(b'\\') => {
+                    parser.next();
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+fn index(name: &[u8], names: &[&str]) -> Option<u32> {
+    names
+        .iter()
+        .position(|candidate| candidate.as_bytes().eq_ignore_ascii_case(name))
+        .map(|index| index as u32 + 1)
+}
+
+// Consumes one of the given names, returning its (one-based) number.
+fn name(
+    parser: &mut Parser<'_>,
+    names: &[&str],
+    reason: &'static str,
+) -> Result<u32, ParseIsoError> {
+    let start = parser.position();
+    let name = parser.letters();
+    index(name, names).ok_or_else(|| parser.error_at(start, reason))
+}
+
+const fn two_digit_year(year: i64) -> i64 {
+    if year < 50 {
+        year + 2000
+    } else {
+        year + 1900
+    }
+}
+
+fn rfc2822_zone(parser: &mut Parser<'_>) -> Result<i32, ParseIsoError> {
+    let start = parser.position();
+    if let Some(negative) = parser.sign() {
+        let (offset, _) = parser.digits(4, 4, "invalid offset")?;
+        let (hours, minutes) = (offset / 100, offset % 100);
+        if minutes >= MINUTES_IN_HOUR || hours > 18 || (hours == 18 && minutes > 0) {
+            return Err(parser.error_at(start, "offset out of range"));
+        }
+        let seconds = (hours * SECONDS_IN_HOUR + minutes * SECONDS_IN_MINUTE) as i32;
+        return Ok(if negative { -seconds } else { seconds });
+    }
+    let name = parser.letters();
+    if let Some(&(_, hours)) = ZONE_NAMES
+        .iter()
+        .find(|(zone, _)| zone.as_bytes().eq_ignore_ascii_case(name))
+    {
+        Ok(hours * SECONDS_IN_HOUR as i32)
+    } else if name.len() == 1 && !name.eq_ignore_ascii_case(b"J") {
+        Ok(0)
+    } else {
+        Err(parser.error_at(start, "invalid zone"))
+    }
+}
+
+fn date(
+    parser: &Parser<'_>,
+    day_start: usize,
+    year: i64,
+    month: u32,
+    day: i64,
+) -> Result<LocalDate, ParseIsoError> {
+    if day < 1 || day > days_in_month(year, month) as i64 {
+        return Err(parser.error_at(day_start, "day of month out of range"));
+    }
+    Ok(LocalDate::of(year, month, day as u32))
+}
+
+fn date_time(
+    parser: &Parser<'_>,
+    date: LocalDate,
+    time_start: usize,
+    (hour, minute, second): (i64, i64, i64),
+    offset_seconds: i32,
+) -> Result<OffsetDateTime, ParseIsoError> {
+    if hour >= HOURS_IN_DAY || minute >= MINUTES_IN_HOUR || second > SECONDS_IN_MINUTE {
+        return Err(parser.error_at(time_start, "time out of range"));
+    }
+    OffsetDateTime::of_checked(
+        date,
+        hour as u32,
+        minute as u32,
+        second as u32,
+        0,
+        offset_seconds,
+    )
+    .ok_or_else(|| parser.error_at(time_start, "leap second must end a UTC day"))
+}
@@ -0,0 +1,214 @@
+use crate::{LocalDate, OffsetDateTime, TaiUtcTable};
+
+fn date_time(
+    (year, month, day): (i64, u32, u32),
+    (hour, minute, second): (u32, u32, u32),
+    offset_seconds: i32,
+) -> OffsetDateTime {
+    OffsetDateTime::of(
+        LocalDate::of(year, month, day),
+        hour,
+        minute,
+        second,
+        0,
+        offset_seconds,
+    )
+}
+
+#[test]
+fn parse_rfc2822() {
+    assert_eq!(
+        Ok(date_time((2003, 7, 1), (10, 52, 37), 7_200)),
+        OffsetDateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200")
+    );
+    assert_eq!(
+        Ok(date_time((1997, 11, 21), (9, 55, 6), -6 * 3_600)),
+        OffsetDateTime::parse_rfc2822("Fri, 21 Nov 1997 09:55:06 -0600")
+    );
+    assert_eq!(
+        Ok(date_time((1969, 2, 13), (23, 32, 0), -1_800)),
+        OffsetDateTime::parse_rfc2822(
+            "Thu,\r\n 13\r\n Feb\r\n 1969\r\n 23:32\r\n -0030 (Newfoundland Time)"
+        )
+    );
+    assert_eq!(
+        Ok(date_time((1997, 11, 21), (9, 55, 6), 0)),
+        OffsetDateTime::parse_rfc2822("21 Nov 97 09:55:06 GMT")
+    );
+    assert_eq!(
+        Ok(date_time((1997, 11, 21), (9, 55, 6), -5 * 3_600)),
+        OffsetDateTime::parse_rfc2822(
+            "Fri, 21 Nov 1997 09 :   55  :  06 EST (Eastern (Standard) Time)"
+        )
+    );
+    assert_eq!(
+        Ok(date_time((2001, 1, 1), (0, 0, 0), -7 * 3_600)),
+        OffsetDateTime::parse_rfc2822("1 jan 101 00:00 pdt")
+    );
+    assert_eq!(
+        Ok(date_time((2049, 1, 1), (0, 0, 0), 0)),
+        OffsetDateTime::parse_rfc2822("1 Jan 49 00:00 Z")
+    );
+    assert_eq!(
+        Ok(date_time((1950, 1, 1), (0, 0, 0), 0)),
+        OffsetDateTime::parse_rfc2822("1 Jan 50 00:00 A")
+    );
+}
+
+#[test]
+fn parse_rfc2822_invalid() {
+    for &(text, position, reason) in &[
+        ("Tue, 1 Jul 2003 10:52:37", 24, "invalid zone"),
+        (
+            "Wed, 1 Jul 2003 10:52:37 +0200",
+            0,
+            "day of week does not match date",
+        ),
+        ("Tue 1 Jul 2003 10:52:37 +0200", 4, "expected ','"),
+        ("Tus, 1 Jul 2003 10:52:37 +0200", 0, "invalid day of week"),
+        ("31 Jun 2003 10:52:37 +0200", 0, "day of month out of range"),
+        ("1 Jux 2003 10:52:37 +0200", 2, "invalid month"),
+        ("1 Jul 200310:52:37 +0200", 12, "expected whitespace"),
+        ("1 Jul 2003 24:00:00 +0200", 11, "time out of range"),
+        ("1 Jul 2003 10:52:37 J", 20, "invalid zone"),
+        ("1 Jul 2003 10:52:37 +1900", 20, "offset out of range"),
+        ("1 Jul 2003 10:52:37 +0260", 20, "offset out of range"),
+        (
+            "1 Jul 2003 10:52:37 +0200 (comment",
+            34,
+            "unterminated comment",
+        ),
+        (
+            "1 Jul 2003 23:59:60 +0200",
+            11,
+            "leap second must end a UTC day",
+        ),
+        (
+            "1 Jul 2003 10:52:37 +0200 x",
+            26,
+            "unexpected trailing text",
+        ),
+    ] {
+        let error = OffsetDateTime::parse_rfc2822(text).unwrap_err();
+        assert_eq!(
+            (position, reason),
+            (error.position(), error.reason()),
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn leap_seconds() {
+    let table = TaiUtcTable::default();
+    let leap_second = date_time((2017, 1, 1), (1, 59, 60), 7_200);
+
+    assert_eq!(
+        Ok(leap_second),
+        OffsetDateTime::parse_rfc2822("Sun, 1 Jan 2017 01:59:60 +0200")
+    );
+    assert_eq!(
+        Ok(leap_second.with_offset_seconds(0)),
+        OffsetDateTime::parse_http_date("Sat, 31 Dec 2016 23:59:60 GMT")
+    );
+    assert!(leap_second.to_instant(&table).is_some());
+    assert_eq!(
+        None,
+        OffsetDateTime::parse_http_date("Fri, 30 Dec 2016 23:59:60 GMT")
+            .unwrap()
+            .to_instant(&table)
+    );
+    assert_eq!(
+        Some("Sat, 31 Dec 2016 23:59:60 GMT".to_string()),
+        leap_second.to_http_date()
+    );
+    assert_eq!(
+        Some("Sun, 1 Jan 2017 01:59:60 +0200".to_string()),
+        leap_second.to_rfc2822()
+    );
+}
+
+#[test]
+fn to_rfc2822() {
+    assert_eq!(
+        Some("Tue, 1 Jul 2003 10:52:37 +0200".to_string()),
+        date_time((2003, 7, 1), (10, 52, 37), 7_200).to_rfc2822()
+    );
+    assert_eq!(
+        Some("Thu, 13 Feb 1969 23:32:00 -0330".to_string()),
+        date_time((1969, 2, 13), (23, 32, 0), -12_600).to_rfc2822()
+    );
+    assert_eq!(None, date_time((1899, 12, 31), (0, 0, 0), 0).to_rfc2822());
+    assert_eq!(None, date_time((2003, 7, 1), (0, 0, 0), 1).to_rfc2822());
+}
+
+#[test]
+fn parse_http_date() {
+    let expected = Ok(date_time((1994, 11, 6), (8, 49, 37), 0));
+
+    assert_eq!(
+        expected,
+        OffsetDateTime::parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT")
+    );
+    assert_eq!(
+        expected,
+        OffsetDateTime::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT")
+    );
+    assert_eq!(
+        expected,
+        OffsetDateTime::parse_http_date("Sun Nov  6 08:49:37 1994")
+    );
+    assert_eq!(
+        Ok(date_time((2021, 11, 16), (8, 49, 37), 0)),
+        OffsetDateTime::parse_http_date("Tue Nov 16 08:49:37 2021")
+    );
+}
+
+#[test]
+fn parse_http_date_invalid() {
+    for &(text, position, reason) in &[
+        ("Sun, 06 Nov 1994 08:49:37 +0000", 26, "expected 'GMT'"),
+        ("Sunday, 06 Nov 1994 08:49:37 GMT", 0, "invalid day of week"),
+        ("Sun, 06-Nov-94 08:49:37 GMT", 0, "invalid day of week"),
+        (
+            "Mon, 06 Nov 1994 08:49:37 GMT",
+            0,
+            "day of week does not match date",
+        ),
+        ("Sun, 6 Nov 1994 08:49:37 GMT", 5, "invalid day of month"),
+        ("Sun, 06 Nov 94 08:49:37 GMT", 12, "invalid year"),
+        ("Sun, 06 Nov 1994 08:49 GMT", 22, "expected ':'"),
+        (
+            "Sun Nov  6 08:49:37 1994 GMT",
+            24,
+            "unexpected trailing text",
+        ),
+        (
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            5,
+            "day of month out of range",
+        ),
+    ] {
+        let error = OffsetDateTime::parse_http_date(text).unwrap_err();
+        assert_eq!(
+            (position, reason),
+            (error.position(), error.reason()),
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn to_http_date() {
+    assert_eq!(
+        Some("Sun, 06 Nov 1994 08:49:37 GMT".to_string()),
+        date_time((1994, 11, 6), (9, 49, 37), 3_600).to_http_date()
+    );
+    assert_eq!(
+        Some("Sat, 01 Jan 0000 00:00:00 GMT".to_string()),
+        date_time((0, 1, 1), (0, 0, 0), 0).to_http_date()
+    );
+    assert_eq!(None, date_time((10_000, 1, 1), (0, 0, 0), 0).to_http_date());
+}
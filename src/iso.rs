@@ -0,0 +1,325 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::calendar::*;
+use crate::constants::*;
+
+// Shared pieces of the ISO-8601 text formats used by `Display` and `FromStr`,
+// following the java.time `toString()` and `parse()` conventions.
+
+const MAX_YEAR_DIGITS: usize = 12;
+const NANO_DIGITS: usize = 9;
+
+/// An error parsing ISO-8601 text, such as an instant, date or duration.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseIsoError {
+    position: usize,
+    reason: &'static str,
+}
+
+/// The time scale an instant is labelled with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scale {
+    /// Suffixed with ' TAI'.
+    Tai,
+    /// Suffixed with 'Z'.
+    Utc,
+}
+
+impl ParseIsoError {
+    /// Gets the (byte) position in the text the error was found at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets a short description of what was wrong.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseIsoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid ISO-8601 text at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl Error for ParseIsoError {}
+
+pub struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn of(text: &'a str) -> Parser<'a> {
+        Parser {
+            text: text.as_bytes(),
+            position: 0,
+        }
+    }
+
+    pub fn error(&self, reason: &'static str) -> ParseIsoError {
+        ParseIsoError {
+            position: self.position,
+            reason,
+        }
+    }
+
+    pub fn error_at(&self, position: usize, reason: &'static str) -> ParseIsoError {
+        ParseIsoError { position, reason }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    /// Consumes the given (ASCII, case-insensitive) character, if it is next.
+    pub fn eat(&mut self, expected: u8) -> bool {
+        match self.peek() {
+            Some(c) if c.eq_ignore_ascii_case(&expected) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn expect(&mut self, expected: u8, reason: &'static str) -> Result<(), ParseIsoError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Consumes a sign, returning whether it was negative.
+    pub fn sign(&mut self) -> Option<bool> {
+        if self.eat(b'-') {
+            Some(true)
+        } else if self.eat(b'+') {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Consumes between `min` and `max` digits, returning their value and how many there were.
+    pub fn digits(
+        &mut self,
+        min: usize,
+        max: usize,
+        reason: &'static str,
+    ) -> Result<(i64, usize), ParseIsoError> {
+        let start = self.position;
+        let mut value: i64 = 0;
+        while self.position - start < max {
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((c - b'0') as i64))
+                        .ok_or_else(|| self.error_at(start, reason))?;
+                    self.position += 1;
+                }
+                _ => break,
+            }
+        }
+        let count = self.position - start;
+        if count < min {
+            Err(self.error_at(start, reason))
+        } else {
+            Ok((value, count))
+        }
+    }
+
+    /// Consumes a fraction of a second (after the separator), as nanoseconds.
+    pub fn fraction(&mut self) -> Result<u32, ParseIsoError> {
+        let (value, count) = self.digits(1, NANO_DIGITS, "invalid fraction of second")?;
+        Ok((value * 10i64.pow((NANO_DIGITS - count) as u32)) as u32)
+    }
+
+    /// Consumes any next character.
+    pub fn next(&mut self) -> Option<u8> {
+        let next = self.peek();
+        if next.is_some() {
+            self.position += 1;
+        }
+        next
+    }
+
+    /// Consumes a run of ASCII letters.
+    pub fn letters(&mut self) -> &'a [u8] {
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            self.position += 1;
+        }
+        &self.text[start..self.position]
+    }
+
+    pub fn end(&self) -> Result<(), ParseIsoError> {
+        if self.position == self.text.len() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing text"))
+        }
+    }
+
+    /// Parses an ISO local date, such as '2007-12-03', returning the year, month and day.
+    ///
+    /// Years with more than four digits must be signed.
+    pub fn date(&mut self) -> Result<(i64, u32, u32), ParseIsoError> {
+        let start = self.position;
+        let negative = self.sign();
+        let (year, digits) = self.digits(4, MAX_YEAR_DIGITS, "invalid year")?;
+        if digits > 4 && negative.is_none() {
+            return Err(self.error_at(start, "years beyond 9999 must be signed"));
+        }
+        let year = if negative == Some(true) { -year } else { year };
+        self.expect(b'-', "expected '-'")?;
+
+        let month_start = self.position;
+        let (month, _) = self.digits(2, 2, "invalid month")?;
+        if !(1..=MONTHS_IN_YEAR).contains(&month) {
+            return Err(self.error_at(month_start, "month out of range"));
+        }
+        self.expect(b'-', "expected '-'")?;
+
+        let day_start = self.position;
+        let (day, _) = self.digits(2, 2, "invalid day of month")?;
+        if day < 1 || day > days_in_month(year, month as u32) as i64 {
+            return Err(self.error_at(day_start, "day of month out of range"));
+        }
+        Ok((year, month as u32, day as u32))
+    }
+
+    /// Parses an ISO local time, such as '10:15:30.5', returning the second of day and nano of second.
+    ///
+    /// The seconds are optional.
+    /// If allowed, a leap second (':60') results in a second of day past the end of the day.
+    pub fn time(&mut self, allow_leap_second: bool) -> Result<(i64, u32), ParseIsoError> {
+        let hour_start = self.position;
+        let (hour, _) = self.digits(2, 2, "invalid hour")?;
+        if hour >= HOURS_IN_DAY {
+            return Err(self.error_at(hour_start, "hour out of range"));
+        }
+        self.expect(b':', "expected ':'")?;
+
+        let minute_start = self.position;
+        let (minute, _) = self.digits(2, 2, "invalid minute")?;
+        if minute >= MINUTES_IN_HOUR {
+            return Err(self.error_at(minute_start, "minute out of range"));
+        }
+
+        let (mut second, mut nano) = (0, 0);
+        if self.eat(b':') {
+            let second_start = self.position;
+            second = self.digits(2, 2, "invalid second")?.0;
+            let leap_second = allow_leap_second
+                && second == SECONDS_IN_MINUTE
+                && (hour, minute) == (HOURS_IN_DAY - 1, MINUTES_IN_HOUR - 1);
+            if second >= SECONDS_IN_MINUTE && !leap_second {
+                return Err(self.error_at(second_start, "second out of range"));
+            }
+            if self.eat(b'.') || self.eat(b',') {
+                nano = self.fraction()?;
+            }
+        }
+        Ok((
+            (hour * MINUTES_IN_HOUR + minute) * SECONDS_IN_MINUTE + second,
+            nano,
+        ))
+    }
+
+    /// Parses an ISO local date-time, such as '2007-12-03T10:15:30',
+    /// returning the epoch day, the second of day and the nano of second.
+    pub fn date_time(&mut self, allow_leap_second: bool) -> Result<(i64, i64, u32), ParseIsoError> {
+        let (year, month, day) = self.date()?;
+        self.expect(b'T', "expected 'T'")?;
+        let (second_of_day, nano) = self.time(allow_leap_second)?;
+        Ok((epoch_day_of(year, month, day), second_of_day, nano))
+    }
+
+    /// Parses the label of the time scale following a date-time, either ' TAI' or 'Z'.
+    pub fn scale(&mut self) -> Result<Scale, ParseIsoError> {
+        let start = self.position;
+        if self.eat(b'Z') {
+            Ok(Scale::Utc)
+        } else if self.eat(b' ') && self.eat(b'T') && self.eat(b'A') && self.eat(b'I') {
+            Ok(Scale::Tai)
+        } else {
+            Err(self.error_at(start, "expected ' TAI' or 'Z'"))
+        }
+    }
+}
+
+pub fn format_date(f: &mut fmt::Formatter<'_>, year: i64, month: u32, day: u32) -> fmt::Result {
+    if year > 9999 {
+        write!(f, "+")?;
+    } else if year < 0 {
+        write!(f, "-")?;
+    }
+    write!(f, "{:04}-{:02}-{:02}", year.unsigned_abs(), month, day)
+}
+
+/// Formats a time of day, with the fraction as for `format_fraction`.
+///
+/// A second of day past the end of the day is formatted as a leap second.
+pub fn format_time(f: &mut fmt::Formatter<'_>, second_of_day: i64, nano: u32) -> fmt::Result {
+    let (hour, minute, second) = if second_of_day >= SECONDS_IN_DAY {
+        (
+            HOURS_IN_DAY - 1,
+            MINUTES_IN_HOUR - 1,
+            SECONDS_IN_MINUTE + second_of_day - SECONDS_IN_DAY,
+        )
+    } else {
+        (
+            second_of_day / SECONDS_IN_HOUR,
+            second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR,
+            second_of_day % SECONDS_IN_MINUTE,
+        )
+    };
+    write!(f, "{:02}:{:02}:{:02}", hour, minute, second)?;
+    format_fraction(f, nano)
+}
+
+/// Formats a fraction of a second, omitting zero and otherwise using as many groups of three digits as needed.
+pub fn format_fraction(f: &mut fmt::Formatter<'_>, nano: u32) -> fmt::Result {
+    if nano == 0 {
+        Ok(())
+    } else if nano as i64 % NANOSECONDS_IN_MILLISECOND == 0 {
+        write!(f, ".{:03}", nano as i64 / NANOSECONDS_IN_MILLISECOND)
+    } else if nano as i64 % NANOSECONDS_IN_MICROSECOND == 0 {
+        write!(f, ".{:06}", nano as i64 / NANOSECONDS_IN_MICROSECOND)
+    } else {
+        write!(f, ".{:09}", nano)
+    }
+}
+
+/// Formats an offset from UTC, such as '+01:00', or 'Z' if zero.
+pub fn format_offset(f: &mut fmt::Formatter<'_>, offset_seconds: i32) -> fmt::Result {
+    if offset_seconds == 0 {
+        return write!(f, "Z");
+    }
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let seconds = offset_seconds.unsigned_abs() as i64;
+    write!(
+        f,
+        "{}{:02}:{:02}",
+        sign,
+        seconds / SECONDS_IN_HOUR,
+        seconds / SECONDS_IN_MINUTE % MINUTES_IN_HOUR
+    )?;
+    if seconds % SECONDS_IN_MINUTE != 0 {
+        write!(f, ":{:02}", seconds % SECONDS_IN_MINUTE)?;
+    }
+    Ok(())
+}
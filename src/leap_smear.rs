@@ -0,0 +1,213 @@
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::{DateTimeError, Duration, Instant, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod conversions;
+
+const NANOSECONDS_IN_DAY_WIDE: i128 = NANOSECONDS_IN_DAY as i128;
+
+/// A strategy for spreading leap seconds out over a window of time, instead of inserting (or removing) a whole second.
+///
+/// A smeared clock never reads '23:59:60': every day is 86,400 (smeared) seconds long,
+/// with the seconds within the window around a leap second slightly longer (or shorter) than an SI second.
+/// Smeared times are represented as a [`UtcInstant`] whose nano of day is always less than a day.
+///
+/// The window is given relative to the end of the day with the leap second;
+/// the provided conversions smear linearly over the whole window.
+/// Windows around different leap seconds are assumed not to overlap.
+///
+/// [`UtcInstant`]: struct.UtcInstant.html
+pub trait LeapSmear {
+    /// Gets how long before the end of a day with a leap second the smear starts.
+    fn before(&self) -> Duration;
+
+    /// Gets how long after the end of a day with a leap second the smear ends.
+    fn after(&self) -> Duration;
+
+    /// Converts a smeared UTC reading to an instant.
+    ///
+    /// # Parameters
+    ///  - `table`: the table the leap seconds are taken from.
+    ///  - `smeared`: the reading of the smeared clock.
+    ///
+    /// # Returns
+    /// The instant, or `None` if UTC is not defined at that reading,
+    /// the nano of day is not less than a day, or the result would overflow the instant.
+    fn to_instant(&self, table: &TaiUtcTable, smeared: UtcInstant) -> Option<Instant> {
+        if smeared.nano_of_day() >= NANOSECONDS_IN_DAY {
+            return None;
+        }
+        let smeared_nanos = smeared.modified_julian_day() as i128 * NANOSECONDS_IN_DAY_WIDE
+            + smeared.nano_of_day() as i128;
+
+        match windows(table, self.before(), self.after())
+            .find(|window| window.start <= smeared_nanos && smeared_nanos < window.end)
+        {
+            None => table.to_instant(smeared),
+            Some(window) => {
+                let elapsed = (smeared_nanos - window.start) * (window.end_tai - window.start_tai)
+                    / (window.end - window.start);
+                of_total_nanos_checked(window.start_tai + elapsed).map(|(seconds, nanos)| {
+                    Instant::of_epoch_second_and_adjustment(seconds, nanos as i64)
+                })
+            }
+        }
+    }
+
+    /// Converts an instant to the reading of the smeared UTC clock.
+    ///
+    /// # Parameters
+    ///  - `table`: the table the leap seconds are taken from.
+    ///  - `instant`: the instant to convert.
+    ///
+    /// # Returns
+    /// The smeared reading, or `None` if UTC is not defined at that instant.
+    fn to_smeared_utc(&self, table: &TaiUtcTable, instant: Instant) -> Option<UtcInstant> {
+        let tai_nanos = total_nanos(instant.epoch_second(), instant.nano());
+
+        match windows(table, self.before(), self.after())
+            .find(|window| window.start_tai <= tai_nanos && tai_nanos < window.end_tai)
+        {
+            None => table.to_utc(instant),
+            Some(window) => {
+                let smeared_nanos = window.start
+                    + (tai_nanos - window.start_tai) * (window.end - window.start)
+                        / (window.end_tai - window.start_tai);
+                Some(utc_of_nanos(smeared_nanos))
+            }
+        }
+    }
+}
+
+/// The 24 hour, noon-to-noon linear smear used by Google and AWS.
+///
+/// The smear starts at noon UTC before the leap second, and ends at noon UTC after it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NoonToNoonSmear;
+
+/// UTC with Smoothed Leap Seconds (UTC-SLS), which smears over the last 1000 seconds before the leap second ends.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct UtcSls;
+
+/// A linear smear over a configurable window around the end of the day with a leap second.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LinearSmear {
+    before: Duration,
+    after: Duration,
+}
+
+impl LinearSmear {
+    /// Obtains a linear smear over the given window.
+    ///
+    /// # Parameters
+    ///  - `before`: how long before the end of the day the smear starts, between one second and one day.
+    ///  - `after`: how long after the end of the day the smear ends, up to one day.
+    ///
+    /// # Panics
+    /// - if either part of the window is out of range.
+    pub fn of(before: Duration, after: Duration) -> LinearSmear {
+        LinearSmear::of_checked(before, after).expect("smear window out of range")
+    }
+
+    /// Obtains a linear smear over the given window, or an error if either part of the window is out of range.
+    ///
+    /// # Parameters
+    ///  - `before`: how long before the end of the day the smear starts, between one second and one day.
+    ///  - `after`: how long after the end of the day the smear ends, up to one day.
+    pub fn try_of(before: Duration, after: Duration) -> Result<LinearSmear, DateTimeError> {
+        LinearSmear::of_checked(before, after)
+            .ok_or(DateTimeError::InvalidArgument("smear window out of range"))
+    }
+
+    fn of_checked(before: Duration, after: Duration) -> Option<LinearSmear> {
+        if before < Duration::of_seconds(1)
+            || before > Duration::of_days(1)
+            || after < Duration::ZERO
+            || after > Duration::of_days(1)
+        {
+            return None;
+        }
+        Some(LinearSmear { before, after })
+    }
+}
+
+impl LeapSmear for NoonToNoonSmear {
+    fn before(&self) -> Duration {
+        Duration::of_hours(HOURS_IN_DAY / 2)
+    }
+
+    fn after(&self) -> Duration {
+        Duration::of_hours(HOURS_IN_DAY / 2)
+    }
+}
+
+impl LeapSmear for UtcSls {
+    fn before(&self) -> Duration {
+        Duration::of_seconds(1000)
+    }
+
+    fn after(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+impl LeapSmear for LinearSmear {
+    fn before(&self) -> Duration {
+        self.before
+    }
+
+    fn after(&self) -> Duration {
+        self.after
+    }
+}
+
+// A smear window, as nominal UTC nanoseconds since MJD 0, and instant nanoseconds since the epoch.
+struct Window {
+    start: i128,
+    end: i128,
+    start_tai: i128,
+    end_tai: i128,
+}
+
+fn windows(
+    table: &TaiUtcTable,
+    before: Duration,
+    after: Duration,
+) -> impl Iterator<Item = Window> + '_ {
+    let before = total_nanos(before.seconds(), before.nano());
+    let after = total_nanos(after.seconds(), after.nano());
+
+    table.entries().iter().skip(1).filter_map(move |entry| {
+        // Only whole leap seconds are smeared, not the fractional steps and drift of UTC before 1972.
+        let leap_day = entry.modified_julian_day() - 1;
+        let length = table.day_length(leap_day)?;
+        if length != Duration::of_seconds(SECONDS_IN_DAY + 1)
+            && length != Duration::of_seconds(SECONDS_IN_DAY - 1)
+        {
+            return None;
+        }
+
+        let end_of_day = entry.modified_julian_day() as i128 * NANOSECONDS_IN_DAY_WIDE;
+        let (start, end) = (end_of_day - before, end_of_day + after);
+        let tai_of = |utc_nanos| {
+            table
+                .to_instant(utc_of_nanos(utc_nanos))
+                .map(|instant| total_nanos(instant.epoch_second(), instant.nano()))
+        };
+
+        Some(Window {
+            start,
+            end,
+            start_tai: tai_of(start)?,
+            end_tai: tai_of(end)?,
+        })
+    })
+}
+
+fn utc_of_nanos(utc_nanos: i128) -> UtcInstant {
+    UtcInstant::of_modified_julian_day(
+        utc_nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE) as i64,
+        utc_nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64,
+    )
+}
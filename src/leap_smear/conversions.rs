@@ -0,0 +1,168 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{
+    Duration, Instant, LeapSmear, LinearSmear, NoonToNoonSmear, TaiUtcTable, UtcInstant, UtcSls,
+};
+
+const LAST_LEAP_SECOND_DAY: i64 = 57_753;
+
+fn seconds_since_epoch(modified_julian_day: i64) -> i64 {
+    (modified_julian_day - MODIFIED_JULIAN_DAY_OF_EPOCH) * SECONDS_IN_DAY
+}
+
+fn nanos_between(a: Instant, b: Instant) -> i64 {
+    (b.epoch_second() - a.epoch_second()) * NANOSECONDS_IN_SECOND + b.nano() as i64
+        - a.nano() as i64
+}
+
+#[test]
+fn outside_window_matches_table() {
+    let table = TaiUtcTable::default();
+    let utc = UtcInstant::of_modified_julian_day(LAST_LEAP_SECOND_DAY, 0);
+    let instant = table.to_instant(utc).unwrap();
+
+    assert_eq!(Some(instant), NoonToNoonSmear.to_instant(&table, utc));
+    assert_eq!(Some(utc), NoonToNoonSmear.to_smeared_utc(&table, instant));
+}
+
+#[test]
+fn steps_before_1972_are_not_smeared() {
+    let table = TaiUtcTable::default();
+    // The step of -0.05 seconds at the start of '1961-08-01', and of about 0.1 seconds at the start of '1972-01-01'.
+    for &day in &[37_512, 41_317] {
+        for &nano_of_day in &[
+            0,
+            NANOSECONDS_IN_DAY / 2,
+            NANOSECONDS_IN_DAY - NANOSECONDS_IN_SECOND,
+        ] {
+            for &utc in &[
+                UtcInstant::of_modified_julian_day(day, nano_of_day),
+                UtcInstant::of_modified_julian_day(day - 1, nano_of_day),
+            ] {
+                let instant = table.to_instant(utc).unwrap();
+
+                assert_eq!(Some(instant), NoonToNoonSmear.to_instant(&table, utc));
+                assert_eq!(Some(utc), NoonToNoonSmear.to_smeared_utc(&table, instant));
+            }
+        }
+    }
+}
+
+#[test]
+fn noon_to_noon_midnight_is_halfway() {
+    let table = TaiUtcTable::default();
+    let midnight = UtcInstant::of_modified_julian_day(LAST_LEAP_SECOND_DAY + 1, 0);
+
+    let instant = NoonToNoonSmear.to_instant(&table, midnight);
+
+    assert_eq!(
+        Some(Instant::of_epoch_second_and_adjustment(
+            seconds_since_epoch(LAST_LEAP_SECOND_DAY + 1) + 36,
+            500_000_000
+        )),
+        instant
+    );
+}
+
+#[test]
+fn utc_sls_halfway() {
+    let table = TaiUtcTable::default();
+    let smeared = UtcInstant::of_modified_julian_day(
+        LAST_LEAP_SECOND_DAY,
+        (SECONDS_IN_DAY - 500) * NANOSECONDS_IN_SECOND,
+    );
+
+    let instant = UtcSls.to_instant(&table, smeared).unwrap();
+
+    let start = table
+        .to_instant(UtcInstant::of_modified_julian_day(
+            LAST_LEAP_SECOND_DAY,
+            (SECONDS_IN_DAY - 1000) * NANOSECONDS_IN_SECOND,
+        ))
+        .unwrap();
+    assert_eq!(500_500_000_000, nanos_between(start, instant));
+}
+
+#[test]
+fn leap_second_is_smeared() {
+    let table = TaiUtcTable::default();
+    let leap_second = table
+        .to_instant(UtcInstant::of_modified_julian_day(
+            LAST_LEAP_SECOND_DAY,
+            NANOSECONDS_IN_DAY,
+        ))
+        .unwrap();
+
+    let smeared = UtcSls.to_smeared_utc(&table, leap_second).unwrap();
+
+    assert_eq!(LAST_LEAP_SECOND_DAY, smeared.modified_julian_day());
+    assert!(smeared.nano_of_day() < NANOSECONDS_IN_DAY);
+    assert!(smeared.nano_of_day() > (SECONDS_IN_DAY - 1) * NANOSECONDS_IN_SECOND);
+}
+
+#[test]
+fn to_instant_rejects_leap_second_reading() {
+    let table = TaiUtcTable::default();
+    let reading = UtcInstant::of_modified_julian_day(LAST_LEAP_SECOND_DAY, NANOSECONDS_IN_DAY);
+
+    assert_eq!(None, UtcSls.to_instant(&table, reading));
+}
+
+#[test]
+fn linear_smear_window() {
+    let smear = LinearSmear::of(Duration::of_hours(2), Duration::of_minutes(30));
+
+    assert_eq!(Duration::of_hours(2), smear.before());
+    assert_eq!(Duration::of_minutes(30), smear.after());
+}
+
+#[test]
+fn linear_smear_out_of_range() {
+    expect_panic("smear window out of range", || {
+        LinearSmear::of(Duration::ZERO, Duration::ZERO)
+    })
+    .unwrap();
+    expect_panic("smear window out of range", || {
+        LinearSmear::of(Duration::of_days(2), Duration::ZERO)
+    })
+    .unwrap();
+    expect_panic("smear window out of range", || {
+        LinearSmear::of(Duration::of_seconds(1), Duration::of_seconds(-1))
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn smeared_round_trip(nanos in -NANOSECONDS_IN_DAY..NANOSECONDS_IN_DAY) {
+        let table = TaiUtcTable::default();
+        let nominal = (LAST_LEAP_SECOND_DAY + 1) * NANOSECONDS_IN_DAY + nanos;
+        let smeared = UtcInstant::of_modified_julian_day(nominal.div_euclid(NANOSECONDS_IN_DAY), nominal.rem_euclid(NANOSECONDS_IN_DAY));
+
+        let instant = NoonToNoonSmear.to_instant(&table, smeared).unwrap();
+        let round_trip = NoonToNoonSmear.to_smeared_utc(&table, instant).unwrap();
+
+        let difference = (smeared.modified_julian_day() - round_trip.modified_julian_day()) * NANOSECONDS_IN_DAY
+            + smeared.nano_of_day() - round_trip.nano_of_day();
+        prop_assert!((0..=1).contains(&difference), "difference of {} nanoseconds", difference);
+    }
+}
+
+proptest! {
+    #[test]
+    fn smeared_is_monotonic(seconds in -SECONDS_IN_DAY..SECONDS_IN_DAY, nanos in 0..NANOSECONDS_IN_SECOND, step in 1..NANOSECONDS_IN_SECOND) {
+        let table = TaiUtcTable::default();
+        let base = seconds_since_epoch(LAST_LEAP_SECOND_DAY + 1) + 36 + seconds;
+        let earlier = Instant::of_epoch_second_and_adjustment(base, nanos);
+        let later = Instant::of_epoch_second_and_adjustment(base, nanos + step);
+
+        let earlier = UtcSls.to_smeared_utc(&table, earlier).unwrap();
+        let later = UtcSls.to_smeared_utc(&table, later).unwrap();
+
+        prop_assert!(earlier <= later);
+        prop_assert!(later.nano_of_day() < NANOSECONDS_IN_DAY);
+    }
+}
@@ -1,11 +1,67 @@
+mod almanac;
+mod calendar;
+mod ccsds;
 mod constants;
+mod day_of_week;
+mod delta_t;
 mod duration;
+mod error;
+mod formatter;
 mod instant;
+mod internet_date;
+mod iso;
+mod leap_smear;
+mod local_date;
+mod locale;
+mod ntp;
+mod offset_date_time;
+mod period;
+mod period_duration;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+mod ptp;
+mod rounding;
 mod seconds_nanos;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod solar;
+mod tai_utc;
+mod temporal;
+mod temporal_adjuster;
+mod utc;
 mod util;
+mod value_range;
 
-pub use crate::duration::Duration;
+pub use crate::almanac::{AstronomicalEvent, AstronomicalEvents, LunarPhase, SeasonalEvent};
+pub use crate::ccsds::{
+    CcsCalendar, CcsFormat, CdsFormat, CdsResolution, CucFormat, DecodeCcsdsError,
+};
+pub use crate::day_of_week::DayOfWeek;
+pub use crate::delta_t::{DeltaTModel, EspenakMeeus, ModeledUniversalTime, MorrisonStephenson};
+pub use crate::duration::{Duration, HumanUnit, ParseHumanDurationError};
+pub use crate::error::DateTimeError;
+pub use crate::formatter::{
+    DateTimeFormatter, FormatError, ParseDateTimeError, ParsePatternError, Parsed, ResolverStyle,
+};
 pub use crate::instant::Instant;
+pub use crate::internet_date::ParseInternetDateError;
+pub use crate::iso::ParseIsoError;
+pub use crate::leap_smear::{LeapSmear, LinearSmear, NoonToNoonSmear, UtcSls};
+pub use crate::local_date::LocalDate;
+#[cfg(feature = "locales")]
+pub use crate::locale::{FormatStyle, Locale};
+pub use crate::ntp::{NtpShort, NtpTimestamp};
+pub use crate::offset_date_time::OffsetDateTime;
+pub use crate::period::Period;
+pub use crate::period_duration::PeriodDuration;
+pub use crate::ptp::PtpTimestamp;
+pub use crate::rounding::RoundingMode;
+pub use crate::solar::{Daylight, Observer};
+pub use crate::tai_utc::{ParseTaiUtcError, TaiUtcEntry, TaiUtcTable};
+pub use crate::temporal::{ChronoField, ChronoUnit, Temporal, TemporalAccessor};
+pub use crate::temporal_adjuster::{TemporalAdjuster, TemporalAdjusters};
+pub use crate::utc::UtcInstant;
+pub use crate::value_range::ValueRange;
 
 #[cfg(test)]
 pub mod assert;
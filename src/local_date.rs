@@ -0,0 +1,374 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::calendar::*;
+use crate::constants::*;
+use crate::iso::*;
+use crate::util::const_expect;
+use crate::{ChronoField, DateTimeError, DayOfWeek, Period, TemporalAdjuster};
+
+#[cfg(test)]
+pub mod factories;
+#[cfg(test)]
+pub mod text;
+
+/// A date without a time-zone in the ISO-8601 calendar system, such as '2007-12-03'.
+///
+/// The ISO-8601 calendar system is the proleptic Gregorian calendar, applied to all dates, even before its introduction.
+/// Years are numbered astronomically, so the year before year 1 is year 0.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LocalDate {
+    year: i64,
+    month: u8,
+    day: u8,
+}
+
+impl LocalDate {
+    /// Constant for the earliest supported date, '-999999999-01-01'.
+    pub const MIN: LocalDate = LocalDate {
+        year: MIN_YEAR,
+        month: 1,
+        day: 1,
+    };
+
+    /// Constant for the epoch date, '1970-01-01'.
+    pub const EPOCH: LocalDate = LocalDate {
+        year: 1970,
+        month: 1,
+        day: 1,
+    };
+
+    /// Constant for the latest supported date, '+999999999-12-31'.
+    pub const MAX: LocalDate = LocalDate {
+        year: MAX_YEAR,
+        month: 12,
+        day: 31,
+    };
+
+    /// Obtains a `LocalDate` from a year, month and day.
+    ///
+    /// # Parameters
+    ///  - `year`: the year, from `-999_999_999` to `999_999_999`.
+    ///  - `month`: the month of the year, from 1 (January) to 12 (December).
+    ///  - `day`: the day of the month, from 1 to 31.
+    ///
+    /// # Panics
+    /// - if any field is out of range, or the day does not exist in that month.
+    pub const fn of(year: i64, month: u32, day: u32) -> LocalDate {
+        const_expect!(LocalDate::of_checked(year, month, day), "date out of range")
+    }
+
+    pub(crate) const fn of_checked(year: i64, month: u32, day: u32) -> Option<LocalDate> {
+        if year < MIN_YEAR
+            || year > MAX_YEAR
+            || month < 1
+            || month > MONTHS_IN_YEAR as u32
+            || day < 1
+            || day > days_in_month(year, month)
+        {
+            None
+        } else {
+            Some(LocalDate {
+                year,
+                month: month as u8,
+                day: day as u8,
+            })
+        }
+    }
+
+    /// Obtains a `LocalDate` from a year, month and day, or an error naming the first field out of range.
+    ///
+    /// # Parameters
+    ///  - `year`: the year, from `-999_999_999` to `999_999_999`.
+    ///  - `month`: the month of the year, from 1 (January) to 12 (December).
+    ///  - `day`: the day of the month, from 1 to the length of the month.
+    pub fn try_of(year: i64, month: u32, day: u32) -> Result<LocalDate, DateTimeError> {
+        DateTimeError::check(ChronoField::Year, year, MIN_YEAR..=MAX_YEAR)?;
+        DateTimeError::check(ChronoField::MonthOfYear, month as i64, 1..=MONTHS_IN_YEAR)?;
+        let length = days_in_month(year, month) as i64;
+        DateTimeError::check(ChronoField::DayOfMonth, day as i64, 1..=length)?;
+        Ok(LocalDate::of(year, month, day))
+    }
+
+    /// Obtains a `LocalDate` from a year and day of year.
+    ///
+    /// # Parameters
+    ///  - `year`: the year, from `-999_999_999` to `999_999_999`.
+    ///  - `day_of_year`: the day of the year, from 1 to 366.
+    ///
+    /// # Panics
+    /// - if the year is out of range, or the day does not exist in that year.
+    pub const fn of_year_day(year: i64, day_of_year: u32) -> LocalDate {
+        const_expect!(
+            LocalDate::of_year_day_checked(year, day_of_year),
+            "date out of range"
+        )
+    }
+
+    const fn of_year_day_checked(year: i64, day_of_year: u32) -> Option<LocalDate> {
+        if year < MIN_YEAR
+            || year > MAX_YEAR
+            || day_of_year < 1
+            || day_of_year as i64 > days_in_year(year)
+        {
+            None
+        } else {
+            LocalDate::of_epoch_day_checked(epoch_day_of(year, 1, 1) + day_of_year as i64 - 1)
+        }
+    }
+
+    /// Obtains a `LocalDate` from a year and day of year, or an error naming the first field out of range.
+    ///
+    /// # Parameters
+    ///  - `year`: the year, from `-999_999_999` to `999_999_999`.
+    ///  - `day_of_year`: the day of the year, from 1 to the length of the year.
+    pub fn try_of_year_day(year: i64, day_of_year: u32) -> Result<LocalDate, DateTimeError> {
+        DateTimeError::check(ChronoField::Year, year, MIN_YEAR..=MAX_YEAR)?;
+        let length = days_in_year(year);
+        DateTimeError::check(ChronoField::DayOfYear, day_of_year as i64, 1..=length)?;
+        Ok(LocalDate::of_year_day(year, day_of_year))
+    }
+
+    /// Obtains a `LocalDate` from the number of days since '1970-01-01'.
+    ///
+    /// # Parameters
+    ///  - `epoch_day`: the days since the epoch.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    pub const fn of_epoch_day(epoch_day: i64) -> LocalDate {
+        const_expect!(
+            LocalDate::of_epoch_day_checked(epoch_day),
+            "date out of range"
+        )
+    }
+
+    pub(crate) const fn of_epoch_day_checked(epoch_day: i64) -> Option<LocalDate> {
+        if epoch_day < LocalDate::MIN.to_epoch_day() || epoch_day > LocalDate::MAX.to_epoch_day() {
+            None
+        } else {
+            let (year, month, day) = date_of_epoch_day(epoch_day);
+            Some(LocalDate {
+                year,
+                month: month as u8,
+                day: day as u8,
+            })
+        }
+    }
+
+    /// Obtains a `LocalDate` from the number of days since '1970-01-01', or an error if the date would be out of range.
+    ///
+    /// # Parameters
+    ///  - `epoch_day`: the days since the epoch.
+    pub fn try_of_epoch_day(epoch_day: i64) -> Result<LocalDate, DateTimeError> {
+        let range = LocalDate::MIN.to_epoch_day()..=LocalDate::MAX.to_epoch_day();
+        DateTimeError::check(ChronoField::EpochDay, epoch_day, range)?;
+        Ok(LocalDate::of_epoch_day(epoch_day))
+    }
+
+    /// Gets the year.
+    pub const fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Gets the month of the year, from 1 (January) to 12 (December).
+    pub const fn month_value(&self) -> u32 {
+        self.month as u32
+    }
+
+    /// Gets the day of the month, from 1 to 31.
+    pub const fn day_of_month(&self) -> u32 {
+        self.day as u32
+    }
+
+    /// Gets the day of the year, from 1 to 366.
+    pub const fn day_of_year(&self) -> u32 {
+        (self.to_epoch_day() - epoch_day_of(self.year, 1, 1)) as u32 + 1
+    }
+
+    /// Gets the day of the week.
+    pub const fn day_of_week(&self) -> DayOfWeek {
+        DayOfWeek::of_epoch_day(self.to_epoch_day())
+    }
+
+    /// Checks if the year is a leap year, according to the ISO proleptic calendar system rules.
+    pub const fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Gets the number of days in the month of this date, from 28 to 31.
+    pub const fn length_of_month(&self) -> u32 {
+        days_in_month(self.year, self.month as u32)
+    }
+
+    /// Gets the number of days in the year of this date, 365 or 366.
+    pub const fn length_of_year(&self) -> u32 {
+        days_in_year(self.year) as u32
+    }
+
+    /// Gets the number of days since '1970-01-01'.
+    pub const fn to_epoch_day(&self) -> i64 {
+        epoch_day_of(self.year, self.month as u32, self.day as u32)
+    }
+
+    /// Returns a copy of this date with the given number of days added.
+    ///
+    /// # Parameters
+    ///  - `days`: the days to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    pub const fn plus_days(&self, days: i64) -> LocalDate {
+        const_expect!(
+            match self.to_epoch_day().checked_add(days) {
+                None => None,
+                Some(epoch_day) => LocalDate::of_epoch_day_checked(epoch_day),
+            },
+            "date out of range"
+        )
+    }
+
+    /// Returns a copy of this date with the given number of months added.
+    ///
+    /// The day of the month is kept, unless it does not exist in the resulting month,
+    /// when it is clamped to the last valid day, so '2007-03-31' plus one month is '2007-04-30'.
+    ///
+    /// # Parameters
+    ///  - `months`: the months to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    pub const fn plus_months(&self, months: i64) -> LocalDate {
+        const_expect!(self.plus_months_checked(months), "date out of range")
+    }
+
+    pub(crate) const fn plus_months_checked(&self, months: i64) -> Option<LocalDate> {
+        let month_count = self.year as i128 * MONTHS_IN_YEAR as i128 + self.month as i128 - 1;
+        let month_count = month_count + months as i128;
+        let year = month_count.div_euclid(MONTHS_IN_YEAR as i128);
+        let month = month_count.rem_euclid(MONTHS_IN_YEAR as i128) as u32 + 1;
+        if year < MIN_YEAR as i128 || year > MAX_YEAR as i128 {
+            return None;
+        }
+        let length = days_in_month(year as i64, month);
+        let day = if (self.day as u32) < length {
+            self.day as u32
+        } else {
+            length
+        };
+        LocalDate::of_checked(year as i64, month, day)
+    }
+
+    /// Returns a copy of this date with the given number of years added.
+    ///
+    /// The day is clamped as by [`plus_months()`], so '2008-02-29' plus one year is '2009-02-28'.
+    ///
+    /// # Parameters
+    ///  - `years`: the years to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    ///
+    /// [`plus_months()`]: struct.LocalDate.html#method.plus_months
+    pub const fn plus_years(&self, years: i64) -> LocalDate {
+        const_expect!(
+            match years.checked_mul(MONTHS_IN_YEAR) {
+                None => None,
+                Some(months) => self.plus_months_checked(months),
+            },
+            "date out of range"
+        )
+    }
+
+    /// Returns a copy of this date with the given period added.
+    ///
+    /// As in java.time, the years and months are added together with [`plus_months()`] before the days,
+    /// so '2007-01-31' plus 'P1M1D' is '2007-03-01'.
+    ///
+    /// # Parameters
+    ///  - `period`: the period to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    ///
+    /// [`plus_months()`]: struct.LocalDate.html#method.plus_months
+    pub const fn plus_period(&self, period: Period) -> LocalDate {
+        const_expect!(self.plus_period_checked(period), "date out of range")
+    }
+
+    pub(crate) const fn plus_period_checked(&self, period: Period) -> Option<LocalDate> {
+        let date = match self.plus_months_checked(period.to_total_months()) {
+            None => return None,
+            Some(date) => date,
+        };
+        match date.to_epoch_day().checked_add(period.days() as i64) {
+            None => None,
+            Some(epoch_day) => LocalDate::of_epoch_day_checked(epoch_day),
+        }
+    }
+
+    /// Returns a copy of this date with the given period subtracted.
+    ///
+    /// This adds the negated period, so the months are subtracted before the days.
+    ///
+    /// # Parameters
+    ///  - `period`: the period to subtract, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date would be out of range.
+    pub const fn minus_period(&self, period: Period) -> LocalDate {
+        const_expect!(
+            match period.negated_checked() {
+                None => None,
+                Some(negated) => self.plus_period_checked(negated),
+            },
+            "date out of range"
+        )
+    }
+
+    /// Returns a copy of this date adjusted by an adjuster, such as to the last day of the month.
+    ///
+    /// # Parameters
+    ///  - `adjuster`: the adjuster to apply, such as one from [`TemporalAdjusters`], or a closure.
+    ///
+    /// # Returns
+    /// The adjusted date, or an error if it would be out of range.
+    ///
+    /// [`TemporalAdjusters`]: enum.TemporalAdjusters.html
+    pub fn with_adjuster(
+        &self,
+        adjuster: impl TemporalAdjuster,
+    ) -> Result<LocalDate, DateTimeError> {
+        adjuster.adjust_into(*self)
+    }
+}
+
+impl Default for LocalDate {
+    fn default() -> LocalDate {
+        LocalDate::EPOCH
+    }
+}
+
+impl fmt::Display for LocalDate {
+    /// Formats this date in ISO-8601 format, such as '2007-12-03'.
+    ///
+    /// Years beyond 9999 are prefixed with a '+'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_date(f, self.year, self.month as u32, self.day as u32)
+    }
+}
+
+impl FromStr for LocalDate {
+    type Err = ParseIsoError;
+
+    /// Parses an ISO-8601 date, such as '2007-12-03'.
+    ///
+    /// Years with more than four digits must be signed.
+    fn from_str(text: &str) -> Result<LocalDate, ParseIsoError> {
+        let mut parser = Parser::of(text);
+        let (year, month, day) = parser.date()?;
+        parser.end()?;
+        LocalDate::of_checked(year, month, day)
+            .ok_or_else(|| parser.error_at(0, "date out of range"))
+    }
+}
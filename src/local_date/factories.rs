@@ -0,0 +1,131 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::LocalDate;
+
+use crate::assert::expect_panic;
+
+proptest! {
+    #[test]
+    fn of_epoch_day(epoch_day in LocalDate::MIN.to_epoch_day()..=LocalDate::MAX.to_epoch_day()) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        prop_assert_eq!(epoch_day, date.to_epoch_day());
+        prop_assert_eq!(date, LocalDate::of(date.year(), date.month_value(), date.day_of_month()));
+        prop_assert_eq!(date, LocalDate::of_year_day(date.year(), date.day_of_year()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_epoch_day_overflow(epoch_day in LocalDate::MAX.to_epoch_day() + 1..=i64::MAX) {
+        expect_panic("date out of range", || LocalDate::of_epoch_day(epoch_day))?;
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_epoch_day_underflow(epoch_day in i64::MIN..LocalDate::MIN.to_epoch_day()) {
+        expect_panic("date out of range", || LocalDate::of_epoch_day(epoch_day))?;
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_year_out_of_range(year in prop_oneof![i64::MIN..MIN_YEAR, MAX_YEAR + 1..=i64::MAX]) {
+        expect_panic("date out of range", || LocalDate::of(year, 1, 1))?;
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_month_out_of_range(month in prop_oneof![Just(0u32), 13..=u32::MAX]) {
+        expect_panic("date out of range", || LocalDate::of(2000, month, 1))?;
+    }
+}
+
+#[test]
+fn of_day_out_of_range() {
+    expect_panic("date out of range", || LocalDate::of(2000, 1, 0)).unwrap();
+    expect_panic("date out of range", || LocalDate::of(2000, 4, 31)).unwrap();
+    expect_panic("date out of range", || LocalDate::of(2001, 2, 29)).unwrap();
+    expect_panic("date out of range", || LocalDate::of(1900, 2, 29)).unwrap();
+    expect_panic("date out of range", || LocalDate::of_year_day(2001, 366)).unwrap();
+}
+
+#[test]
+fn of_leap_day() {
+    let date = LocalDate::of(2000, 2, 29);
+
+    assert_eq!(2000, date.year());
+    assert_eq!(2, date.month_value());
+    assert_eq!(29, date.day_of_month());
+    assert_eq!(60, date.day_of_year());
+    assert!(date.is_leap_year());
+    assert_eq!(29, date.length_of_month());
+    assert_eq!(366, date.length_of_year());
+}
+
+#[test]
+fn constants() {
+    assert_eq!(0, LocalDate::EPOCH.to_epoch_day());
+    assert_eq!(LocalDate::of(-999_999_999, 1, 1), LocalDate::MIN);
+    assert_eq!(LocalDate::of(999_999_999, 12, 31), LocalDate::MAX);
+}
+
+proptest! {
+    #[test]
+    fn plus_days(epoch_day in -(MAX_YEAR * DAYS_IN_YEAR / 2)..(MAX_YEAR * DAYS_IN_YEAR / 2), days in -(MAX_YEAR * DAYS_IN_YEAR / 2)..(MAX_YEAR * DAYS_IN_YEAR / 2)) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        prop_assert_eq!(epoch_day + days, date.plus_days(days).to_epoch_day());
+    }
+}
+
+#[test]
+fn plus_days_overflow() {
+    expect_panic("date out of range", || LocalDate::MAX.plus_days(1)).unwrap();
+    expect_panic("date out of range", || LocalDate::MIN.plus_days(i64::MIN)).unwrap();
+}
+
+#[test]
+fn plus_months() {
+    let date = LocalDate::of(2007, 3, 31);
+
+    assert_eq!(LocalDate::of(2007, 4, 30), date.plus_months(1));
+    assert_eq!(LocalDate::of(2007, 2, 28), date.plus_months(-1));
+    assert_eq!(LocalDate::of(2008, 2, 29), date.plus_months(11));
+    assert_eq!(LocalDate::of(2006, 12, 31), date.plus_months(-3));
+    assert_eq!(
+        LocalDate::of(2009, 2, 28),
+        LocalDate::of(2008, 2, 29).plus_years(1)
+    );
+    assert_eq!(
+        LocalDate::of(2012, 2, 29),
+        LocalDate::of(2008, 2, 29).plus_years(4)
+    );
+    expect_panic("date out of range", || LocalDate::MAX.plus_months(1)).unwrap();
+    expect_panic("date out of range", || LocalDate::MIN.plus_months(i64::MIN)).unwrap();
+    expect_panic("date out of range", || {
+        LocalDate::EPOCH.plus_years(i64::MAX)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn plus_months_keeps_or_clamps_day(
+        epoch_day in -1_000_000..=1_000_000i64,
+        months in -100_000..=100_000i64,
+    ) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+        let later = date.plus_months(months);
+
+        prop_assert_eq!(
+            date.year() * MONTHS_IN_YEAR + date.month_value() as i64 + months,
+            later.year() * MONTHS_IN_YEAR + later.month_value() as i64
+        );
+        prop_assert_eq!(date.day_of_month().min(later.length_of_month()), later.day_of_month());
+    }
+}
@@ -0,0 +1,38 @@
+use proptest::prelude::*;
+
+use crate::{LocalDate, ParseIsoError};
+
+fn error(text: &str) -> (usize, &'static str) {
+    let error: ParseIsoError = text.parse::<LocalDate>().unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn display() {
+    assert_eq!("2007-12-03", LocalDate::of(2007, 12, 3).to_string());
+    assert_eq!("0000-01-01", LocalDate::of(0, 1, 1).to_string());
+    assert_eq!("-0001-12-31", LocalDate::of(-1, 12, 31).to_string());
+    assert_eq!("+10000-01-01", LocalDate::of(10_000, 1, 1).to_string());
+    assert_eq!("-999999999-01-01", LocalDate::MIN.to_string());
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!((0, "invalid year"), error("207-12-03"));
+    assert_eq!(
+        (0, "years beyond 9999 must be signed"),
+        error("10000-01-01")
+    );
+    assert_eq!((8, "day of month out of range"), error("2001-02-29"));
+    assert_eq!((10, "unexpected trailing text"), error("2007-12-03T00:00"));
+    assert_eq!((0, "date out of range"), error("+1000000000-01-01"));
+}
+
+proptest! {
+    #[test]
+    fn round_trip(epoch_day in LocalDate::MIN.to_epoch_day()..=LocalDate::MAX.to_epoch_day()) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        prop_assert_eq!(Ok(date), date.to_string().parse());
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(feature = "locales")]
+use std::fmt;
+
+#[cfg(feature = "locales")]
+use crate::DayOfWeek;
+
+#[cfg(feature = "locales")]
+mod cldr;
+#[cfg(all(test, feature = "locales"))]
+pub mod names;
+
+// The text and week rules of a locale, compacted from CLDR.
+//
+// Each list of names is separated by '|', with the full, short and narrow lists in that order.
+// Days are listed from Monday, as ISO-8601 numbers them.
+// Patterns are listed from the full style to the short, and the date-time ones join the date ('{1}') and time ('{0}').
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct LocaleData {
+    pub(crate) tag: &'static str,
+    pub(crate) months: [&'static str; 3],
+    pub(crate) days: [&'static str; 3],
+    pub(crate) quarters: [&'static str; 3],
+    pub(crate) eras: [&'static str; 3],
+    pub(crate) am_pm: &'static str,
+    // From 1 (Monday) to 7 (Sunday).
+    pub(crate) first_day_of_week: i64,
+    pub(crate) minimal_days: i64,
+    pub(crate) date_patterns: [&'static str; 4],
+    pub(crate) time_patterns: [&'static str; 4],
+    pub(crate) date_time_patterns: [&'static str; 4],
+}
+
+// The default: English names, ISO-8601 weeks and the CLDR root patterns.
+pub(crate) const ROOT: LocaleData = LocaleData {
+    tag: "",
+    months: [
+        "January|February|March|April|May|June|July|August|September|October|November|December",
+        "Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec",
+        "J|F|M|A|M|J|J|A|S|O|N|D",
+    ],
+    days: [
+        "Monday|Tuesday|Wednesday|Thursday|Friday|Saturday|Sunday",
+        "Mon|Tue|Wed|Thu|Fri|Sat|Sun",
+        "M|T|W|T|F|S|S",
+    ],
+    quarters: [
+        "1st quarter|2nd quarter|3rd quarter|4th quarter",
+        "Q1|Q2|Q3|Q4",
+        "1|2|3|4",
+    ],
+    eras: ["Before Christ|Anno Domini", "BC|AD", "B|A"],
+    am_pm: "AM|PM",
+    first_day_of_week: 1,
+    minimal_days: 4,
+    date_patterns: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+    date_time_patterns: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+};
+
+impl LocaleData {
+    // Gets a name from one of the lists, counting from 0.
+    pub(crate) fn name(list: &'static str, index: usize) -> Option<&'static str> {
+        list.split('|').nth(index)
+    }
+
+    // The localized day of the week, from 1 on the first day of the week, of an ISO-8601 one.
+    pub(crate) const fn localized_day_of_week(&self, day_of_week: i64) -> i64 {
+        (day_of_week - self.first_day_of_week).rem_euclid(7) + 1
+    }
+
+    // The ISO-8601 day of the week of a localized one.
+    pub(crate) const fn iso_day_of_week(&self, localized_day_of_week: i64) -> i64 {
+        (localized_day_of_week + self.first_day_of_week - 2).rem_euclid(7) + 1
+    }
+}
+
+/// A locale, giving the names, week rules and localized patterns used by a
+/// [`DateTimeFormatter`](struct.DateTimeFormatter.html), enabled by the `locales` feature.
+///
+/// The data is a compacted subset of CLDR for a dozen common locales.
+/// There are no time-zone names, so the full and long time styles give localized offsets instead.
+#[cfg(feature = "locales")]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Locale(pub(crate) &'static LocaleData);
+
+/// The length of a localized date or time format, from the full 'Tuesday, July 1, 2003' to the short '7/1/03'.
+#[cfg(feature = "locales")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FormatStyle {
+    /// The most detail, such as 'Tuesday, July 1, 2003' or '10:52:37 AM GMT+02:00'.
+    Full,
+    /// Such as 'July 1, 2003' or '10:52:37 AM GMT+2'.
+    Long,
+    /// Such as 'Jul 1, 2003' or '10:52:37 AM'.
+    Medium,
+    /// Usually numeric, such as '7/1/03' or '10:52 AM'.
+    Short,
+}
+
+#[cfg(feature = "locales")]
+impl Locale {
+    /// The default locale, with English names, ISO-8601 weeks and the CLDR root patterns, such as '2003-07-01'.
+    pub const ROOT: Locale = Locale(&ROOT);
+
+    /// Obtains a locale from a BCP 47 language tag, such as 'de' or 'en-GB'.
+    ///
+    /// Tags are matched ignoring case, and a region without data falls back to its language,
+    /// so 'de-AT' gives 'de'.
+    ///
+    /// # Parameters
+    ///  - `tag`: the language tag, with '-' or '_' between the parts.
+    ///
+    /// # Returns
+    /// The locale, or `None` if there is no data for the language.
+    pub fn of(tag: &str) -> Option<Locale> {
+        let tag = tag.replace('_', "-");
+        let mut tag = tag.as_str();
+        loop {
+            if let Some(data) = cldr::LOCALES
+                .iter()
+                .find(|data| data.tag.eq_ignore_ascii_case(tag))
+            {
+                return Some(Locale(data));
+            }
+            tag = &tag[..tag.rfind('-')?];
+        }
+    }
+
+    /// Gets the language tags of all the locales with data.
+    pub fn available() -> impl Iterator<Item = &'static str> {
+        cldr::LOCALES.iter().map(|data| data.tag)
+    }
+
+    /// Gets the language tag, empty for the root locale.
+    pub const fn tag(&self) -> &'static str {
+        self.0.tag
+    }
+
+    /// Gets the day weeks start on.
+    pub const fn first_day_of_week(&self) -> DayOfWeek {
+        DayOfWeek::of(self.0.first_day_of_week as u32)
+    }
+
+    /// Gets the fewest days of a year (or month) that the first week must have.
+    pub const fn minimal_days_in_first_week(&self) -> u32 {
+        self.0.minimal_days as u32
+    }
+}
+
+#[cfg(feature = "locales")]
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::ROOT
+    }
+}
+
+#[cfg(feature = "locales")]
+impl fmt::Debug for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Locale").field(&self.0.tag).finish()
+    }
+}
@@ -0,0 +1,332 @@
+// A compacted subset of the CLDR data for the supported locales, in the layout `LocaleData` describes.
+//
+// Month names are those used in dates (the format context), so they are in the genitive where a language has one.
+// Time-zone names in the time patterns are replaced with localized offsets ('OOOO' and 'O'), as there is no zone data.
+
+use super::LocaleData;
+
+pub(super) const LOCALES: [LocaleData; 12] = [
+    LocaleData {
+        tag: "en",
+        months: [
+            "January|February|March|April|May|June|July|August|September|October|November|December",
+            "Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "Monday|Tuesday|Wednesday|Thursday|Friday|Saturday|Sunday",
+            "Mon|Tue|Wed|Thu|Fri|Sat|Sun",
+            "M|T|W|T|F|S|S",
+        ],
+        quarters: [
+            "1st quarter|2nd quarter|3rd quarter|4th quarter",
+            "Q1|Q2|Q3|Q4",
+            "1|2|3|4",
+        ],
+        eras: ["Before Christ|Anno Domini", "BC|AD", "B|A"],
+        am_pm: "AM|PM",
+        first_day_of_week: 7,
+        minimal_days: 1,
+        date_patterns: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+        time_patterns: ["h:mm:ss a OOOO", "h:mm:ss a O", "h:mm:ss a", "h:mm a"],
+        date_time_patterns: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "en-GB",
+        months: [
+            "January|February|March|April|May|June|July|August|September|October|November|December",
+            "Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sept|Oct|Nov|Dec",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "Monday|Tuesday|Wednesday|Thursday|Friday|Saturday|Sunday",
+            "Mon|Tue|Wed|Thu|Fri|Sat|Sun",
+            "M|T|W|T|F|S|S",
+        ],
+        quarters: [
+            "1st quarter|2nd quarter|3rd quarter|4th quarter",
+            "Q1|Q2|Q3|Q4",
+            "1|2|3|4",
+        ],
+        eras: ["Before Christ|Anno Domini", "BC|AD", "B|A"],
+        am_pm: "am|pm",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "de",
+        months: [
+            "Januar|Februar|März|April|Mai|Juni|Juli|August|September|Oktober|November|Dezember",
+            "Jan.|Feb.|März|Apr.|Mai|Juni|Juli|Aug.|Sept.|Okt.|Nov.|Dez.",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "Montag|Dienstag|Mittwoch|Donnerstag|Freitag|Samstag|Sonntag",
+            "Mo.|Di.|Mi.|Do.|Fr.|Sa.|So.",
+            "M|D|M|D|F|S|S",
+        ],
+        quarters: [
+            "1. Quartal|2. Quartal|3. Quartal|4. Quartal",
+            "Q1|Q2|Q3|Q4",
+            "1|2|3|4",
+        ],
+        eras: ["v. Chr.|n. Chr.", "v. Chr.|n. Chr.", "v. Chr.|n. Chr."],
+        am_pm: "AM|PM",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} 'um' {0}", "{1} 'um' {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "fr",
+        months: [
+            "janvier|février|mars|avril|mai|juin|juillet|août|septembre|octobre|novembre|décembre",
+            "janv.|févr.|mars|avr.|mai|juin|juil.|août|sept.|oct.|nov.|déc.",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche",
+            "lun.|mar.|mer.|jeu.|ven.|sam.|dim.",
+            "L|M|M|J|V|S|D",
+        ],
+        quarters: [
+            "1er trimestre|2e trimestre|3e trimestre|4e trimestre",
+            "T1|T2|T3|T4",
+            "1|2|3|4",
+        ],
+        eras: [
+            "avant Jésus-Christ|après Jésus-Christ",
+            "av. J.-C.|ap. J.-C.",
+            "av. J.-C.|ap. J.-C.",
+        ],
+        am_pm: "AM|PM",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} 'à' {0}", "{1} 'à' {0}", "{1} {0}", "{1} {0}"],
+    },
+    LocaleData {
+        tag: "es",
+        months: [
+            "enero|febrero|marzo|abril|mayo|junio|julio|agosto|septiembre|octubre|noviembre|diciembre",
+            "ene|feb|mar|abr|may|jun|jul|ago|sept|oct|nov|dic",
+            "E|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "lunes|martes|miércoles|jueves|viernes|sábado|domingo",
+            "lun|mar|mié|jue|vie|sáb|dom",
+            "L|M|X|J|V|S|D",
+        ],
+        quarters: [
+            "1.er trimestre|2.º trimestre|3.er trimestre|4.º trimestre",
+            "T1|T2|T3|T4",
+            "1|2|3|4",
+        ],
+        eras: ["antes de Cristo|después de Cristo", "a. C.|d. C.", "a. C.|d. C."],
+        am_pm: "a. m.|p. m.",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: [
+            "EEEE, d 'de' MMMM 'de' y",
+            "d 'de' MMMM 'de' y",
+            "d MMM y",
+            "d/M/yy",
+        ],
+        time_patterns: ["H:mm:ss (OOOO)", "H:mm:ss O", "H:mm:ss", "H:mm"],
+        date_time_patterns: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "it",
+        months: [
+            "gennaio|febbraio|marzo|aprile|maggio|giugno|luglio|agosto|settembre|ottobre|novembre|dicembre",
+            "gen|feb|mar|apr|mag|giu|lug|ago|set|ott|nov|dic",
+            "G|F|M|A|M|G|L|A|S|O|N|D",
+        ],
+        days: [
+            "lunedì|martedì|mercoledì|giovedì|venerdì|sabato|domenica",
+            "lun|mar|mer|gio|ven|sab|dom",
+            "L|M|M|G|V|S|D",
+        ],
+        quarters: [
+            "1º trimestre|2º trimestre|3º trimestre|4º trimestre",
+            "T1|T2|T3|T4",
+            "1|2|3|4",
+        ],
+        eras: ["avanti Cristo|dopo Cristo", "a.C.|d.C.", "aC|dC"],
+        am_pm: "AM|PM",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} {0}", "{1} {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "pt",
+        months: [
+            "janeiro|fevereiro|março|abril|maio|junho|julho|agosto|setembro|outubro|novembro|dezembro",
+            "jan.|fev.|mar.|abr.|mai.|jun.|jul.|ago.|set.|out.|nov.|dez.",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "segunda-feira|terça-feira|quarta-feira|quinta-feira|sexta-feira|sábado|domingo",
+            "seg.|ter.|qua.|qui.|sex.|sáb.|dom.",
+            "S|T|Q|Q|S|S|D",
+        ],
+        quarters: [
+            "1º trimestre|2º trimestre|3º trimestre|4º trimestre",
+            "T1|T2|T3|T4",
+            "1|2|3|4",
+        ],
+        eras: ["antes de Cristo|depois de Cristo", "a.C.|d.C.", "a.C.|d.C."],
+        am_pm: "AM|PM",
+        first_day_of_week: 7,
+        minimal_days: 1,
+        date_patterns: [
+            "EEEE, d 'de' MMMM 'de' y",
+            "d 'de' MMMM 'de' y",
+            "d 'de' MMM 'de' y",
+            "dd/MM/y",
+        ],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    },
+    LocaleData {
+        tag: "nl",
+        months: [
+            "januari|februari|maart|april|mei|juni|juli|augustus|september|oktober|november|december",
+            "jan|feb|mrt|apr|mei|jun|jul|aug|sep|okt|nov|dec",
+            "J|F|M|A|M|J|J|A|S|O|N|D",
+        ],
+        days: [
+            "maandag|dinsdag|woensdag|donderdag|vrijdag|zaterdag|zondag",
+            "ma|di|wo|do|vr|za|zo",
+            "M|D|W|D|V|Z|Z",
+        ],
+        quarters: [
+            "1e kwartaal|2e kwartaal|3e kwartaal|4e kwartaal",
+            "K1|K2|K3|K4",
+            "1|2|3|4",
+        ],
+        eras: ["voor Christus|na Christus", "v.Chr.|n.Chr.", "v.C.|n.C."],
+        am_pm: "a.m.|p.m.",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} 'om' {0}", "{1} 'om' {0}", "{1} {0}", "{1} {0}"],
+    },
+    LocaleData {
+        tag: "pl",
+        months: [
+            "stycznia|lutego|marca|kwietnia|maja|czerwca|lipca|sierpnia|września|października|listopada|grudnia",
+            "sty|lut|mar|kwi|maj|cze|lip|sie|wrz|paź|lis|gru",
+            "s|l|m|k|m|c|l|s|w|p|l|g",
+        ],
+        days: [
+            "poniedziałek|wtorek|środa|czwartek|piątek|sobota|niedziela",
+            "pon.|wt.|śr.|czw.|pt.|sob.|niedz.",
+            "p|w|ś|c|p|s|n",
+        ],
+        quarters: [
+            "I kwartał|II kwartał|III kwartał|IV kwartał",
+            "I kw.|II kw.|III kw.|IV kw.",
+            "1|2|3|4",
+        ],
+        eras: ["przed naszą erą|naszej ery", "p.n.e.|n.e.", "p.n.e.|n.e."],
+        am_pm: "AM|PM",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d.MM.y"],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} {0}", "{1} {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "ru",
+        months: [
+            "января|февраля|марта|апреля|мая|июня|июля|августа|сентября|октября|ноября|декабря",
+            "янв.|февр.|мар.|апр.|мая|июн.|июл.|авг.|сент.|окт.|нояб.|дек.",
+            "Я|Ф|М|А|М|И|И|А|С|О|Н|Д",
+        ],
+        days: [
+            "понедельник|вторник|среда|четверг|пятница|суббота|воскресенье",
+            "пн|вт|ср|чт|пт|сб|вс",
+            "П|В|С|Ч|П|С|В",
+        ],
+        quarters: [
+            "1-й квартал|2-й квартал|3-й квартал|4-й квартал",
+            "1-й кв.|2-й кв.|3-й кв.|4-й кв.",
+            "1|2|3|4",
+        ],
+        eras: [
+            "до Рождества Христова|от Рождества Христова",
+            "до н. э.|н. э.",
+            "до н.э.|н.э.",
+        ],
+        am_pm: "AM|PM",
+        first_day_of_week: 1,
+        minimal_days: 4,
+        date_patterns: [
+            "EEEE, d MMMM y 'г'.",
+            "d MMMM y 'г'.",
+            "d MMM y 'г'.",
+            "dd.MM.y",
+        ],
+        time_patterns: ["HH:mm:ss OOOO", "HH:mm:ss O", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+    },
+    LocaleData {
+        tag: "ja",
+        months: [
+            "1月|2月|3月|4月|5月|6月|7月|8月|9月|10月|11月|12月",
+            "1月|2月|3月|4月|5月|6月|7月|8月|9月|10月|11月|12月",
+            "1|2|3|4|5|6|7|8|9|10|11|12",
+        ],
+        days: [
+            "月曜日|火曜日|水曜日|木曜日|金曜日|土曜日|日曜日",
+            "月|火|水|木|金|土|日",
+            "月|火|水|木|金|土|日",
+        ],
+        quarters: [
+            "第1四半期|第2四半期|第3四半期|第4四半期",
+            "Q1|Q2|Q3|Q4",
+            "1|2|3|4",
+        ],
+        eras: ["紀元前|西暦", "紀元前|西暦", "BC|AD"],
+        am_pm: "午前|午後",
+        first_day_of_week: 7,
+        minimal_days: 1,
+        date_patterns: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+        time_patterns: ["H時mm分ss秒 OOOO", "H:mm:ss O", "H:mm:ss", "H:mm"],
+        date_time_patterns: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    },
+    LocaleData {
+        tag: "zh",
+        months: [
+            "一月|二月|三月|四月|五月|六月|七月|八月|九月|十月|十一月|十二月",
+            "1月|2月|3月|4月|5月|6月|7月|8月|9月|10月|11月|12月",
+            "1|2|3|4|5|6|7|8|9|10|11|12",
+        ],
+        days: [
+            "星期一|星期二|星期三|星期四|星期五|星期六|星期日",
+            "周一|周二|周三|周四|周五|周六|周日",
+            "一|二|三|四|五|六|日",
+        ],
+        quarters: [
+            "第一季度|第二季度|第三季度|第四季度",
+            "1季度|2季度|3季度|4季度",
+            "1|2|3|4",
+        ],
+        eras: ["公元前|公元", "公元前|公元", "公元前|公元"],
+        am_pm: "上午|下午",
+        first_day_of_week: 7,
+        minimal_days: 1,
+        date_patterns: ["y年M月d日EEEE", "y年M月d日", "y年M月d日", "y/M/d"],
+        time_patterns: ["OOOO HH:mm:ss", "O HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        date_time_patterns: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    },
+];
@@ -0,0 +1,190 @@
+use crate::{DateTimeFormatter, DayOfWeek, FormatStyle, LocalDate, Locale, OffsetDateTime};
+
+const STYLES: [FormatStyle; 4] = [
+    FormatStyle::Full,
+    FormatStyle::Long,
+    FormatStyle::Medium,
+    FormatStyle::Short,
+];
+
+fn locale(tag: &str) -> Locale {
+    Locale::of(tag).unwrap()
+}
+
+fn date_time() -> OffsetDateTime {
+    OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 0, 7_200)
+}
+
+fn format(formatter: DateTimeFormatter, tag: &str) -> String {
+    formatter
+        .with_locale(locale(tag))
+        .format(&date_time())
+        .unwrap()
+}
+
+#[test]
+fn locale_of_tag() {
+    assert_eq!("de", locale("de-AT").tag());
+    assert_eq!("en-GB", locale("en_gb").tag());
+    assert_eq!("en", locale("EN-us").tag());
+    assert_eq!(None, Locale::of("xx"));
+    assert_eq!(None, Locale::of(""));
+    assert_eq!(12, Locale::available().count());
+    assert!(Locale::available().all(|tag| locale(tag).tag() == tag));
+}
+
+#[test]
+fn week_rules() {
+    assert_eq!(DayOfWeek::Monday, Locale::ROOT.first_day_of_week());
+    assert_eq!(4, Locale::ROOT.minimal_days_in_first_week());
+    assert_eq!(DayOfWeek::Sunday, locale("en").first_day_of_week());
+    assert_eq!(1, locale("en").minimal_days_in_first_week());
+    assert_eq!(DayOfWeek::Monday, locale("en-GB").first_day_of_week());
+    assert_eq!(4, locale("de").minimal_days_in_first_week());
+}
+
+#[test]
+fn every_style_formats_in_every_locale() {
+    for tag in Locale::available() {
+        for &date_style in &STYLES {
+            for &time_style in &STYLES {
+                let formatter = DateTimeFormatter::of_localized_date_time(date_style, time_style);
+
+                let text = format(formatter, tag);
+
+                assert!(
+                    !text.is_empty(),
+                    "{} {:?} {:?}",
+                    tag,
+                    date_style,
+                    time_style
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn localized_dates() {
+    let date = DateTimeFormatter::of_localized_date;
+
+    assert_eq!(
+        "Tuesday, July 1, 2003",
+        format(date(FormatStyle::Full), "en")
+    );
+    assert_eq!("7/1/03", format(date(FormatStyle::Short), "en"));
+    assert_eq!("1 July 2003", format(date(FormatStyle::Long), "en-GB"));
+    assert_eq!(
+        "Dienstag, 1. Juli 2003",
+        format(date(FormatStyle::Full), "de")
+    );
+    assert_eq!("01.07.2003", format(date(FormatStyle::Medium), "de"));
+    assert_eq!("1 juillet 2003", format(date(FormatStyle::Long), "fr"));
+    assert_eq!("2003年7月1日火曜日", format(date(FormatStyle::Full), "ja"));
+    assert_eq!(
+        "2003 Jul 1",
+        date(FormatStyle::Medium).format(&date_time()).unwrap()
+    );
+}
+
+#[test]
+fn localized_times() {
+    let time = DateTimeFormatter::of_localized_time;
+
+    assert_eq!(
+        "10:52:37 AM GMT+02:00",
+        format(time(FormatStyle::Full), "en")
+    );
+    assert_eq!("10:52 AM", format(time(FormatStyle::Short), "en"));
+    assert_eq!("10:52:37", format(time(FormatStyle::Medium), "de"));
+    assert_eq!(
+        "2003-07-01 10:52",
+        DateTimeFormatter::of_localized_date_time(FormatStyle::Short, FormatStyle::Short)
+            .format(&date_time())
+            .unwrap()
+    );
+}
+
+#[test]
+fn locale_is_kept() {
+    let formatter = DateTimeFormatter::of_localized_date(FormatStyle::Long);
+
+    assert_eq!(Locale::ROOT, formatter.locale());
+    assert_eq!(locale("fr"), formatter.with_locale(locale("fr")).locale());
+}
+
+#[test]
+fn pattern_names() {
+    let formatter = DateTimeFormatter::of_pattern("EEEE d MMMM G").unwrap();
+
+    assert_eq!("Tuesday 1 July AD", formatter.format(&date_time()).unwrap());
+    assert_eq!("mardi 1 juillet ap. J.-C.", format(formatter, "fr"));
+}
+
+#[test]
+fn localized_weeks() {
+    let formatter = DateTimeFormatter::of_pattern("YYYY-ww-e").unwrap();
+    // 2005-01-01 is a Saturday.
+    let date_time = OffsetDateTime::of(LocalDate::of(2005, 1, 1), 0, 0, 0, 0, 0);
+
+    assert_eq!("2004-53-6", formatter.format(&date_time).unwrap());
+    assert_eq!(
+        "2005-01-7",
+        formatter
+            .with_locale(locale("en"))
+            .format(&date_time)
+            .unwrap()
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2005, 1, 1)),
+        formatter
+            .with_locale(locale("en"))
+            .parse_local_date("2005-01-7")
+    );
+}
+
+#[test]
+fn localized_day_names() {
+    let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd eeee eee e").unwrap();
+    let date = LocalDate::of(2003, 6, 30);
+    let date_time = OffsetDateTime::of(date, 0, 0, 0, 0, 0);
+
+    for &(tag, text) in &[
+        ("en", "2003-06-30 Monday Mon 2"),
+        ("de", "2003-06-30 Montag Mo. 1"),
+    ] {
+        let formatter = formatter.with_locale(locale(tag));
+
+        assert_eq!(text, formatter.format(&date_time).unwrap());
+        assert_eq!(Ok(date), formatter.parse_local_date(text));
+    }
+    for &(tag, text) in &[("en", "2003-06-30 Monday"), ("de", "2003-06-30 Montag")] {
+        let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd eeee")
+            .unwrap()
+            .with_locale(locale(tag));
+
+        assert_eq!(Ok(date), formatter.parse_local_date(text));
+    }
+}
+
+#[test]
+fn parse_localized_names() {
+    let formatter = DateTimeFormatter::of_localized_date(FormatStyle::Long);
+
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        formatter
+            .with_locale(locale("fr"))
+            .parse_local_date("1 JUILLET 2003")
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2003, 7, 1)),
+        formatter
+            .with_locale(locale("de"))
+            .parse_local_date("1. Juli 2003")
+    );
+    assert!(formatter
+        .with_locale(locale("de"))
+        .parse_local_date("1. July 2003")
+        .is_err());
+}
@@ -0,0 +1,227 @@
+use crate::calendar::*;
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::{Duration, Instant, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod conversions;
+
+const NANOSECONDS_IN_DAY_WIDE: i128 = NANOSECONDS_IN_DAY as i128;
+const NANOSECONDS_IN_SECOND_WIDE: u128 = NANOSECONDS_IN_SECOND as u128;
+// '1900-01-01', the start of NTP era 0.
+const MODIFIED_JULIAN_DAY_OF_NTP_EPOCH: i64 =
+    epoch_day_of(1900, 1, 1) + MODIFIED_JULIAN_DAY_OF_EPOCH;
+const SECONDS_IN_ERA: i128 = 1 << 32;
+
+/// An NTP timestamp, a 32.32 bit fixed point count of seconds since the start of an NTP era.
+///
+/// NTP counts UTC seconds as if every day were 86,400 seconds long, starting from '1900-01-01 00:00:00' UTC.
+/// The 32 bits of seconds wrap around every 136 years, so the era (era 1 started in 2036) has to come from context.
+/// As most operating systems do, the clock repeats the last second of the day during a leap second,
+/// so that second has two instants with the same timestamp.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NtpTimestamp {
+    seconds: u32,
+    fraction: u32,
+}
+
+/// An NTP short format value, a 16.16 bit fixed point count of seconds.
+///
+/// NTP uses these for (non-negative) durations, such as the root delay and root dispersion.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NtpShort {
+    seconds: u16,
+    fraction: u16,
+}
+
+impl NtpTimestamp {
+    /// Obtains a timestamp from the seconds and the fraction of a second.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds since the start of the era.
+    ///  - `fraction`: the fraction of the second, in units of 2^-32 seconds.
+    pub const fn of(seconds: u32, fraction: u32) -> NtpTimestamp {
+        NtpTimestamp { seconds, fraction }
+    }
+
+    /// Obtains a timestamp from its 64 bit wire format, with the seconds in the upper 32 bits.
+    ///
+    /// # Parameters
+    ///  - `bits`: the 64 bit timestamp.
+    pub const fn of_bits(bits: u64) -> NtpTimestamp {
+        NtpTimestamp {
+            seconds: (bits >> 32) as u32,
+            fraction: bits as u32,
+        }
+    }
+
+    /// Converts an instant to a timestamp, discarding the era.
+    ///
+    /// The fraction of the second is truncated to the 2^-32 second resolution of the timestamp.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to convert.
+    ///  - `table`: the table used to convert to UTC.
+    ///
+    /// # Returns
+    /// The timestamp, or `None` if UTC is not defined at the instant.
+    pub fn of_instant(instant: Instant, table: &TaiUtcTable) -> Option<NtpTimestamp> {
+        let utc = table.to_utc(instant)?;
+        let mut nano_of_day = utc.nano_of_day() as i128;
+        if nano_of_day >= NANOSECONDS_IN_DAY_WIDE {
+            // Repeat the end of the day, rather than run on into the next one.
+            let length = table.day_length(utc.modified_julian_day())?;
+            nano_of_day -= total_nanos(length.seconds(), length.nano()) - NANOSECONDS_IN_DAY_WIDE;
+        }
+        let ntp_nanos = (utc.modified_julian_day() - MODIFIED_JULIAN_DAY_OF_NTP_EPOCH) as i128
+            * NANOSECONDS_IN_DAY_WIDE
+            + nano_of_day;
+
+        let nanos = ntp_nanos.rem_euclid(NANOSECONDS_IN_SECOND_WIDE as i128) as u128;
+        Some(NtpTimestamp {
+            seconds: ntp_nanos.div_euclid(NANOSECONDS_IN_SECOND_WIDE as i128) as u32,
+            fraction: ((nanos << 32) / NANOSECONDS_IN_SECOND_WIDE) as u32,
+        })
+    }
+
+    /// Gets the seconds since the start of the era.
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Gets the fraction of the second, in units of 2^-32 seconds.
+    pub const fn fraction(&self) -> u32 {
+        self.fraction
+    }
+
+    /// Gets the 64 bit wire format of this timestamp, with the seconds in the upper 32 bits.
+    pub const fn to_bits(&self) -> u64 {
+        (self.seconds as u64) << 32 | self.fraction as u64
+    }
+
+    /// Converts this timestamp to an instant, in the given era.
+    ///
+    /// The fraction of the second is rounded to the nearest nanosecond.
+    /// A timestamp during a repeated leap second is converted to its first occurrence.
+    ///
+    /// # Parameters
+    ///  - `era`: the NTP era, where era 0 started in 1900 and era 1 starts in 2036.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant, or `None` if UTC is not defined at that time.
+    pub fn to_instant_in_era(&self, era: i64, table: &TaiUtcTable) -> Option<Instant> {
+        let seconds = era as i128 * SECONDS_IN_ERA + self.seconds as i128;
+        let ntp_nanos = seconds * NANOSECONDS_IN_SECOND_WIDE as i128
+            + nanos_of_fraction(self.fraction as u128, 32);
+
+        let day = ntp_nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE)
+            + MODIFIED_JULIAN_DAY_OF_NTP_EPOCH as i128;
+        if day < i64::MIN as i128 || day > i64::MAX as i128 {
+            return None;
+        }
+        table.to_instant(UtcInstant::of_modified_julian_day(
+            day as i64,
+            ntp_nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64,
+        ))
+    }
+
+    /// Converts this timestamp to an instant, in whichever era puts it closest to the given instant.
+    ///
+    /// As recommended by RFC 5905, this resolves the era correctly as long as the pivot is within 68 years.
+    ///
+    /// # Parameters
+    ///  - `pivot`: an instant near the expected result, such as the current time.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant, or `None` if UTC is not defined at that time.
+    pub fn to_instant_near(&self, pivot: Instant, table: &TaiUtcTable) -> Option<Instant> {
+        // The pivot only needs to be roughly right, so the difference between TAI and UTC can be ignored.
+        let pivot_seconds = pivot.epoch_second() as i128
+            - MODIFIED_JULIAN_DAY_OF_NTP_EPOCH as i128 * SECONDS_IN_DAY as i128
+            + MODIFIED_JULIAN_DAY_OF_EPOCH as i128 * SECONDS_IN_DAY as i128;
+        let offset = self.seconds.wrapping_sub(pivot_seconds as u32) as i32;
+        let era = (pivot_seconds + offset as i128).div_euclid(SECONDS_IN_ERA);
+        if era < i64::MIN as i128 || era > i64::MAX as i128 {
+            return None;
+        }
+        self.to_instant_in_era(era as i64, table)
+    }
+}
+
+impl NtpShort {
+    /// Obtains a short format value from the seconds and the fraction of a second.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the whole seconds.
+    ///  - `fraction`: the fraction of the second, in units of 2^-16 seconds.
+    pub const fn of(seconds: u16, fraction: u16) -> NtpShort {
+        NtpShort { seconds, fraction }
+    }
+
+    /// Obtains a short format value from its 32 bit wire format, with the seconds in the upper 16 bits.
+    ///
+    /// # Parameters
+    ///  - `bits`: the 32 bit value.
+    pub const fn of_bits(bits: u32) -> NtpShort {
+        NtpShort {
+            seconds: (bits >> 16) as u16,
+            fraction: bits as u16,
+        }
+    }
+
+    /// Converts a duration to the short format.
+    ///
+    /// The fraction of the second is rounded to the nearest 2^-16 of a second,
+    /// so converting a short format value to a duration and back gives the same value.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to convert.
+    ///
+    /// # Returns
+    /// The short format value, or `None` if the duration is negative, or rounds to 65,536 seconds or more.
+    pub const fn of_duration(duration: Duration) -> Option<NtpShort> {
+        // Checked before shifting, which would otherwise lose the high bits of the seconds.
+        if duration.seconds() < 0 || duration.seconds() > u16::MAX as i64 {
+            return None;
+        }
+        let fraction = ((duration.nano() as u64) << 16) + NANOSECONDS_IN_SECOND as u64 / 2;
+        let units = ((duration.seconds() as u64) << 16) + fraction / NANOSECONDS_IN_SECOND as u64;
+        if units > u32::MAX as u64 {
+            None
+        } else {
+            Some(NtpShort::of_bits(units as u32))
+        }
+    }
+
+    /// Gets the whole seconds.
+    pub const fn seconds(&self) -> u16 {
+        self.seconds
+    }
+
+    /// Gets the fraction of the second, in units of 2^-16 seconds.
+    pub const fn fraction(&self) -> u16 {
+        self.fraction
+    }
+
+    /// Gets the 32 bit wire format of this value, with the seconds in the upper 16 bits.
+    pub const fn to_bits(&self) -> u32 {
+        (self.seconds as u32) << 16 | self.fraction as u32
+    }
+
+    /// Converts this value to a duration.
+    ///
+    /// The fraction of the second is rounded to the nearest nanosecond.
+    pub const fn to_duration(&self) -> Duration {
+        Duration::of_seconds_and_adjustment(
+            self.seconds as i64,
+            nanos_of_fraction(self.fraction as u128, 16) as i64,
+        )
+    }
+}
+
+// The nearest nanoseconds to a binary fraction of a second.
+const fn nanos_of_fraction(fraction: u128, bits: u32) -> i128 {
+    ((fraction * NANOSECONDS_IN_SECOND_WIDE + (1 << (bits - 1))) >> bits) as i128
+}
@@ -0,0 +1,176 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{Duration, Instant, LocalDate, NtpShort, NtpTimestamp, TaiUtcTable, UtcInstant};
+
+fn utc(date: LocalDate, nano_of_day: i64) -> Instant {
+    TaiUtcTable::default()
+        .to_instant(UtcInstant::of_modified_julian_day(
+            date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            nano_of_day,
+        ))
+        .unwrap()
+}
+
+#[test]
+fn of_instant() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        Some(NtpTimestamp::of(2_208_988_800, 0)),
+        NtpTimestamp::of_instant(utc(LocalDate::of(1970, 1, 1), 0), &table)
+    );
+    assert_eq!(
+        Some(NtpTimestamp::of(3_692_217_600, 1 << 31)),
+        NtpTimestamp::of_instant(
+            utc(LocalDate::of(2017, 1, 1), NANOSECONDS_IN_SECOND / 2),
+            &table
+        )
+    );
+    // Era 1 starts at '2036-02-07T06:28:16Z'.
+    assert_eq!(
+        Some(NtpTimestamp::of(0, 0)),
+        NtpTimestamp::of_instant(
+            utc(LocalDate::of(2036, 2, 7), 23_296 * NANOSECONDS_IN_SECOND),
+            &table
+        )
+    );
+    assert_eq!(
+        None,
+        NtpTimestamp::of_instant(Instant::of_epoch_second(-283_996_801), &table)
+    );
+}
+
+#[test]
+fn leap_second_repeats() {
+    let table = TaiUtcTable::default();
+    let before = utc(
+        LocalDate::of(2016, 12, 31),
+        NANOSECONDS_IN_DAY - NANOSECONDS_IN_SECOND / 2,
+    );
+    let during = utc(
+        LocalDate::of(2016, 12, 31),
+        NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND / 2,
+    );
+    let timestamp = NtpTimestamp::of_instant(during, &table).unwrap();
+
+    assert_eq!(NtpTimestamp::of_instant(before, &table), Some(timestamp));
+    assert_eq!(Some(before), timestamp.to_instant_near(during, &table));
+}
+
+#[test]
+fn to_instant_in_era() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        Some(utc(LocalDate::of(1970, 1, 1), 0)),
+        NtpTimestamp::of(2_208_988_800, 0).to_instant_in_era(0, &table)
+    );
+    assert_eq!(
+        Some(utc(
+            LocalDate::of(2036, 2, 7),
+            23_296 * NANOSECONDS_IN_SECOND
+        )),
+        NtpTimestamp::of(0, 0).to_instant_in_era(1, &table)
+    );
+    assert_eq!(None, NtpTimestamp::of(0, 0).to_instant_in_era(0, &table));
+    assert_eq!(
+        None,
+        NtpTimestamp::of(0, 0).to_instant_in_era(i64::MAX, &table)
+    );
+}
+
+#[test]
+fn to_instant_near_era_boundary() {
+    let table = TaiUtcTable::default();
+    let pivot = utc(LocalDate::of(2036, 1, 1), 0);
+
+    assert_eq!(
+        Some(utc(
+            LocalDate::of(2036, 2, 7),
+            23_296 * NANOSECONDS_IN_SECOND
+        )),
+        NtpTimestamp::of(0, 0).to_instant_near(pivot, &table)
+    );
+    assert_eq!(
+        Some(utc(
+            LocalDate::of(2036, 2, 7),
+            23_295 * NANOSECONDS_IN_SECOND
+        )),
+        NtpTimestamp::of(u32::MAX, 0).to_instant_near(pivot, &table)
+    );
+    assert_eq!(
+        Some(utc(LocalDate::of(1970, 1, 1), 0)),
+        NtpTimestamp::of(2_208_988_800, 0).to_instant_near(Instant::EPOCH, &table)
+    );
+}
+
+proptest! {
+    #[test]
+    fn round_trip(seconds in 63_072_010i64..4_102_444_800, nanos in 0u32..1_000_000_000) {
+        let table = TaiUtcTable::default();
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos as i64);
+        let timestamp = NtpTimestamp::of_instant(instant, &table).unwrap();
+
+        prop_assert_eq!(timestamp, NtpTimestamp::of_bits(timestamp.to_bits()));
+        if table.to_utc(instant).unwrap().nano_of_day() < NANOSECONDS_IN_DAY {
+            prop_assert_eq!(Some(instant), timestamp.to_instant_near(Instant::of_epoch_second(seconds), &table));
+        }
+    }
+}
+
+#[test]
+fn bits() {
+    let timestamp = NtpTimestamp::of_bits(0x0123_4567_89AB_CDEF);
+
+    assert_eq!(0x0123_4567, timestamp.seconds());
+    assert_eq!(0x89AB_CDEF, timestamp.fraction());
+    assert_eq!(0x0123_4567_89AB_CDEF, timestamp.to_bits());
+    assert_eq!(0x0001_8000, NtpShort::of(1, 0x8000).to_bits());
+    assert_eq!(NtpShort::of(1, 0x8000), NtpShort::of_bits(0x0001_8000));
+}
+
+#[test]
+fn short_of_duration() {
+    assert_eq!(
+        Some(NtpShort::of(1, 0x8000)),
+        NtpShort::of_duration(Duration::of_millis(1500))
+    );
+    assert_eq!(
+        Some(NtpShort::of(0, 1)),
+        NtpShort::of_duration(Duration::of_nanos(7_630))
+    );
+    assert_eq!(
+        Some(NtpShort::of(0, 0)),
+        NtpShort::of_duration(Duration::of_nanos(7_629))
+    );
+    assert_eq!(
+        Some(NtpShort::of(u16::MAX, u16::MAX)),
+        NtpShort::of_duration(Duration::of_seconds_and_adjustment(65_535, 999_990_000))
+    );
+    assert_eq!(
+        None,
+        NtpShort::of_duration(Duration::of_seconds_and_adjustment(65_535, 999_999_999))
+    );
+    assert_eq!(None, NtpShort::of_duration(Duration::of_seconds(65_536)));
+    assert_eq!(None, NtpShort::of_duration(Duration::of_nanos(-1)));
+    assert_eq!(None, NtpShort::of_duration(Duration::of_seconds(1 << 48)));
+    assert_eq!(
+        None,
+        NtpShort::of_duration(Duration::of_seconds_and_adjustment(
+            (1 << 48) - 1,
+            999_999_999
+        ))
+    );
+    assert_eq!(None, NtpShort::of_duration(Duration::MAX));
+}
+
+proptest! {
+    #[test]
+    fn short_round_trip(seconds: u16, fraction: u16) {
+        let short = NtpShort::of(seconds, fraction);
+
+        prop_assert_eq!(Some(short), NtpShort::of_duration(short.to_duration()));
+    }
+}
@@ -0,0 +1,475 @@
+use std::fmt;
+
+use crate::constants::*;
+use crate::iso::*;
+use crate::seconds_nanos::*;
+use crate::util::const_expect;
+use crate::{
+    ChronoField, DateTimeError, Duration, Instant, LocalDate, Period, PeriodDuration, TaiUtcTable,
+    TemporalAdjuster, UtcInstant,
+};
+
+#[cfg(test)]
+pub mod arithmetic;
+#[cfg(test)]
+pub mod factories;
+
+pub(crate) const MAX_OFFSET_SECONDS: i32 = 18 * SECONDS_IN_HOUR as i32;
+const NANOSECONDS_IN_DAY_WIDE: i128 = NANOSECONDS_IN_DAY as i128;
+
+/// A UTC date-time with an offset from UTC, such as '2007-12-03T10:15:30+01:00'.
+///
+/// Unlike an [`Instant`], this follows UTC labelling, so its second may be 60 during a leap second.
+/// The leap second is written at the same local time as it happens in UTC (so ':60' can end
+/// any minute with an offset), and whether it exists is only checked when converting through a [`TaiUtcTable`].
+///
+/// [`Instant`]: struct.Instant.html
+/// [`TaiUtcTable`]: struct.TaiUtcTable.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OffsetDateTime {
+    utc: UtcInstant,
+    offset_seconds: i32,
+}
+
+struct LocalFields {
+    epoch_day: i64,
+    second_of_day: i64,
+    leap_second: bool,
+    nano: u32,
+}
+
+impl OffsetDateTime {
+    /// Obtains an `OffsetDateTime` from a local date, time and offset.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `hour`: the hour of the day, from 0 to 23.
+    ///  - `minute`: the minute of the hour, from 0 to 59.
+    ///  - `second`: the second of the minute, from 0 to 60.
+    ///  - `nano`: the nanosecond of the second, from 0 to 999,999,999.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    ///
+    /// # Panics
+    /// - if any field is out of range.
+    /// - if the second is 60, but is not the last second of a UTC day.
+    pub const fn of(
+        date: LocalDate,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+        offset_seconds: i32,
+    ) -> OffsetDateTime {
+        const_expect!(
+            OffsetDateTime::of_checked(date, hour, minute, second, nano, offset_seconds),
+            "date-time out of range"
+        )
+    }
+
+    pub(crate) const fn of_checked(
+        date: LocalDate,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+        offset_seconds: i32,
+    ) -> Option<OffsetDateTime> {
+        if hour as i64 >= HOURS_IN_DAY
+            || minute as i64 >= MINUTES_IN_HOUR
+            || second as i64 > SECONDS_IN_MINUTE
+            || nano as i64 >= NANOSECONDS_IN_SECOND
+            || offset_seconds < -MAX_OFFSET_SECONDS
+            || offset_seconds > MAX_OFFSET_SECONDS
+        {
+            return None;
+        }
+        let leap_second = second as i64 == SECONDS_IN_MINUTE;
+        let second_of_day = (hour as i64 * MINUTES_IN_HOUR + minute as i64) * SECONDS_IN_MINUTE
+            + second as i64
+            - leap_second as i64;
+        let nanos = date.to_epoch_day() as i128 * NANOSECONDS_IN_DAY_WIDE
+            + (second_of_day - offset_seconds as i64) as i128 * NANOSECONDS_IN_SECOND as i128
+            + nano as i128;
+
+        let mut nano_of_day = nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64;
+        if leap_second {
+            if nano_of_day < NANOSECONDS_IN_DAY - NANOSECONDS_IN_SECOND {
+                return None;
+            }
+            nano_of_day += NANOSECONDS_IN_SECOND;
+        }
+        Some(OffsetDateTime {
+            utc: UtcInstant::of_modified_julian_day(
+                nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE) as i64 + MODIFIED_JULIAN_DAY_OF_EPOCH,
+                nano_of_day,
+            ),
+            offset_seconds,
+        })
+    }
+
+    /// Obtains an `OffsetDateTime` from a local date, time and offset, or an error naming the first field out of range.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `hour`: the hour of the day, from 0 to 23.
+    ///  - `minute`: the minute of the hour, from 0 to 59.
+    ///  - `second`: the second of the minute, from 0 to 60, where 60 must be the last second of a UTC day.
+    ///  - `nano`: the nanosecond of the second, from 0 to 999,999,999.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    pub fn try_of(
+        date: LocalDate,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+        offset_seconds: i32,
+    ) -> Result<OffsetDateTime, DateTimeError> {
+        DateTimeError::check(ChronoField::HourOfDay, hour as i64, 0..=HOURS_IN_DAY - 1)?;
+        DateTimeError::check(
+            ChronoField::MinuteOfHour,
+            minute as i64,
+            0..=MINUTES_IN_HOUR - 1,
+        )?;
+        DateTimeError::check(
+            ChronoField::SecondOfMinute,
+            second as i64,
+            0..=SECONDS_IN_MINUTE,
+        )?;
+        DateTimeError::check(
+            ChronoField::NanoOfSecond,
+            nano as i64,
+            0..=NANOSECONDS_IN_SECOND - 1,
+        )?;
+        check_offset(offset_seconds)?;
+        OffsetDateTime::of_checked(date, hour, minute, second, nano, offset_seconds).ok_or(
+            // Everything else is in range, so this is a leap second in the middle of a UTC day.
+            DateTimeError::InvalidField {
+                field: ChronoField::SecondOfMinute,
+                value: second as i64,
+                range: 0..=SECONDS_IN_MINUTE - 1,
+            },
+        )
+    }
+
+    /// Obtains an `OffsetDateTime` from a UTC instant and an offset.
+    ///
+    /// # Parameters
+    ///  - `utc`: the UTC instant.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    ///
+    /// # Panics
+    /// - if the offset is out of range, or the local date would be out of range.
+    pub const fn of_utc(utc: UtcInstant, offset_seconds: i32) -> OffsetDateTime {
+        const_expect!(
+            OffsetDateTime::of_utc_checked(utc, offset_seconds),
+            "date-time out of range"
+        )
+    }
+
+    const fn of_utc_checked(utc: UtcInstant, offset_seconds: i32) -> Option<OffsetDateTime> {
+        if offset_seconds < -MAX_OFFSET_SECONDS || offset_seconds > MAX_OFFSET_SECONDS {
+            return None;
+        }
+        let date_time = OffsetDateTime {
+            utc,
+            offset_seconds,
+        };
+        let epoch_day = date_time.local().epoch_day;
+        if epoch_day < LocalDate::MIN.to_epoch_day() || epoch_day > LocalDate::MAX.to_epoch_day() {
+            None
+        } else {
+            Some(date_time)
+        }
+    }
+
+    /// Obtains an `OffsetDateTime` from a UTC instant and an offset,
+    /// or an error if the offset is out of range, or the local date would be out of range.
+    ///
+    /// # Parameters
+    ///  - `utc`: the UTC instant.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    pub fn try_of_utc(
+        utc: UtcInstant,
+        offset_seconds: i32,
+    ) -> Result<OffsetDateTime, DateTimeError> {
+        check_offset(offset_seconds)?;
+        OffsetDateTime::of_utc_checked(utc, offset_seconds).ok_or(DateTimeError::Overflow)
+    }
+
+    /// Obtains an `OffsetDateTime` from an instant and an offset.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    ///  - `table`: the table used to convert to UTC.
+    ///
+    /// # Returns
+    /// The date-time, or `None` if UTC is not defined at the instant.
+    ///
+    /// # Panics
+    /// - if the offset is out of range, or the local date would be out of range.
+    pub fn of_instant(
+        instant: Instant,
+        offset_seconds: i32,
+        table: &TaiUtcTable,
+    ) -> Option<OffsetDateTime> {
+        table
+            .to_utc(instant)
+            .map(|utc| OffsetDateTime::of_utc(utc, offset_seconds))
+    }
+
+    /// Obtains an `OffsetDateTime` from an instant and an offset,
+    /// or an error if the instant is before UTC was defined, or the result would be out of range.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant.
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    ///  - `table`: the table used to convert to UTC.
+    pub fn try_of_instant(
+        instant: Instant,
+        offset_seconds: i32,
+        table: &TaiUtcTable,
+    ) -> Result<OffsetDateTime, DateTimeError> {
+        match table.to_utc(instant) {
+            Some(utc) => OffsetDateTime::try_of_utc(utc, offset_seconds),
+            None if is_before_utc(instant, table) => Err(DateTimeError::BeforeUtc),
+            None => Err(DateTimeError::Overflow),
+        }
+    }
+
+    // The local date and time, with a leap second kept in the second before it.
+    const fn local(&self) -> LocalFields {
+        let leap_second = self.utc.nano_of_day() >= NANOSECONDS_IN_DAY;
+        let nanos = (self.utc.modified_julian_day() - MODIFIED_JULIAN_DAY_OF_EPOCH) as i128
+            * NANOSECONDS_IN_DAY_WIDE
+            + (self.utc.nano_of_day() - leap_second as i64 * NANOSECONDS_IN_SECOND) as i128
+            + self.offset_seconds as i128 * NANOSECONDS_IN_SECOND as i128;
+        let nano_of_day = nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64;
+        LocalFields {
+            epoch_day: nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE) as i64,
+            second_of_day: nano_of_day / NANOSECONDS_IN_SECOND,
+            leap_second,
+            nano: (nano_of_day % NANOSECONDS_IN_SECOND) as u32,
+        }
+    }
+
+    // The nanoseconds of the local date-time since '1970-01-01T00:00', with a leap second kept in the second before it.
+    pub(crate) const fn local_nanos(&self) -> i128 {
+        let local = self.local();
+        local.epoch_day as i128 * NANOSECONDS_IN_DAY_WIDE
+            + local.second_of_day as i128 * NANOSECONDS_IN_SECOND as i128
+            + local.nano as i128
+    }
+
+    /// Gets the local date.
+    pub const fn date(&self) -> LocalDate {
+        LocalDate::of_epoch_day(self.local().epoch_day)
+    }
+
+    /// Gets the hour of the day, from 0 to 23.
+    pub const fn hour(&self) -> u32 {
+        (self.local().second_of_day / SECONDS_IN_HOUR) as u32
+    }
+
+    /// Gets the minute of the hour, from 0 to 59.
+    pub const fn minute(&self) -> u32 {
+        (self.local().second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR) as u32
+    }
+
+    /// Gets the second of the minute, from 0 to 60.
+    pub const fn second(&self) -> u32 {
+        let local = self.local();
+        (local.second_of_day % SECONDS_IN_MINUTE + local.leap_second as i64) as u32
+    }
+
+    /// Gets the nanosecond of the second, from 0 to 999,999,999.
+    pub const fn nano(&self) -> u32 {
+        self.local().nano
+    }
+
+    /// Gets the offset from UTC, in seconds.
+    pub const fn offset_seconds(&self) -> i32 {
+        self.offset_seconds
+    }
+
+    /// Returns the same instant with a different offset.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC, up to 18 hours either way.
+    ///
+    /// # Panics
+    /// - if the offset is out of range, or the local date would be out of range.
+    pub const fn with_offset_seconds(&self, offset_seconds: i32) -> OffsetDateTime {
+        OffsetDateTime::of_utc(self.utc, offset_seconds)
+    }
+
+    /// Converts this date-time to a UTC instant.
+    pub const fn to_utc(&self) -> UtcInstant {
+        self.utc
+    }
+
+    /// Converts this date-time to an instant.
+    ///
+    /// # Parameters
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant, or `None` if there is no such UTC instant (such as a leap second the table does not have).
+    pub fn to_instant(&self, table: &TaiUtcTable) -> Option<Instant> {
+        table.to_instant(self.utc)
+    }
+
+    /// Returns a copy of this date-time with the given period added to the local date.
+    ///
+    /// The local time and offset are kept, and the date is clamped to the end of shorter months
+    /// as by [`LocalDate::plus_period()`].
+    ///
+    /// # Parameters
+    ///  - `period`: the period to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date-time would be out of range.
+    ///
+    /// [`LocalDate::plus_period()`]: struct.LocalDate.html#method.plus_period
+    pub const fn plus_period(&self, period: Period) -> OffsetDateTime {
+        const_expect!(self.plus_period_checked(period), "date-time out of range")
+    }
+
+    const fn plus_period_checked(&self, period: Period) -> Option<OffsetDateTime> {
+        match self.date().plus_period_checked(period) {
+            None => None,
+            Some(date) => OffsetDateTime::of_checked(
+                date,
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.nano(),
+                self.offset_seconds,
+            ),
+        }
+    }
+
+    /// Returns a copy of this date-time with the given duration added to the local date-time.
+    ///
+    /// Days are taken to be exactly 86,400 seconds, so leap seconds are not counted,
+    /// and a leap second is treated as the second before it.
+    /// To add elapsed time across leap seconds, convert to an [`Instant`] instead.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date-time would be out of range.
+    ///
+    /// [`Instant`]: struct.Instant.html
+    pub const fn plus_duration(&self, duration: Duration) -> OffsetDateTime {
+        const_expect!(
+            self.plus_duration_checked(duration),
+            "date-time out of range"
+        )
+    }
+
+    pub(crate) const fn plus_duration_checked(&self, duration: Duration) -> Option<OffsetDateTime> {
+        let nanos = self.local_nanos() + total_nanos(duration.seconds(), duration.nano());
+        let epoch_day = nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE);
+        if epoch_day < LocalDate::MIN.to_epoch_day() as i128
+            || epoch_day > LocalDate::MAX.to_epoch_day() as i128
+        {
+            return None;
+        }
+        let nano_of_day = nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64;
+        let second_of_day = nano_of_day / NANOSECONDS_IN_SECOND;
+        OffsetDateTime::of_checked(
+            LocalDate::of_epoch_day(epoch_day as i64),
+            (second_of_day / SECONDS_IN_HOUR) as u32,
+            (second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR) as u32,
+            (second_of_day % SECONDS_IN_MINUTE) as u32,
+            (nano_of_day % NANOSECONDS_IN_SECOND) as u32,
+            self.offset_seconds,
+        )
+    }
+
+    /// Returns a copy of this date-time with the given amount added, the period first and then the duration.
+    ///
+    /// This is [`plus_period()`] followed by [`plus_duration()`], so 'P1MT1H' moves
+    /// '2007-01-31T23:30Z' to '2007-02-28T23:30Z' and then to '2007-03-01T00:30Z'.
+    ///
+    /// # Parameters
+    ///  - `amount`: the amount to add, which may be negative.
+    ///
+    /// # Panics
+    /// - if the resulting date-time would be out of range.
+    ///
+    /// [`plus_period()`]: struct.OffsetDateTime.html#method.plus_period
+    /// [`plus_duration()`]: struct.OffsetDateTime.html#method.plus_duration
+    pub const fn plus_period_duration(&self, amount: PeriodDuration) -> OffsetDateTime {
+        const_expect!(
+            match self.plus_period_checked(amount.period()) {
+                None => None,
+                Some(date_time) => date_time.plus_duration_checked(amount.duration()),
+            },
+            "date-time out of range"
+        )
+    }
+
+    /// Returns a copy of this date-time with the local date adjusted by an adjuster, keeping the local time and offset.
+    ///
+    /// # Parameters
+    ///  - `adjuster`: the adjuster to apply to the date, such as one from [`TemporalAdjusters`], or a closure.
+    ///
+    /// # Returns
+    /// The adjusted date-time, or an error if it would be out of range.
+    ///
+    /// [`TemporalAdjusters`]: enum.TemporalAdjusters.html
+    pub fn with_adjuster(
+        &self,
+        adjuster: impl TemporalAdjuster,
+    ) -> Result<OffsetDateTime, DateTimeError> {
+        OffsetDateTime::try_of(
+            adjuster.adjust_into(self.date())?,
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.nano(),
+            self.offset_seconds,
+        )
+    }
+}
+
+impl fmt::Display for OffsetDateTime {
+    /// Formats this date-time in ISO-8601 format, such as '2007-12-03T10:15:30+01:00'.
+    ///
+    /// A zero offset is written as 'Z', and an offset is only written with seconds if it has them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date();
+        format_date(f, date.year(), date.month_value(), date.day_of_month())?;
+        write!(
+            f,
+            "T{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+        format_fraction(f, self.nano())?;
+        format_offset(f, self.offset_seconds)
+    }
+}
+
+fn check_offset(offset_seconds: i32) -> Result<i64, DateTimeError> {
+    let range = -MAX_OFFSET_SECONDS as i64..=MAX_OFFSET_SECONDS as i64;
+    DateTimeError::check(ChronoField::OffsetSeconds, offset_seconds as i64, range)
+}
+
+// Whether the instant is before the first entry of the table, when UTC was not yet defined.
+fn is_before_utc(instant: Instant, table: &TaiUtcTable) -> bool {
+    let start = table.entries().first().and_then(|first| {
+        table.to_instant(UtcInstant::of_modified_julian_day(
+            first.modified_julian_day(),
+            0,
+        ))
+    });
+    match start {
+        Some(start) => instant < start,
+        None => true,
+    }
+}
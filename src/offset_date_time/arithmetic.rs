@@ -0,0 +1,100 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{Duration, LocalDate, OffsetDateTime, Period, PeriodDuration};
+
+fn date_time(year: i64, month: u32, day: u32, hour: u32, minute: u32) -> OffsetDateTime {
+    OffsetDateTime::of(LocalDate::of(year, month, day), hour, minute, 0, 0, 3_600)
+}
+
+#[test]
+fn plus_period() {
+    let start = date_time(2007, 1, 31, 23, 30);
+
+    assert_eq!(
+        date_time(2007, 2, 28, 23, 30),
+        start.plus_period(Period::of_months(1))
+    );
+    assert_eq!(
+        date_time(2006, 2, 28, 23, 30),
+        start.plus_period(Period::of(-1, 1, 0))
+    );
+    assert_eq!(
+        "2016-12-30T23:59:60Z",
+        OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 60, 0, 0)
+            .plus_period(Period::of_days(-1))
+            .to_string()
+    );
+}
+
+#[test]
+fn plus_duration() {
+    let start = date_time(2007, 1, 31, 23, 30);
+
+    assert_eq!(
+        date_time(2007, 2, 1, 0, 30),
+        start.plus_duration(Duration::of_hours(1))
+    );
+    assert_eq!(
+        date_time(2007, 1, 30, 23, 30),
+        start.plus_duration(Duration::of_days(-1))
+    );
+    assert_eq!(
+        OffsetDateTime::of(LocalDate::of(2017, 1, 1), 0, 0, 0, 500_000_000, 0),
+        OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 60, 500_000_000, 0)
+            .plus_duration(Duration::of_seconds(1))
+    );
+}
+
+#[test]
+fn plus_period_duration() {
+    let amount = PeriodDuration::of(Period::of_months(1), Duration::of_hours(1));
+
+    assert_eq!(
+        OffsetDateTime::of(LocalDate::of(2007, 3, 1), 0, 30, 0, 0, 0),
+        OffsetDateTime::of(LocalDate::of(2007, 1, 31), 23, 30, 0, 0, 0)
+            .plus_period_duration(amount)
+    );
+}
+
+#[test]
+fn plus_out_of_range() {
+    let max = OffsetDateTime::of(LocalDate::MAX, 23, 0, 0, 0, 0);
+
+    expect_panic("date-time out of range", || {
+        max.plus_duration(Duration::of_hours(1))
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        max.plus_period(Period::of_days(1))
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        max.plus_duration(Duration::MAX)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn plus_duration_matches_utc(
+        epoch_second in -100_000_000_000..100_000_000_000i64,
+        seconds in -100_000_000_000..100_000_000_000i64,
+        offset_hours in -18..=18i32,
+    ) {
+        let date_time = OffsetDateTime::of(LocalDate::EPOCH, 0, 0, 0, 0, 0)
+            .plus_duration(Duration::of_seconds(epoch_second))
+            .with_offset_seconds(offset_hours * SECONDS_IN_HOUR as i32);
+
+        let later = date_time.plus_duration(Duration::of_seconds(seconds));
+
+        prop_assert_eq!(date_time.offset_seconds(), later.offset_seconds());
+        prop_assert_eq!(
+            OffsetDateTime::of(LocalDate::EPOCH, 0, 0, 0, 0, 0)
+                .plus_duration(Duration::of_seconds(epoch_second + seconds)),
+            later.with_offset_seconds(0)
+        );
+    }
+}
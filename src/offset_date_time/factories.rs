@@ -0,0 +1,161 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{Instant, LocalDate, OffsetDateTime, TaiUtcTable, UtcInstant};
+
+fn utc(date: LocalDate, nano_of_day: i64) -> UtcInstant {
+    UtcInstant::of_modified_julian_day(
+        date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+        nano_of_day,
+    )
+}
+
+#[test]
+fn of() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 5, 7_200);
+
+    assert_eq!(LocalDate::of(2003, 7, 1), date_time.date());
+    assert_eq!(
+        (10, 52, 37, 5, 7_200),
+        (
+            date_time.hour(),
+            date_time.minute(),
+            date_time.second(),
+            date_time.nano(),
+            date_time.offset_seconds()
+        )
+    );
+    assert_eq!(
+        utc(
+            LocalDate::of(2003, 7, 1),
+            31_957 * NANOSECONDS_IN_SECOND + 5
+        ),
+        date_time.to_utc()
+    );
+}
+
+#[test]
+fn of_across_midnight() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2000, 1, 1), 1, 0, 0, 0, 7_200);
+
+    assert_eq!(
+        utc(LocalDate::of(1999, 12, 31), 23 * NANOSECONDS_IN_HOUR),
+        date_time.to_utc()
+    );
+    assert_eq!(
+        OffsetDateTime::of(LocalDate::of(1999, 12, 31), 14, 0, 0, 0, -9 * 3_600),
+        date_time.with_offset_seconds(-9 * 3_600)
+    );
+}
+
+#[test]
+fn of_leap_second() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2017, 1, 1), 1, 59, 60, 500, 7_200);
+
+    assert_eq!(
+        utc(LocalDate::of(2016, 12, 31), NANOSECONDS_IN_DAY + 500),
+        date_time.to_utc()
+    );
+    assert_eq!(60, date_time.second());
+    assert_eq!(
+        (23, 59, 60),
+        (
+            date_time.with_offset_seconds(0).hour(),
+            date_time.with_offset_seconds(0).minute(),
+            date_time.with_offset_seconds(0).second()
+        )
+    );
+    assert_eq!("2017-01-01T01:59:60.000000500+02:00", date_time.to_string());
+}
+
+#[test]
+fn of_out_of_range() {
+    let date = LocalDate::of(2016, 12, 31);
+
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 24, 0, 0, 0, 0)
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 0, 60, 0, 0, 0)
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 0, 0, 61, 0, 0)
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 0, 0, 0, 1_000_000_000, 0)
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 0, 0, 0, 0, 18 * 3_600 + 1)
+    })
+    .unwrap();
+    // Only the last minute of a UTC day can have a leap second.
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of(date, 23, 59, 60, 0, 3_600)
+    })
+    .unwrap();
+    expect_panic("date-time out of range", || {
+        OffsetDateTime::of_utc(utc(LocalDate::MAX, 23 * NANOSECONDS_IN_HOUR), 3_600)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn of_utc(epoch_day in -1_000_000i64..1_000_000, nano_of_day in 0..NANOSECONDS_IN_DAY, offset_seconds in -64_800i32..=64_800) {
+        let date_time = OffsetDateTime::of_utc(utc(LocalDate::of_epoch_day(epoch_day), nano_of_day), offset_seconds);
+
+        prop_assert_eq!(
+            date_time,
+            OffsetDateTime::of(
+                date_time.date(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second(),
+                date_time.nano(),
+                offset_seconds
+            )
+        );
+    }
+}
+
+#[test]
+fn instants() {
+    let table = TaiUtcTable::default();
+    let date_time = OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 60, 0, 0);
+    let instant = date_time.to_instant(&table).unwrap();
+
+    assert_eq!(
+        Some(date_time),
+        OffsetDateTime::of_instant(instant, 0, &table)
+    );
+    assert_eq!(
+        None,
+        OffsetDateTime::of(LocalDate::of(2016, 12, 30), 23, 59, 60, 0, 0).to_instant(&table)
+    );
+    assert_eq!(
+        None,
+        OffsetDateTime::of_instant(Instant::of_epoch_second(-400_000_000), 0, &table)
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        "2003-07-01T10:52:37+02:00",
+        OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 0, 7_200).to_string()
+    );
+    assert_eq!(
+        "2003-07-01T10:52:37.250Z",
+        OffsetDateTime::of(LocalDate::of(2003, 7, 1), 10, 52, 37, 250_000_000, 0).to_string()
+    );
+    assert_eq!(
+        "1900-01-01T00:00:00-00:19:32",
+        OffsetDateTime::of(LocalDate::of(1900, 1, 1), 0, 0, 0, 0, -1_172).to_string()
+    );
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::constants::*;
+use crate::iso::*;
+use crate::util::const_expect;
+use crate::{DateTimeError, LocalDate};
+
+#[cfg(test)]
+pub mod factories;
+#[cfg(test)]
+pub mod text;
+
+/// A date-based amount of time in the ISO-8601 calendar system, such as '2 years, 3 months and 4 days'.
+///
+/// This is the date-based counterpart of [`Duration`](struct.Duration.html).
+/// A month is not a fixed number of days, so the years, months and days are kept apart,
+/// and only mean a length of time when added to a date.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Period {
+    years: i32,
+    months: i32,
+    days: i32,
+}
+
+impl Period {
+    /// Constant for a period of zero length.
+    pub const ZERO: Period = Period {
+        years: 0,
+        months: 0,
+        days: 0,
+    };
+
+    /// Obtains a `Period` from a number of years, months and days.
+    ///
+    /// # Parameters
+    ///  - `years`: the years, which may be negative.
+    ///  - `months`: the months, which may be negative.
+    ///  - `days`: the days, which may be negative.
+    pub const fn of(years: i32, months: i32, days: i32) -> Period {
+        Period {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// Obtains a `Period` representing a number of years.
+    ///
+    /// # Parameters
+    ///  - `years`: the years, which may be negative.
+    pub const fn of_years(years: i32) -> Period {
+        Period::of(years, 0, 0)
+    }
+
+    /// Obtains a `Period` representing a number of months.
+    ///
+    /// # Parameters
+    ///  - `months`: the months, which may be negative.
+    pub const fn of_months(months: i32) -> Period {
+        Period::of(0, months, 0)
+    }
+
+    /// Obtains a `Period` representing a number of weeks, as seven days each.
+    ///
+    /// # Parameters
+    ///  - `weeks`: the weeks, which may be negative.
+    ///
+    /// # Panics
+    /// - if the amount of days would overflow the period.
+    pub const fn of_weeks(weeks: i32) -> Period {
+        const_expect!(
+            Period::of_weeks_checked(weeks),
+            "weeks would overflow period"
+        )
+    }
+
+    /// Obtains a `Period` representing a number of weeks, or an error if the days would overflow the period.
+    ///
+    /// # Parameters
+    ///  - `weeks`: the weeks, which may be negative.
+    pub fn try_of_weeks(weeks: i32) -> Result<Period, DateTimeError> {
+        Period::of_weeks_checked(weeks).ok_or(DateTimeError::Overflow)
+    }
+
+    const fn of_weeks_checked(weeks: i32) -> Option<Period> {
+        match weeks.checked_mul(DAYS_IN_WEEK_ISO as i32) {
+            None => None,
+            Some(days) => Some(Period::of_days(days)),
+        }
+    }
+
+    /// Obtains a `Period` representing a number of days.
+    ///
+    /// # Parameters
+    ///  - `days`: the days, which may be negative.
+    pub const fn of_days(days: i32) -> Period {
+        Period::of(0, 0, days)
+    }
+
+    /// Obtains the `Period` between two dates, as whole years, then months, then days.
+    ///
+    /// The start is included and the end is not. The period is negative if the end is before the start,
+    /// and all the parts have the same sign, so '2007-01-31' to '2007-03-01' is 'P1M1D'.
+    ///
+    /// # Parameters
+    ///  - `start`: the start date, inclusive.
+    ///  - `end`: the end date, exclusive.
+    pub const fn between(start: LocalDate, end: LocalDate) -> Period {
+        let start_months = start.year() * MONTHS_IN_YEAR + start.month_value() as i64;
+        let end_months = end.year() * MONTHS_IN_YEAR + end.month_value() as i64;
+        let mut total_months = end_months - start_months;
+        let mut days = end.day_of_month() as i64 - start.day_of_month() as i64;
+        if total_months > 0 && days < 0 {
+            total_months -= 1;
+            days = end.to_epoch_day() - start.plus_months(total_months).to_epoch_day();
+        } else if total_months < 0 && days > 0 {
+            total_months += 1;
+            days -= end.length_of_month() as i64;
+        }
+        // Dates are less than 2 billion years apart, so this can't overflow.
+        Period::of(
+            (total_months / MONTHS_IN_YEAR) as i32,
+            (total_months % MONTHS_IN_YEAR) as i32,
+            days as i32,
+        )
+    }
+
+    /// Gets the years in this period.
+    pub const fn years(&self) -> i32 {
+        self.years
+    }
+
+    /// Gets the months in this period, which are not normalized into years.
+    pub const fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Gets the days in this period, which are not normalized into months.
+    pub const fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Checks if all the parts of this period are zero.
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Checks if any of the parts of this period are negative.
+    pub const fn is_negative(&self) -> bool {
+        self.years < 0 || self.months < 0 || self.days < 0
+    }
+
+    /// Gets the total number of months in this period, counting each year as 12 months and leaving out the days.
+    pub const fn to_total_months(&self) -> i64 {
+        self.years as i64 * MONTHS_IN_YEAR + self.months as i64
+    }
+
+    /// Returns a copy of this period with the months normalized into years, so 'P1Y15M' is 'P2Y3M'.
+    ///
+    /// The years and months are given the same sign, and the days are left unchanged.
+    ///
+    /// # Panics
+    /// - if the years would overflow the period.
+    pub const fn normalized(&self) -> Period {
+        const_expect!(self.normalized_checked(), "years would overflow period")
+    }
+
+    const fn normalized_checked(&self) -> Option<Period> {
+        let total_months = self.to_total_months();
+        let years = total_months / MONTHS_IN_YEAR;
+        if years < i32::MIN as i64 || years > i32::MAX as i64 {
+            return None;
+        }
+        Some(Period::of(
+            years as i32,
+            (total_months % MONTHS_IN_YEAR) as i32,
+            self.days,
+        ))
+    }
+
+    /// Returns a copy of this period with another added, part by part.
+    ///
+    /// # Parameters
+    ///  - `other`: the period to add.
+    ///
+    /// # Panics
+    /// - if any part would overflow the period.
+    pub const fn plus(&self, other: Period) -> Period {
+        const_expect!(
+            match (
+                self.years.checked_add(other.years),
+                self.months.checked_add(other.months),
+                self.days.checked_add(other.days),
+            ) {
+                (Some(years), Some(months), Some(days)) => Some(Period::of(years, months, days)),
+                _ => None,
+            },
+            "period overflow"
+        )
+    }
+
+    /// Returns a copy of this period with another subtracted, part by part.
+    ///
+    /// # Parameters
+    ///  - `other`: the period to subtract.
+    ///
+    /// # Panics
+    /// - if any part would overflow the period.
+    pub const fn minus(&self, other: Period) -> Period {
+        const_expect!(
+            match (
+                self.years.checked_sub(other.years),
+                self.months.checked_sub(other.months),
+                self.days.checked_sub(other.days),
+            ) {
+                (Some(years), Some(months), Some(days)) => Some(Period::of(years, months, days)),
+                _ => None,
+            },
+            "period overflow"
+        )
+    }
+
+    /// Returns a copy of this period with each part multiplied by a scalar.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply by.
+    ///
+    /// # Panics
+    /// - if any part would overflow the period.
+    pub const fn multiplied_by(&self, scalar: i32) -> Period {
+        const_expect!(self.multiplied_by_checked(scalar), "period overflow")
+    }
+
+    /// Returns a copy of this period with each part negated.
+    ///
+    /// # Panics
+    /// - if any part is `i32::MIN`.
+    pub const fn negated(&self) -> Period {
+        const_expect!(self.negated_checked(), "period overflow")
+    }
+
+    pub(crate) const fn negated_checked(&self) -> Option<Period> {
+        self.multiplied_by_checked(-1)
+    }
+
+    const fn multiplied_by_checked(&self, scalar: i32) -> Option<Period> {
+        match (
+            self.years.checked_mul(scalar),
+            self.months.checked_mul(scalar),
+            self.days.checked_mul(scalar),
+        ) {
+            (Some(years), Some(months), Some(days)) => Some(Period::of(years, months, days)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    /// Formats this period in ISO-8601 format, such as 'P6Y3M1D'.
+    ///
+    /// Parts that are zero are left out, and each part carries its own sign, such as 'P-1M2D'.
+    /// A zero period is 'P0D'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "P0D");
+        }
+        write!(f, "P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Period {
+    type Err = ParseIsoError;
+
+    /// Parses an ISO-8601 period of the form `PnYnMnWnD`, such as 'P1Y2M3D' or 'P2W'.
+    ///
+    /// Weeks are added to the days as seven days each.
+    /// Each amount, and the whole period, may be signed, and letters are case insensitive.
+    fn from_str(text: &str) -> Result<Period, ParseIsoError> {
+        let mut parser = Parser::of(text);
+        let negative = parser.sign() == Some(true);
+        parser.expect(b'P', "expected 'P'")?;
+
+        let mut units: &[u8] = b"YMWD";
+        // The years, months, weeks and days.
+        let mut amounts = [0i64; 4];
+        let mut count = 0;
+        while parser.peek().is_some() {
+            let start = parser.position();
+            let amount_negative = parser.sign() == Some(true);
+            let (value, _) = parser.digits(1, 19, "invalid amount")?;
+            let index = units
+                .iter()
+                .position(|&letter| parser.eat(letter))
+                .ok_or_else(|| parser.error("expected a unit"))?;
+            let unit = b"YMWD".len() - units.len() + index;
+            units = &units[index + 1..];
+
+            let value = if amount_negative { -value } else { value };
+            let value = if negative { -value } else { value };
+            if value < i32::MIN as i64 || value > i32::MAX as i64 {
+                return Err(parser.error_at(start, "amount out of range"));
+            }
+            amounts[unit] = value;
+            count += 1;
+        }
+        if count == 0 {
+            return Err(parser.error("expected an amount"));
+        }
+
+        let [years, months, weeks, days] = amounts;
+        let days = weeks * DAYS_IN_WEEK_ISO + days;
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return Err(parser.error_at(0, "period out of range"));
+        }
+        Ok(Period::of(years as i32, months as i32, days as i32))
+    }
+}
@@ -0,0 +1,140 @@
+use proptest::prelude::*;
+
+use crate::assert::expect_panic;
+use crate::{LocalDate, Period};
+
+fn date_strategy() -> impl Strategy<Value = LocalDate> {
+    (-100_000..=100_000i64).prop_map(|epoch_day| LocalDate::EPOCH.plus_days(epoch_day))
+}
+
+#[test]
+fn of() {
+    assert_eq!(Period::of(1, 0, 0), Period::of_years(1));
+    assert_eq!(Period::of(0, 1, 0), Period::of_months(1));
+    assert_eq!(Period::of(0, 0, 14), Period::of_weeks(2));
+    assert_eq!(Period::of(0, 0, -1), Period::of_days(-1));
+    assert!(Period::ZERO.is_zero());
+    assert!(!Period::of(0, 0, 1).is_zero());
+    assert!(Period::of(1, -1, 0).is_negative());
+    assert!(!Period::of(1, 1, 0).is_negative());
+}
+
+#[test]
+fn of_weeks_overflow() {
+    expect_panic("weeks would overflow period", || {
+        Period::of_weeks(i32::MAX / 7 + 1)
+    })
+    .unwrap();
+}
+
+#[test]
+fn between() {
+    let between = |start: (i64, u32, u32), end: (i64, u32, u32)| {
+        Period::between(
+            LocalDate::of(start.0, start.1, start.2),
+            LocalDate::of(end.0, end.1, end.2),
+        )
+    };
+
+    assert_eq!(Period::of(0, 1, 1), between((2007, 1, 31), (2007, 3, 1)));
+    assert_eq!(Period::of(1, 2, 3), between((2007, 1, 1), (2008, 3, 4)));
+    assert_eq!(Period::of(0, 0, 29), between((2008, 1, 31), (2008, 2, 29)));
+    assert_eq!(Period::of(0, 11, 0), between((2007, 1, 31), (2007, 12, 31)));
+    assert_eq!(Period::of(0, -1, -1), between((2007, 3, 1), (2007, 1, 31)));
+    assert_eq!(Period::of(-1, -2, -3), between((2008, 3, 4), (2007, 1, 1)));
+    assert_eq!(Period::ZERO, between((2007, 1, 1), (2007, 1, 1)));
+    assert_eq!(
+        Period::of(1_999_999_998, 11, 30),
+        Period::between(LocalDate::MIN, LocalDate::MAX)
+    );
+}
+
+proptest! {
+    #[test]
+    fn between_adds_back(start in date_strategy(), end in date_strategy()) {
+        let period = Period::between(start, end);
+
+        // Clamping to the end of a month can't be undone, so this only holds going forwards.
+        if start <= end {
+            prop_assert_eq!(end, start.plus_period(period));
+        }
+        prop_assert!(period.months().abs() < 12);
+        prop_assert!(
+            (period.years() >= 0 && period.months() >= 0 && period.days() >= 0)
+                || (period.years() <= 0 && period.months() <= 0 && period.days() <= 0)
+        );
+    }
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(Period::of(2, 3, 40), Period::of(1, 15, 40).normalized());
+    assert_eq!(Period::of(0, 11, 0), Period::of(1, -1, 0).normalized());
+    assert_eq!(Period::of(-1, -3, 5), Period::of(0, -15, 5).normalized());
+    assert_eq!(27, Period::of(2, 3, 40).to_total_months());
+    expect_panic("years would overflow period", || {
+        Period::of(i32::MAX, 12, 0).normalized()
+    })
+    .unwrap();
+}
+
+#[test]
+fn arithmetic() {
+    let period = Period::of(1, 2, 3);
+
+    assert_eq!(Period::of(2, 4, 6), period.plus(period));
+    assert_eq!(Period::of(0, 1, 2), period.minus(Period::of(1, 1, 1)));
+    assert_eq!(Period::of(3, 6, 9), period.multiplied_by(3));
+    assert_eq!(Period::of(-1, -2, -3), period.negated());
+    expect_panic("period overflow", || {
+        Period::of_days(i32::MAX).plus(Period::of_days(1))
+    })
+    .unwrap();
+    expect_panic("period overflow", || {
+        Period::of_days(i32::MIN).minus(Period::of_days(1))
+    })
+    .unwrap();
+    expect_panic("period overflow", || {
+        Period::of_years(i32::MAX).multiplied_by(2)
+    })
+    .unwrap();
+    expect_panic("period overflow", || Period::of_months(i32::MIN).negated()).unwrap();
+}
+
+#[test]
+fn added_to_dates() {
+    let date = LocalDate::of(2007, 1, 31);
+
+    assert_eq!(
+        LocalDate::of(2007, 2, 28),
+        date.plus_period(Period::of_months(1))
+    );
+    assert_eq!(
+        LocalDate::of(2007, 3, 1),
+        date.plus_period(Period::of(0, 1, 1))
+    );
+    assert_eq!(
+        LocalDate::of(2008, 2, 29),
+        date.plus_period(Period::of(1, 1, 0))
+    );
+    assert_eq!(
+        LocalDate::of(2006, 11, 30),
+        date.minus_period(Period::of_months(2))
+    );
+    assert_eq!(
+        LocalDate::of(2007, 2, 14),
+        date.plus_period(Period::of_weeks(2))
+    );
+    assert_eq!(
+        LocalDate::of(2009, 2, 28),
+        LocalDate::of(2008, 2, 29).plus_period(Period::of_years(1))
+    );
+    expect_panic("date out of range", || {
+        LocalDate::MAX.plus_period(Period::of_days(1))
+    })
+    .unwrap();
+    expect_panic("date out of range", || {
+        LocalDate::MIN.minus_period(Period::of_months(i32::MIN))
+    })
+    .unwrap();
+}
@@ -0,0 +1,51 @@
+use proptest::prelude::*;
+
+use crate::{ParseIsoError, Period};
+
+fn error(text: &str) -> (usize, &'static str) {
+    let error: ParseIsoError = text.parse::<Period>().unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn display() {
+    assert_eq!("P0D", Period::ZERO.to_string());
+    assert_eq!("P6Y3M1D", Period::of(6, 3, 1).to_string());
+    assert_eq!("P1Y", Period::of_years(1).to_string());
+    assert_eq!("P14D", Period::of_weeks(2).to_string());
+    assert_eq!("P-1M2D", Period::of(0, -1, 2).to_string());
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Ok(Period::of(1, 2, 3)), "P1Y2M3D".parse());
+    assert_eq!(Ok(Period::of_weeks(2)), "P2W".parse());
+    assert_eq!(Ok(Period::of(0, 0, 17)), "P2W3D".parse());
+    assert_eq!(Ok(Period::of(1, 0, 14)), "p1y2w".parse());
+    assert_eq!(Ok(Period::of(0, -1, 2)), "P-1M+2D".parse());
+    assert_eq!(Ok(Period::of(-1, 1, 0)), "-P1Y-1M".parse());
+    assert_eq!(Ok(Period::ZERO), "P0D".parse());
+    assert_eq!(Ok(Period::of_years(i32::MIN)), "-P2147483648Y".parse());
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!((0, "expected 'P'"), error("1Y"));
+    assert_eq!((1, "expected an amount"), error("P"));
+    assert_eq!((2, "expected a unit"), error("P1"));
+    assert_eq!((4, "expected a unit"), error("P1M1Y"));
+    assert_eq!((2, "expected a unit"), error("P1H"));
+    assert_eq!((1, "amount out of range"), error("P2147483648Y"));
+    assert_eq!((1, "amount out of range"), error("P12345678901D"));
+    assert_eq!((1, "invalid amount"), error("P99999999999999999999D"));
+    assert_eq!((0, "period out of range"), error("P306783379W1D"));
+}
+
+proptest! {
+    #[test]
+    fn round_trip(years: i32, months: i32, days: i32) {
+        let period = Period::of(years, months, days);
+
+        prop_assert_eq!(Ok(period), period.to_string().parse());
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::iso::*;
+use crate::{Duration, Period};
+
+#[cfg(test)]
+pub mod text;
+
+/// An amount of time with both a date-based [`Period`] and a time-based [`Duration`], such as 'P1Y2M3DT4H5M6.5S'.
+///
+/// This holds any ISO-8601 duration, such as those of XML Schema `xs:duration` fields, without losing either part.
+/// The two parts are kept apart: the period is applied to a date-time first, and then the duration.
+///
+/// [`Period`]: struct.Period.html
+/// [`Duration`]: struct.Duration.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PeriodDuration {
+    period: Period,
+    duration: Duration,
+}
+
+impl PeriodDuration {
+    /// Constant for an amount of zero length.
+    pub const ZERO: PeriodDuration = PeriodDuration {
+        period: Period::ZERO,
+        duration: Duration::ZERO,
+    };
+
+    /// Obtains a `PeriodDuration` from a period and a duration.
+    ///
+    /// # Parameters
+    ///  - `period`: the date-based part.
+    ///  - `duration`: the time-based part.
+    pub const fn of(period: Period, duration: Duration) -> PeriodDuration {
+        PeriodDuration { period, duration }
+    }
+
+    /// Obtains a `PeriodDuration` from a period, with a zero duration.
+    ///
+    /// # Parameters
+    ///  - `period`: the date-based part.
+    pub const fn of_period(period: Period) -> PeriodDuration {
+        PeriodDuration::of(period, Duration::ZERO)
+    }
+
+    /// Obtains a `PeriodDuration` from a duration, with a zero period.
+    ///
+    /// # Parameters
+    ///  - `duration`: the time-based part.
+    pub const fn of_duration(duration: Duration) -> PeriodDuration {
+        PeriodDuration::of(Period::ZERO, duration)
+    }
+
+    /// Gets the date-based part.
+    pub const fn period(&self) -> Period {
+        self.period
+    }
+
+    /// Gets the time-based part.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Checks if both the period and the duration are zero.
+    pub const fn is_zero(&self) -> bool {
+        self.period.is_zero() && self.duration.seconds() == 0 && self.duration.nano() == 0
+    }
+}
+
+impl fmt::Display for PeriodDuration {
+    /// Formats this amount in ISO-8601 format, such as 'P1Y2M3DT4H5M6.5S'.
+    ///
+    /// The period is written as by `Period` and the time part as by `Duration`, leaving out whichever is zero,
+    /// so a zero amount is 'P0D'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.duration == Duration::ZERO {
+            return write!(f, "{}", self.period);
+        }
+        if self.period.is_zero() {
+            return write!(f, "{}", self.duration);
+        }
+        // Both start with 'P', which is only written once.
+        write!(f, "{}{}", self.period, &self.duration.to_string()[1..])
+    }
+}
+
+impl FromStr for PeriodDuration {
+    type Err = ParseIsoError;
+
+    /// Parses an ISO-8601 duration of the form `PnYnMnWnDTnHnMn.nS`, such as 'P1Y2M3DT4H5M6.5S'.
+    ///
+    /// The part before the 'T' is parsed as a `Period`, and the part after as a `Duration`, so only seconds may have a fraction.
+    /// Each amount, and the whole amount, may be signed, and letters are case insensitive.
+    fn from_str(text: &str) -> Result<PeriodDuration, ParseIsoError> {
+        let time = match text.find(['T', 't']) {
+            None => return text.parse().map(PeriodDuration::of_period),
+            Some(time) => time,
+        };
+        let date = &text[..time];
+        let sign = match date.as_bytes().first() {
+            Some(b'-') | Some(b'+') => &date[..1],
+            _ => "",
+        };
+
+        let period = if date[sign.len()..].eq_ignore_ascii_case("P") {
+            // Only the 'P', which the duration checks for.
+            Period::ZERO
+        } else {
+            date.parse()?
+        };
+        // The time part is parsed with the sign and 'P' in front, so it is negated with the period.
+        let prefix = sign.len() + 1;
+        let duration =
+            format!("{}P{}", sign, &text[time..])
+                .parse()
+                .map_err(|error: ParseIsoError| {
+                    let position = if error.position() < prefix {
+                        error.position()
+                    } else {
+                        error.position() - prefix + time
+                    };
+                    Parser::of(text).error_at(position, error.reason())
+                })?;
+        Ok(PeriodDuration::of(period, duration))
+    }
+}
@@ -0,0 +1,97 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{Duration, ParseIsoError, Period, PeriodDuration};
+
+fn error(text: &str) -> (usize, &'static str) {
+    let error: ParseIsoError = text.parse::<PeriodDuration>().unwrap_err();
+    (error.position(), error.reason())
+}
+
+#[test]
+fn display() {
+    assert_eq!("P0D", PeriodDuration::ZERO.to_string());
+    assert_eq!(
+        "P1Y2M3DT4H5M6.5S",
+        PeriodDuration::of(
+            Period::of(1, 2, 3),
+            Duration::of_millis(((4 * 60 + 5) * 60 + 6) * 1_000 + 500)
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "P1Y",
+        PeriodDuration::of_period(Period::of_years(1)).to_string()
+    );
+    assert_eq!(
+        "PT-1.5S",
+        PeriodDuration::of_duration(Duration::of_millis(-1_500)).to_string()
+    );
+    assert_eq!(
+        "P-1DT1H",
+        PeriodDuration::of(Period::of_days(-1), Duration::of_hours(1)).to_string()
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        Ok(PeriodDuration::of(
+            Period::of(1, 2, 3),
+            Duration::of_millis(((4 * 60 + 5) * 60 + 6) * 1_000 + 500)
+        )),
+        "P1Y2M3DT4H5M6.5S".parse()
+    );
+    assert_eq!(
+        Ok(PeriodDuration::of_period(Period::of_weeks(2))),
+        "P2W".parse()
+    );
+    assert_eq!(
+        Ok(PeriodDuration::of_duration(Duration::of_minutes(90))),
+        "PT1H30M".parse()
+    );
+    assert_eq!(
+        Ok(PeriodDuration::of(
+            Period::of_months(-1),
+            Duration::of_seconds(-SECONDS_IN_HOUR)
+        )),
+        "-P1MT1H".parse()
+    );
+    assert_eq!(
+        Ok(PeriodDuration::of(
+            Period::of_days(1),
+            Duration::of_seconds(1)
+        )),
+        "p1dt1s".parse()
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!((0, "expected 'P'"), error("T1H"));
+    assert_eq!((0, "expected 'P'"), error("X1YT1H"));
+    assert_eq!((1, "expected an amount"), error("P"));
+    assert_eq!((2, "expected an amount"), error("PT"));
+    assert_eq!((5, "expected a unit"), error("P1YT1D"));
+    assert_eq!((7, "only seconds may have a fraction"), error("P1DT1.5H"));
+    assert_eq!((2, "expected a unit"), error("P1H"));
+}
+
+proptest! {
+    #[test]
+    fn round_trip(
+        years: i32,
+        months: i32,
+        days: i32,
+        seconds in prop::num::i64::ANY,
+        nanos in 0..NANOSECONDS_IN_SECOND,
+    ) {
+        let amount = PeriodDuration::of(
+            Period::of(years, months, days),
+            Duration::of_seconds_and_adjustment(seconds, nanos),
+        );
+
+        prop_assert_eq!(Ok(amount), amount.to_string().parse());
+    }
+}
@@ -0,0 +1,204 @@
+//! Conversions to and from the `google.protobuf.Timestamp` and `google.protobuf.Duration` well-known types,
+//! enabled by the `protobuf` feature.
+//!
+//! These are plain structs with the same fields as the generated messages,
+//! so they can be copied field by field to and from whichever protobuf implementation is in use.
+
+use crate::calendar::*;
+use crate::constants::*;
+use crate::{Instant, LeapSmear, NoonToNoonSmear, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod conversions;
+
+// '0001-01-01T00:00:00Z' to '9999-12-31T23:59:59.999999999Z'.
+const MIN_TIMESTAMP_SECONDS: i64 = epoch_day_of(1, 1, 1) * SECONDS_IN_DAY;
+const MAX_TIMESTAMP_SECONDS: i64 = epoch_day_of(10_000, 1, 1) * SECONDS_IN_DAY - 1;
+// About 10,000 years.
+const MAX_DURATION_SECONDS: i64 = 315_576_000_000;
+const MAX_NANOS: i32 = NANOSECONDS_IN_SECOND as i32 - 1;
+const NANOSECONDS_IN_DAY_WIDE: i128 = NANOSECONDS_IN_DAY as i128;
+
+/// A `google.protobuf.Timestamp`, a count of seconds and nanoseconds since '1970-01-01T00:00:00Z'.
+///
+/// Timestamps count every day as 86,400 seconds, and (per the protobuf documentation) leap seconds are smeared
+/// over the 24 hours from noon to noon around them, as with [`NoonToNoonSmear`].
+/// Only the years 1 to 9999 are valid, and `nanos` must be from 0 to 999,999,999.
+/// Before the first entry of the TAI - UTC table (1961), when UTC was not yet defined,
+/// timestamps are converted using the offset of that first entry.
+///
+/// [`NoonToNoonSmear`]: ../struct.NoonToNoonSmear.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+/// A `google.protobuf.Duration`, a signed count of seconds and nanoseconds.
+///
+/// Unlike a [`Duration`](../struct.Duration.html), whose nanoseconds are always positive,
+/// a negative protobuf duration has negative (or zero) `nanos`, so -1.5 seconds is `{ seconds: -1, nanos: -500_000_000 }`.
+/// Only durations up to about ±10,000 years are valid.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Duration {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl Timestamp {
+    /// Constant for the earliest valid timestamp, '0001-01-01T00:00:00Z'.
+    pub const MIN: Timestamp = Timestamp {
+        seconds: MIN_TIMESTAMP_SECONDS,
+        nanos: 0,
+    };
+
+    /// Constant for the latest valid timestamp, '9999-12-31T23:59:59.999999999Z'.
+    pub const MAX: Timestamp = Timestamp {
+        seconds: MAX_TIMESTAMP_SECONDS,
+        nanos: MAX_NANOS,
+    };
+
+    /// Checks if the fields are within the ranges allowed by the protobuf definition.
+    pub const fn is_valid(&self) -> bool {
+        self.seconds >= MIN_TIMESTAMP_SECONDS
+            && self.seconds <= MAX_TIMESTAMP_SECONDS
+            && self.nanos >= 0
+            && self.nanos <= MAX_NANOS
+    }
+
+    /// Converts an instant to the (smeared) timestamp.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to convert.
+    ///  - `table`: the table the leap seconds are taken from.
+    ///
+    /// # Returns
+    /// The timestamp, or `None` if UTC is not defined at that instant, or the timestamp would not be valid.
+    pub fn of_instant(instant: Instant, table: &TaiUtcTable) -> Option<Timestamp> {
+        let tai_nanos = instant.to_epoch_nano();
+        let smeared = match first_offset(table) {
+            Some((first_day, offset)) if tai_nanos < epoch_nanos_of_day(first_day) + offset => {
+                let utc_nanos = tai_nanos - offset;
+                UtcInstant::of_modified_julian_day(
+                    utc_nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE) as i64
+                        + MODIFIED_JULIAN_DAY_OF_EPOCH,
+                    utc_nanos.rem_euclid(NANOSECONDS_IN_DAY_WIDE) as i64,
+                )
+            }
+            _ => NoonToNoonSmear.to_smeared_utc(table, instant)?,
+        };
+        let timestamp = Timestamp {
+            seconds: (smeared.modified_julian_day() - MODIFIED_JULIAN_DAY_OF_EPOCH)
+                .checked_mul(SECONDS_IN_DAY)?
+                .checked_add(smeared.nano_of_day() / NANOSECONDS_IN_SECOND)?,
+            nanos: (smeared.nano_of_day() % NANOSECONDS_IN_SECOND) as i32,
+        };
+        if timestamp.is_valid() {
+            Some(timestamp)
+        } else {
+            None
+        }
+    }
+
+    /// Converts this (smeared) timestamp to an instant.
+    ///
+    /// # Parameters
+    ///  - `table`: the table the leap seconds are taken from.
+    ///
+    /// # Returns
+    /// The instant, or `None` if this timestamp is not valid, or UTC is not defined at that time.
+    pub fn to_instant(&self, table: &TaiUtcTable) -> Option<Instant> {
+        if !self.is_valid() {
+            return None;
+        }
+        let smeared = UtcInstant::of_modified_julian_day(
+            self.seconds.div_euclid(SECONDS_IN_DAY) + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            self.seconds.rem_euclid(SECONDS_IN_DAY) * NANOSECONDS_IN_SECOND + self.nanos as i64,
+        );
+        match first_offset(table) {
+            Some((first_day, offset)) if smeared.modified_julian_day() < first_day => {
+                let utc_nanos =
+                    self.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + self.nanos as i128;
+                Instant::try_of_epoch_nano(utc_nanos + offset).ok()
+            }
+            _ => NoonToNoonSmear.to_instant(table, smeared),
+        }
+    }
+}
+
+// The first day of the table, as a Modified Julian Day, and the nanoseconds of TAI - UTC at its start.
+// UTC is not defined before then, so timestamps there use this offset.
+fn first_offset(table: &TaiUtcTable) -> Option<(i64, i128)> {
+    let first_day = table.entries().first()?.modified_julian_day();
+    let offset = table.tai_minus_utc(UtcInstant::of_modified_julian_day(first_day, 0))?;
+    Some((first_day, offset.to_total_nanos()))
+}
+
+// The nanoseconds from the epoch to the start of the given Modified Julian Day, counting every day as 86,400 seconds.
+fn epoch_nanos_of_day(modified_julian_day: i64) -> i128 {
+    (modified_julian_day - MODIFIED_JULIAN_DAY_OF_EPOCH) as i128 * NANOSECONDS_IN_DAY_WIDE
+}
+
+impl Duration {
+    /// Constant for the greatest negative valid duration.
+    pub const MIN: Duration = Duration {
+        seconds: -MAX_DURATION_SECONDS,
+        nanos: -MAX_NANOS,
+    };
+
+    /// Constant for the greatest positive valid duration.
+    pub const MAX: Duration = Duration {
+        seconds: MAX_DURATION_SECONDS,
+        nanos: MAX_NANOS,
+    };
+
+    /// Checks if the fields are within the ranges allowed by the protobuf definition,
+    /// including that the seconds and nanos do not have opposite signs.
+    pub const fn is_valid(&self) -> bool {
+        self.seconds >= -MAX_DURATION_SECONDS
+            && self.seconds <= MAX_DURATION_SECONDS
+            && self.nanos >= -MAX_NANOS
+            && self.nanos <= MAX_NANOS
+            && !(self.seconds < 0 && self.nanos > 0)
+            && !(self.seconds > 0 && self.nanos < 0)
+    }
+
+    /// Converts a duration to the protobuf duration.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to convert.
+    ///
+    /// # Returns
+    /// The protobuf duration, or `None` if it would not be valid.
+    pub const fn of_duration(duration: crate::Duration) -> Option<Duration> {
+        let (seconds, nanos) = if duration.seconds() < 0 && duration.nano() > 0 {
+            (
+                duration.seconds() + 1,
+                duration.nano() as i32 - NANOSECONDS_IN_SECOND as i32,
+            )
+        } else {
+            (duration.seconds(), duration.nano() as i32)
+        };
+        let converted = Duration { seconds, nanos };
+        if converted.is_valid() {
+            Some(converted)
+        } else {
+            None
+        }
+    }
+
+    /// Converts this protobuf duration to a duration.
+    ///
+    /// # Returns
+    /// The duration, or `None` if this protobuf duration is not valid.
+    pub const fn to_duration(&self) -> Option<crate::Duration> {
+        if self.is_valid() {
+            Some(crate::Duration::of_seconds_and_adjustment(
+                self.seconds,
+                self.nanos as i64,
+            ))
+        } else {
+            None
+        }
+    }
+}
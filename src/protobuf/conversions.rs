@@ -0,0 +1,239 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::protobuf::{Duration, Timestamp};
+use crate::{Instant, TaiUtcTable};
+
+// '2017-01-01T00:00:00Z', the end of the last leap second.
+const AFTER_LEAP_SECOND: i64 = 1_483_228_800;
+
+#[test]
+fn timestamp_constants() {
+    assert_eq!(-62_135_596_800, Timestamp::MIN.seconds);
+    assert_eq!(253_402_300_799, Timestamp::MAX.seconds);
+    assert_eq!(999_999_999, Timestamp::MAX.nanos);
+}
+
+#[test]
+fn timestamp_is_valid() {
+    assert!(Timestamp::MIN.is_valid());
+    assert!(Timestamp::MAX.is_valid());
+    assert!(Timestamp::default().is_valid());
+    assert!(!Timestamp {
+        seconds: Timestamp::MIN.seconds - 1,
+        nanos: 0
+    }
+    .is_valid());
+    assert!(!Timestamp {
+        seconds: Timestamp::MAX.seconds + 1,
+        nanos: 0
+    }
+    .is_valid());
+    assert!(!Timestamp {
+        seconds: 0,
+        nanos: -1
+    }
+    .is_valid());
+    assert!(!Timestamp {
+        seconds: 0,
+        nanos: 1_000_000_000
+    }
+    .is_valid());
+}
+
+#[test]
+fn timestamp_outside_leap_seconds() {
+    let table = TaiUtcTable::default();
+    // '2007-12-03T10:15:30.5Z', when TAI - UTC was 33 seconds.
+    let timestamp = Timestamp {
+        seconds: 1_196_676_930,
+        nanos: 500_000_000,
+    };
+    let instant = Instant::of_epoch_milli(1_196_676_963_500);
+
+    assert_eq!(Some(instant), timestamp.to_instant(&table));
+    assert_eq!(Some(timestamp), Timestamp::of_instant(instant, &table));
+}
+
+#[test]
+fn timestamp_smears_leap_second() {
+    let table = TaiUtcTable::default();
+    // The instant the leap second '2016-12-31T23:59:60Z' started, halfway through the smear.
+    let leap_second = Instant::of_epoch_second(AFTER_LEAP_SECOND + 36);
+
+    let timestamp = Timestamp::of_instant(leap_second, &table).unwrap();
+
+    assert_eq!(AFTER_LEAP_SECOND - 1, timestamp.seconds);
+    assert!(timestamp.nanos >= 500_000_000 && timestamp.nanos < 500_010_000);
+    // Noon to noon, the smeared clock advances 86,400 seconds in 86,401 real seconds.
+    let noon_before = Timestamp {
+        seconds: AFTER_LEAP_SECOND - SECONDS_IN_DAY / 2,
+        nanos: 0,
+    };
+    let noon_after = Timestamp {
+        seconds: AFTER_LEAP_SECOND + SECONDS_IN_DAY / 2,
+        nanos: 0,
+    };
+    assert_eq!(
+        SECONDS_IN_DAY + 1,
+        noon_after.to_instant(&table).unwrap().epoch_second()
+            - noon_before.to_instant(&table).unwrap().epoch_second()
+    );
+}
+
+#[test]
+fn timestamp_out_of_range() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        None,
+        Timestamp {
+            seconds: 0,
+            nanos: -1
+        }
+        .to_instant(&table)
+    );
+    assert_eq!(None, Timestamp::of_instant(Instant::MAX, &table));
+    assert_eq!(None, Timestamp::of_instant(Instant::MIN, &table));
+    assert!(Timestamp::MAX.to_instant(&table).is_some());
+}
+
+#[test]
+fn timestamp_before_utc() {
+    let table = TaiUtcTable::default();
+    // UTC was not defined before 1961, so the first offset of TAI from UTC, 1.422818 seconds, is used.
+    let offset = 1_422_818_000;
+    // '1957-10-04T19:28:34Z'.
+    let sputnik = Timestamp {
+        seconds: -386_310_686,
+        nanos: 0,
+    };
+
+    for &timestamp in &[Timestamp::MIN, sputnik] {
+        let instant = Instant::of_epoch_nano(
+            timestamp.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + offset,
+        );
+
+        assert_eq!(Some(instant), timestamp.to_instant(&table));
+        assert_eq!(Some(timestamp), Timestamp::of_instant(instant, &table));
+    }
+    // The offset carries on across the start of the table.
+    let start = Timestamp {
+        seconds: -283_996_800,
+        nanos: 0,
+    };
+    let just_before = Timestamp {
+        seconds: start.seconds - 1,
+        nanos: 999_999_999,
+    };
+    assert_eq!(
+        Some(Instant::of_epoch_nano(
+            start.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + offset
+        )),
+        start.to_instant(&table)
+    );
+    assert_eq!(
+        Some(Instant::of_epoch_nano(
+            start.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + offset - 1
+        )),
+        just_before.to_instant(&table)
+    );
+}
+
+proptest! {
+    #[test]
+    fn timestamp_round_trip(seconds in Timestamp::MIN.seconds..Timestamp::MAX.seconds, nanos in 0..NANOSECONDS_IN_SECOND as i32) {
+        let table = TaiUtcTable::default();
+        let timestamp = Timestamp { seconds, nanos };
+
+        let instant = timestamp.to_instant(&table).unwrap();
+        let round_trip = Timestamp::of_instant(instant, &table).unwrap();
+
+        // Smearing rounds to the nanosecond.
+        let difference = (round_trip.seconds - seconds) * NANOSECONDS_IN_SECOND + (round_trip.nanos - nanos) as i64;
+        prop_assert!(difference.abs() <= 1);
+    }
+}
+
+#[test]
+fn duration_sign_convention() {
+    assert_eq!(
+        Some(Duration {
+            seconds: -1,
+            nanos: -500_000_000
+        }),
+        Duration::of_duration(crate::Duration::of_millis(-1_500))
+    );
+    assert_eq!(
+        Some(Duration {
+            seconds: 0,
+            nanos: -1
+        }),
+        Duration::of_duration(crate::Duration::of_nanos(-1))
+    );
+    assert_eq!(
+        Some(Duration {
+            seconds: 1,
+            nanos: 500_000_000
+        }),
+        Duration::of_duration(crate::Duration::of_millis(1_500))
+    );
+    assert_eq!(
+        Some(crate::Duration::of_millis(-1_500)),
+        Duration {
+            seconds: -1,
+            nanos: -500_000_000
+        }
+        .to_duration()
+    );
+}
+
+#[test]
+fn duration_is_valid() {
+    assert!(Duration::MIN.is_valid());
+    assert!(Duration::MAX.is_valid());
+    assert!(!Duration {
+        seconds: -1,
+        nanos: 1
+    }
+    .is_valid());
+    assert!(!Duration {
+        seconds: 1,
+        nanos: -1
+    }
+    .is_valid());
+    assert!(!Duration {
+        seconds: 0,
+        nanos: 1_000_000_000
+    }
+    .is_valid());
+    assert!(!Duration {
+        seconds: Duration::MAX.seconds + 1,
+        nanos: 0
+    }
+    .is_valid());
+    assert_eq!(
+        None,
+        Duration {
+            seconds: -1,
+            nanos: 1
+        }
+        .to_duration()
+    );
+    assert_eq!(None, Duration::of_duration(crate::Duration::MAX));
+    assert_eq!(None, Duration::of_duration(crate::Duration::MIN));
+}
+
+proptest! {
+    #[test]
+    fn duration_round_trip(seconds in -Duration::MAX.seconds..=Duration::MAX.seconds, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = crate::Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        match Duration::of_duration(duration) {
+            Some(converted) => prop_assert_eq!(Some(duration), converted.to_duration()),
+            // Only the very largest durations are out of range.
+            None => prop_assert_eq!(Duration::MAX.seconds, seconds),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::convert::TryFrom;
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::{ChronoField, DateTimeError, Instant, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod conversions;
+
+const TIMESTAMP_OCTETS: usize = 10;
+const SECONDS_OCTETS: usize = 6;
+
+/// An IEEE 1588 (PTP) timestamp, a 48 bit count of seconds and a 32 bit count of nanoseconds.
+///
+/// PTP counts TAI seconds since '1970-01-01 00:00:00 TAI', the same epoch as [`Instant`](struct.Instant.html),
+/// so conversions to and from instants are exact and need no leap second table.
+/// Clocks announce the current offset of UTC from TAI (`currentUtcOffset`) alongside, which is what
+/// [`of_utc`](#method.of_utc) and [`to_utc`](#method.to_utc) take.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PtpTimestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl PtpTimestamp {
+    /// The largest number of seconds that fits in 48 bits.
+    pub const MAX_SECONDS: u64 = (1 << 48) - 1;
+
+    /// Obtains a timestamp from the seconds and nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds since '1970-01-01 00:00:00 TAI'.
+    ///  - `nanoseconds`: the nanosecond of the second.
+    ///
+    /// # Panics
+    /// - if the seconds do not fit in 48 bits.
+    /// - if the nanoseconds are not less than a second.
+    pub const fn of(seconds: u64, nanoseconds: u32) -> PtpTimestamp {
+        if seconds > PtpTimestamp::MAX_SECONDS {
+            panic!("seconds out of range");
+        }
+        if nanoseconds as i64 >= NANOSECONDS_IN_SECOND {
+            panic!("nanoseconds out of range");
+        }
+        PtpTimestamp {
+            seconds,
+            nanoseconds,
+        }
+    }
+
+    /// Obtains a timestamp from the seconds and nanoseconds, or an error if either is out of range.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the seconds since '1970-01-01 00:00:00 TAI', which must fit in 48 bits.
+    ///  - `nanoseconds`: the nanosecond of the second.
+    pub fn try_of(seconds: u64, nanoseconds: u32) -> Result<PtpTimestamp, DateTimeError> {
+        let range = 0..=PtpTimestamp::MAX_SECONDS as i64;
+        // Seconds beyond an i64 are out of range anyway, so saturating keeps them so.
+        let value = i64::try_from(seconds).unwrap_or(i64::MAX);
+        DateTimeError::check(ChronoField::InstantSeconds, value, range)?;
+        let range = 0..=NANOSECONDS_IN_SECOND - 1;
+        DateTimeError::check(ChronoField::NanoOfSecond, nanoseconds as i64, range)?;
+        Ok(PtpTimestamp::of(seconds, nanoseconds))
+    }
+
+    /// Obtains a timestamp from its 10 octet wire format,
+    /// the big-endian seconds followed by the big-endian nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `octets`: the encoded timestamp.
+    ///
+    /// # Returns
+    /// The timestamp, or `None` if the nanoseconds are not less than a second.
+    pub const fn of_octets(octets: [u8; TIMESTAMP_OCTETS]) -> Option<PtpTimestamp> {
+        let mut seconds = 0;
+        let mut i = 0;
+        while i < SECONDS_OCTETS {
+            seconds = seconds << 8 | octets[i] as u64;
+            i += 1;
+        }
+        let nanoseconds = u32::from_be_bytes([octets[6], octets[7], octets[8], octets[9]]);
+        if nanoseconds as i64 >= NANOSECONDS_IN_SECOND {
+            None
+        } else {
+            Some(PtpTimestamp {
+                seconds,
+                nanoseconds,
+            })
+        }
+    }
+
+    /// Converts an instant to a timestamp.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to convert.
+    ///
+    /// # Returns
+    /// The timestamp, or `None` if the instant is before the epoch, or too far after it to fit in 48 bits.
+    pub const fn of_instant(instant: Instant) -> Option<PtpTimestamp> {
+        if instant.epoch_second() < 0 || instant.epoch_second() as u64 > PtpTimestamp::MAX_SECONDS {
+            None
+        } else {
+            Some(PtpTimestamp {
+                seconds: instant.epoch_second() as u64,
+                nanoseconds: instant.nano(),
+            })
+        }
+    }
+
+    /// Converts a UTC instant to a timestamp, using the offset a PTP clock announces.
+    ///
+    /// During a leap second, the offset in effect before the leap second applies.
+    ///
+    /// # Parameters
+    ///  - `utc`: the UTC instant to convert.
+    ///  - `current_utc_offset`: TAI - UTC, in seconds.
+    ///
+    /// # Returns
+    /// The timestamp, or `None` if the result is before the epoch, or too far after it to fit in 48 bits.
+    pub const fn of_utc(utc: UtcInstant, current_utc_offset: i16) -> Option<PtpTimestamp> {
+        let nanos = (utc.modified_julian_day() as i128 - MODIFIED_JULIAN_DAY_OF_EPOCH as i128)
+            * NANOSECONDS_IN_DAY as i128
+            + utc.nano_of_day() as i128
+            + current_utc_offset as i128 * NANOSECONDS_IN_SECOND as i128;
+        match of_total_nanos_checked(nanos) {
+            Some((seconds, nanos)) => PtpTimestamp::of_instant(
+                Instant::of_epoch_second_and_adjustment(seconds, nanos as i64),
+            ),
+            None => None,
+        }
+    }
+
+    /// Gets the offset a PTP clock would announce at an instant, TAI - UTC in whole seconds.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to get the offset at.
+    ///  - `table`: the table giving the offset.
+    ///
+    /// # Returns
+    /// The offset, or `None` if UTC is not defined at the instant, or was not a whole number of seconds from TAI (before 1972).
+    pub fn current_utc_offset(instant: Instant, table: &TaiUtcTable) -> Option<i16> {
+        let offset = table.tai_minus_utc(table.to_utc(instant)?)?;
+        if offset.nano() != 0 {
+            None
+        } else {
+            i16::try_from(offset.seconds()).ok()
+        }
+    }
+
+    /// Gets the seconds since '1970-01-01 00:00:00 TAI'.
+    pub const fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Gets the nanosecond of the second.
+    pub const fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Gets the 10 octet wire format of this timestamp,
+    /// the big-endian seconds followed by the big-endian nanoseconds.
+    pub const fn to_octets(&self) -> [u8; TIMESTAMP_OCTETS] {
+        let seconds = self.seconds.to_be_bytes();
+        let nanoseconds = self.nanoseconds.to_be_bytes();
+        [
+            seconds[2],
+            seconds[3],
+            seconds[4],
+            seconds[5],
+            seconds[6],
+            seconds[7],
+            nanoseconds[0],
+            nanoseconds[1],
+            nanoseconds[2],
+            nanoseconds[3],
+        ]
+    }
+
+    /// Converts this timestamp to an instant.
+    pub const fn to_instant(&self) -> Instant {
+        Instant::of_epoch_second_and_adjustment(self.seconds as i64, self.nanoseconds as i64)
+    }
+
+    /// Converts this timestamp to UTC, using the offset a PTP clock announces.
+    ///
+    /// A single offset cannot say when a leap second happens, so the result is never during one.
+    ///
+    /// # Parameters
+    ///  - `current_utc_offset`: TAI - UTC, in seconds.
+    pub const fn to_utc(&self, current_utc_offset: i16) -> UtcInstant {
+        let nanos = total_nanos(self.seconds as i64, self.nanoseconds)
+            - current_utc_offset as i128 * NANOSECONDS_IN_SECOND as i128;
+        UtcInstant::of_modified_julian_day(
+            (nanos.div_euclid(NANOSECONDS_IN_DAY as i128) + MODIFIED_JULIAN_DAY_OF_EPOCH as i128)
+                as i64,
+            nanos.rem_euclid(NANOSECONDS_IN_DAY as i128) as i64,
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{Instant, LocalDate, PtpTimestamp, TaiUtcTable, UtcInstant};
+
+fn utc(date: LocalDate, nano_of_day: i64) -> UtcInstant {
+    UtcInstant::of_modified_julian_day(
+        date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+        nano_of_day,
+    )
+}
+
+#[test]
+fn of_instant() {
+    assert_eq!(
+        Some(PtpTimestamp::of(0, 0)),
+        PtpTimestamp::of_instant(Instant::EPOCH)
+    );
+    assert_eq!(
+        Some(PtpTimestamp::of(PtpTimestamp::MAX_SECONDS, 999_999_999)),
+        PtpTimestamp::of_instant(Instant::of_epoch_second_and_adjustment(
+            PtpTimestamp::MAX_SECONDS as i64,
+            999_999_999
+        ))
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::of_instant(Instant::of_epoch_second_and_adjustment(0, -1))
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::of_instant(Instant::of_epoch_second(
+            PtpTimestamp::MAX_SECONDS as i64 + 1
+        ))
+    );
+}
+
+#[test]
+fn of_out_of_range() {
+    expect_panic("seconds out of range", || {
+        PtpTimestamp::of(PtpTimestamp::MAX_SECONDS + 1, 0)
+    })
+    .unwrap();
+    expect_panic("nanoseconds out of range", || {
+        PtpTimestamp::of(0, 1_000_000_000)
+    })
+    .unwrap();
+}
+
+proptest! {
+    #[test]
+    fn round_trip(seconds in 0..=PtpTimestamp::MAX_SECONDS, nanoseconds in 0u32..1_000_000_000) {
+        let timestamp = PtpTimestamp::of(seconds, nanoseconds);
+
+        prop_assert_eq!(Some(timestamp), PtpTimestamp::of_instant(timestamp.to_instant()));
+        prop_assert_eq!(Some(timestamp), PtpTimestamp::of_octets(timestamp.to_octets()));
+    }
+}
+
+#[test]
+fn octets() {
+    let timestamp = PtpTimestamp::of(0x0123_4567_89AB, 0x3B9A_C9FF);
+
+    assert_eq!(
+        [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0x3B, 0x9A, 0xC9, 0xFF],
+        timestamp.to_octets()
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::of_octets([0, 0, 0, 0, 0, 0, 0x3B, 0x9A, 0xCA, 0x00])
+    );
+}
+
+#[test]
+fn current_utc_offset() {
+    let table = TaiUtcTable::default();
+    let at = |utc| table.to_instant(utc).unwrap();
+
+    assert_eq!(
+        Some(37),
+        PtpTimestamp::current_utc_offset(at(utc(LocalDate::of(2017, 1, 1), 0)), &table)
+    );
+    assert_eq!(
+        Some(36),
+        PtpTimestamp::current_utc_offset(at(utc(LocalDate::of(2016, 12, 31), 0)), &table)
+    );
+    assert_eq!(
+        Some(10),
+        PtpTimestamp::current_utc_offset(at(utc(LocalDate::of(1972, 1, 1), 0)), &table)
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::current_utc_offset(at(utc(LocalDate::of(1971, 1, 1), 0)), &table)
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::current_utc_offset(Instant::of_epoch_second(-400_000_000), &table)
+    );
+}
+
+#[test]
+fn of_utc() {
+    let table = TaiUtcTable::default();
+
+    for &utc in &[
+        utc(LocalDate::of(2017, 1, 1), 0),
+        utc(LocalDate::of(2020, 6, 15), 12_345_678_901),
+    ] {
+        assert_eq!(
+            PtpTimestamp::of_instant(table.to_instant(utc).unwrap()),
+            PtpTimestamp::of_utc(utc, 37)
+        );
+        assert_eq!(utc, PtpTimestamp::of_utc(utc, 37).unwrap().to_utc(37));
+    }
+
+    // The leap second itself, with the offset from before it.
+    let leap_second = utc(LocalDate::of(2016, 12, 31), NANOSECONDS_IN_DAY);
+    assert_eq!(
+        PtpTimestamp::of_instant(table.to_instant(leap_second).unwrap()),
+        PtpTimestamp::of_utc(leap_second, 36)
+    );
+    assert_eq!(
+        None,
+        PtpTimestamp::of_utc(utc(LocalDate::of(1970, 1, 1), 0), -1)
+    );
+}
@@ -0,0 +1,56 @@
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::ChronoUnit;
+
+/// How to round an amount to a multiple of some granularity, following java.math's `RoundingMode`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards negative infinity, so earlier in time.
+    Floor,
+    /// Round towards positive infinity, so later in time.
+    Ceiling,
+    /// Round to the nearest multiple, with ties rounded away from zero.
+    HalfUp,
+    /// Round to the nearest multiple, with ties rounded to the even multiple.
+    HalfEven,
+}
+
+// Rounds an amount to a multiple of a positive granularity.
+pub(crate) const fn round(amount: i128, granularity: i128, mode: RoundingMode) -> i128 {
+    let quotient = round_quotient(
+        amount.div_euclid(granularity),
+        amount.rem_euclid(granularity),
+        granularity,
+        mode,
+    );
+    quotient * granularity
+}
+
+// Rounds a quotient, given the non-negative remainder of the division by a positive divisor.
+pub(crate) const fn round_quotient(
+    quotient: i128,
+    remainder: i128,
+    divisor: i128,
+    mode: RoundingMode,
+) -> i128 {
+    let up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceiling => remainder > 0,
+        RoundingMode::HalfUp | RoundingMode::HalfEven if remainder * 2 != divisor => {
+            remainder * 2 > divisor
+        }
+        // Ties on the negative side are further from zero rounding down.
+        RoundingMode::HalfUp => quotient >= 0,
+        RoundingMode::HalfEven => quotient % 2 != 0,
+    };
+    quotient + up as i128
+}
+
+// The nanoseconds of a unit used for truncation, which must fit in a day.
+pub(crate) const fn truncation_nanos(unit: ChronoUnit) -> i128 {
+    let duration = unit.duration();
+    if duration.seconds() > SECONDS_IN_DAY {
+        panic!("unit is too large for truncation");
+    }
+    total_nanos(duration.seconds(), duration.nano())
+}
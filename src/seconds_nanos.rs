@@ -1,5 +1,3 @@
-use std::i64;
-
 use crate::constants::*;
 
 pub const fn of_seconds_and_adjustment_checked(
@@ -35,3 +33,25 @@ pub const fn carry_and_nanos(nanoseconds: i64) -> (i64, u32) {
         (0, nanoseconds as u32)
     }
 }
+
+pub const fn total_nanos(seconds: i64, nanos: u32) -> i128 {
+    seconds as i128 * NANOSECONDS_IN_SECOND as i128 + nanos as i128
+}
+
+pub const fn of_total_nanos_checked(total_nanos: i128) -> Option<(i64, u32)> {
+    let seconds = total_nanos.div_euclid(NANOSECONDS_IN_SECOND as i128);
+    let nanos = total_nanos.rem_euclid(NANOSECONDS_IN_SECOND as i128);
+    if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+        None
+    } else {
+        Some((seconds as i64, nanos as u32))
+    }
+}
+
+pub const fn i64_of(value: i128) -> Option<i64> {
+    if value < i64::MIN as i128 || value > i64::MAX as i128 {
+        None
+    } else {
+        Some(value as i64)
+    }
+}
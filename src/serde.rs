@@ -0,0 +1,62 @@
+//! Support for the [`serde`](https://serde.rs) framework, enabled by the `serde` feature.
+//!
+//! By default, human-readable formats (such as JSON) represent instants and durations as ISO-8601 strings,
+//! such as `"2007-12-03T10:15:30 TAI"` and `"PT8H6M12.345S"`,
+//! while binary formats represent them as `(seconds, nanos)` tuples.
+//! Dates are ISO-8601 strings such as `"2007-12-03"`, or a count of days since '1970-01-01'.
+//!
+//! The modules here pick a specific representation for a field instead:
+//!
+//! ```
+//! # use ephemeris::{Duration, Instant};
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Request {
+//!     #[serde(with = "ephemeris::serde::instant::iso_utc")]
+//!     received: Instant,
+//!     #[serde(with = "ephemeris::serde::duration::millis")]
+//!     timeout: Duration,
+//! }
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Visitor};
+
+use crate::ParseIsoError;
+
+pub mod duration;
+pub mod instant;
+mod local_date;
+
+#[cfg(test)]
+pub mod round_trips;
+
+// Deserializes a value from its text form.
+struct TextVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<T, ParseIsoError>,
+    value: PhantomData<T>,
+}
+
+impl<T> TextVisitor<T> {
+    fn of(expecting: &'static str, parse: fn(&str) -> Result<T, ParseIsoError>) -> TextVisitor<T> {
+        TextVisitor {
+            expecting,
+            parse,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for TextVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<T, E> {
+        (self.parse)(text).map_err(E::custom)
+    }
+}
@@ -0,0 +1,94 @@
+//! Representations of a [`Duration`](../../struct.Duration.html), for use with `#[serde(with = "...")]`.
+
+use ::serde::de::{Deserialize, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::Duration;
+
+impl Serialize for Duration {
+    /// Serializes as [`iso`](iso/index.html) for human-readable formats,
+    /// and as [`seconds_nanos`](seconds_nanos/index.html) otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            iso::serialize(self, serializer)
+        } else {
+            seconds_nanos::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    /// Deserializes from [`iso`](iso/index.html) for human-readable formats,
+    /// and from [`seconds_nanos`](seconds_nanos/index.html) otherwise.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        if deserializer.is_human_readable() {
+            iso::deserialize(deserializer)
+        } else {
+            seconds_nanos::deserialize(deserializer)
+        }
+    }
+}
+
+/// An ISO-8601 string, such as `"PT8H6M12.345S"`.
+pub mod iso {
+    use ::serde::de::Deserializer;
+    use ::serde::ser::Serializer;
+
+    use super::super::TextVisitor;
+    use crate::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(duration)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_str(TextVisitor::of("an ISO-8601 duration", str::parse))
+    }
+}
+
+/// A `(seconds, nanos)` tuple of the (possibly negative) seconds and the (positive) nanosecond adjustment.
+pub mod seconds_nanos {
+    use ::serde::de::{Deserialize, Deserializer, Error};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use crate::constants::*;
+    use crate::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        (duration.seconds(), duration.nano()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let (seconds, nanos) = <(i64, u32)>::deserialize(deserializer)?;
+        if nanos as i64 >= NANOSECONDS_IN_SECOND {
+            return Err(D::Error::custom("nano of second out of range"));
+        }
+        Ok(Duration::of_seconds_and_adjustment(seconds, nanos as i64))
+    }
+}
+
+/// An integer count of milliseconds.
+///
+/// Serializing truncates towards negative infinity, and fails if the count would overflow.
+pub mod millis {
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Error, Serializer};
+
+    use crate::constants::*;
+    use crate::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = duration
+            .seconds()
+            .checked_mul(MILLISECONDS_IN_SECOND)
+            .and_then(|millis| {
+                millis.checked_add(duration.nano() as i64 / NANOSECONDS_IN_MILLISECOND)
+            })
+            .ok_or_else(|| S::Error::custom("duration would overflow milliseconds"))?;
+        serializer.serialize_i64(millis)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        i64::deserialize(deserializer).map(Duration::of_millis)
+    }
+}
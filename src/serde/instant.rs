@@ -0,0 +1,178 @@
+//! Representations of an [`Instant`](../../struct.Instant.html), for use with `#[serde(with = "...")]`.
+
+use std::sync::OnceLock;
+
+use ::serde::de::{Deserialize, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::constants::*;
+use crate::iso::*;
+use crate::{Instant, ParseIsoError, TaiUtcTable, UtcInstant};
+
+impl Serialize for Instant {
+    /// Serializes as [`iso`](iso/index.html) for human-readable formats,
+    /// and as [`seconds_nanos`](seconds_nanos/index.html) otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            iso::serialize(self, serializer)
+        } else {
+            seconds_nanos::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Instant {
+    /// Deserializes from [`iso`](iso/index.html) for human-readable formats,
+    /// and from [`seconds_nanos`](seconds_nanos/index.html) otherwise.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        if deserializer.is_human_readable() {
+            iso::deserialize(deserializer)
+        } else {
+            seconds_nanos::deserialize(deserializer)
+        }
+    }
+}
+
+/// An ISO-8601 string labelled as TAI, such as `"2007-12-03T10:15:30 TAI"`.
+///
+/// This is exact, and does not depend on the leap second table.
+/// Strings labelled as UTC (`"2007-12-03T10:15:30Z"`) are also accepted when deserializing,
+/// and are converted with the built-in [`TaiUtcTable`](../../../struct.TaiUtcTable.html).
+pub mod iso {
+    use ::serde::de::Deserializer;
+    use ::serde::ser::Serializer;
+
+    use super::super::TextVisitor;
+    use super::parse;
+    use crate::Instant;
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(instant)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        deserializer.deserialize_str(TextVisitor::of("an ISO-8601 date-time", parse))
+    }
+}
+
+/// An ISO-8601 string on the UTC time scale, such as `"2007-12-03T10:15:30Z"`.
+///
+/// Instants are converted with the built-in [`TaiUtcTable`](../../../struct.TaiUtcTable.html),
+/// so an instant during a leap second is written as `"2016-12-31T23:59:60Z"`.
+/// Serializing an instant before UTC was defined (in 1961) fails.
+/// Strings labelled as TAI (`"2007-12-03T10:15:30 TAI"`) are also accepted when deserializing.
+pub mod iso_utc {
+    use std::fmt;
+
+    use ::serde::de::Deserializer;
+    use ::serde::ser::{Error, Serializer};
+
+    use super::super::TextVisitor;
+    use super::{parse, table};
+    use crate::calendar::*;
+    use crate::constants::*;
+    use crate::iso::*;
+    use crate::{Instant, UtcInstant};
+
+    struct UtcText(UtcInstant);
+
+    impl fmt::Display for UtcText {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (year, month, day) =
+                date_of_epoch_day(self.0.modified_julian_day() - MODIFIED_JULIAN_DAY_OF_EPOCH);
+            format_date(f, year, month, day)?;
+            write!(f, "T")?;
+            format_time(
+                f,
+                self.0.nano_of_day() / NANOSECONDS_IN_SECOND,
+                (self.0.nano_of_day() % NANOSECONDS_IN_SECOND) as u32,
+            )?;
+            write!(f, "Z")
+        }
+    }
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        let utc = table()
+            .to_utc(*instant)
+            .ok_or_else(|| S::Error::custom("instant is before UTC was defined"))?;
+        serializer.collect_str(&UtcText(utc))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        deserializer.deserialize_str(TextVisitor::of("an ISO-8601 date-time", parse))
+    }
+}
+
+/// A `(seconds, nanos)` tuple of the seconds since '1970-01-01 00:00:00 TAI' and the nanosecond of the second.
+pub mod seconds_nanos {
+    use ::serde::de::{Deserialize, Deserializer, Error};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use crate::constants::*;
+    use crate::Instant;
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        (instant.epoch_second(), instant.nano()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let (seconds, nanos) = <(i64, u32)>::deserialize(deserializer)?;
+        if nanos as i64 >= NANOSECONDS_IN_SECOND {
+            return Err(D::Error::custom("nano of second out of range"));
+        }
+        Ok(Instant::of_epoch_second_and_adjustment(
+            seconds,
+            nanos as i64,
+        ))
+    }
+}
+
+/// An integer count of milliseconds since '1970-01-01 00:00:00 TAI'.
+///
+/// Serializing truncates towards the past, and fails if the count would overflow.
+pub mod epoch_millis {
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Error, Serializer};
+
+    use crate::constants::*;
+    use crate::Instant;
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = instant
+            .epoch_second()
+            .checked_mul(MILLISECONDS_IN_SECOND)
+            .and_then(|millis| {
+                millis.checked_add(instant.nano() as i64 / NANOSECONDS_IN_MILLISECOND)
+            })
+            .ok_or_else(|| S::Error::custom("instant would overflow epoch milliseconds"))?;
+        serializer.serialize_i64(millis)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        i64::deserialize(deserializer).map(Instant::of_epoch_milli)
+    }
+}
+
+fn table() -> &'static TaiUtcTable {
+    static TABLE: OnceLock<TaiUtcTable> = OnceLock::new();
+    TABLE.get_or_init(TaiUtcTable::default)
+}
+
+// Parses text labelled as either TAI or UTC.
+fn parse(text: &str) -> Result<Instant, ParseIsoError> {
+    let mut parser = Parser::of(text);
+    let (epoch_day, second_of_day, nano) = parser.date_time(true)?;
+    let label = parser.position();
+    if parser.scale()? == Scale::Tai {
+        return text.parse();
+    }
+    parser.end()?;
+
+    let utc = UtcInstant::of_modified_julian_day(
+        epoch_day + MODIFIED_JULIAN_DAY_OF_EPOCH,
+        second_of_day * NANOSECONDS_IN_SECOND + nano as i64,
+    );
+    table()
+        .to_instant(utc)
+        .ok_or_else(|| parser.error_at(label, "no such UTC instant"))
+}
@@ -0,0 +1,31 @@
+use ::serde::de::{Deserialize, Deserializer, Error};
+use ::serde::ser::{Serialize, Serializer};
+
+use super::TextVisitor;
+use crate::LocalDate;
+
+impl Serialize for LocalDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_i64(self.to_epoch_day())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LocalDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<LocalDate, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TextVisitor::of("an ISO-8601 date", str::parse))
+        } else {
+            let epoch_day = i64::deserialize(deserializer)?;
+            if epoch_day < LocalDate::MIN.to_epoch_day()
+                || epoch_day > LocalDate::MAX.to_epoch_day()
+            {
+                return Err(D::Error::custom("date out of range"));
+            }
+            Ok(LocalDate::of_epoch_day(epoch_day))
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Token,
+};
+
+use crate::{Duration, Instant, LocalDate};
+
+// '2016-12-31 23:59:60 UTC', during the last leap second.
+const LEAP_SECOND: Instant = Instant::of_epoch_second(1_483_228_836);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fields {
+    #[serde(with = "crate::serde::instant::iso")]
+    tai: Instant,
+    #[serde(with = "crate::serde::instant::iso_utc")]
+    utc: Instant,
+    #[serde(with = "crate::serde::instant::seconds_nanos")]
+    tuple: Instant,
+    #[serde(with = "crate::serde::instant::epoch_millis")]
+    millis: Instant,
+    #[serde(with = "crate::serde::duration::iso")]
+    timeout: Duration,
+    #[serde(with = "crate::serde::duration::seconds_nanos")]
+    elapsed: Duration,
+    #[serde(with = "crate::serde::duration::millis")]
+    delay: Duration,
+}
+
+#[test]
+fn instant_defaults() {
+    let instant = Instant::of_epoch_milli(1_196_676_930_500);
+
+    assert_tokens(
+        &instant.readable(),
+        &[Token::Str("2007-12-03T10:15:30.500 TAI")],
+    );
+    assert_tokens(
+        &instant.compact(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::I64(1_196_676_930),
+            Token::U32(500_000_000),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn instant_accepts_utc_label() {
+    assert_de_tokens(
+        &LEAP_SECOND.readable(),
+        &[Token::Str("2016-12-31T23:59:60Z")],
+    );
+}
+
+#[test]
+fn duration_defaults() {
+    let duration = Duration::of_millis(-1_500);
+
+    assert_tokens(&duration.readable(), &[Token::Str("PT-1.5S")]);
+    assert_tokens(
+        &duration.compact(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::I64(-2),
+            Token::U32(500_000_000),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn local_date_defaults() {
+    let date = LocalDate::of(2007, 12, 3);
+
+    assert_tokens(&date.readable(), &[Token::Str("2007-12-03")]);
+    assert_tokens(&date.compact(), &[Token::I64(13_850)]);
+}
+
+#[test]
+fn with_modules() {
+    let fields = Fields {
+        tai: LEAP_SECOND,
+        utc: LEAP_SECOND,
+        tuple: LEAP_SECOND,
+        millis: Instant::of_epoch_milli(-1),
+        timeout: Duration::of_seconds(90),
+        elapsed: Duration::of_nanos(1),
+        delay: Duration::of_millis(250),
+    };
+    let json = r#"{"tai":"2017-01-01T00:00:36 TAI","utc":"2016-12-31T23:59:60Z","tuple":[1483228836,0],"millis":-1,"timeout":"PT1M30S","elapsed":[0,1],"delay":250}"#;
+
+    assert_eq!(json, serde_json::to_string(&fields).unwrap());
+    assert_eq!(fields, serde_json::from_str(json).unwrap());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Utc(#[serde(with = "crate::serde::instant::iso_utc")] Instant);
+
+#[test]
+fn invalid() {
+    // 1957, before UTC was defined.
+    assert!(serde_json::to_string(&Utc(Instant::of_epoch_second(-400_000_000))).is_err());
+    assert!(serde_json::from_str::<Utc>(r#""1957-01-01T00:00:00Z""#).is_err());
+    // There was no leap second at the end of 2017.
+    assert!(serde_json::from_str::<Utc>(r#""2017-12-31T23:59:60Z""#).is_err());
+    assert!(serde_json::from_str::<Instant>(r#""2017-12-31T23:59:60 TAI""#).is_err());
+    assert!(serde_json::from_str::<Duration>(r#""PT1H1H""#).is_err());
+    assert!(serde_json::from_str::<LocalDate>(r#""2001-02-29""#).is_err());
+
+    assert_de_tokens_error::<Compact<Instant>>(
+        &[
+            Token::Tuple { len: 2 },
+            Token::I64(0),
+            Token::U32(1_000_000_000),
+            Token::TupleEnd,
+        ],
+        "nano of second out of range",
+    );
+}
@@ -0,0 +1,329 @@
+use crate::constants::*;
+use crate::{DateTimeError, Instant, LocalDate, TaiUtcTable, UtcInstant};
+
+#[cfg(test)]
+pub mod events;
+
+const JULIAN_DAY_OF_J2000: f64 = 2_451_545.0;
+const DAYS_IN_JULIAN_CENTURY: f64 = 36_525.0;
+// The Earth turns one degree every four minutes.
+const MINUTES_IN_DEGREE: f64 = 4.0;
+const MINUTES_AT_NOON: f64 = (MINUTES_IN_DAY / 2) as f64;
+const DEGREES_IN_RIGHT_ANGLE: f64 = 90.0;
+const STANDARD_REFRACTION: f64 = 34.0 / 60.0;
+const SOLAR_SEMIDIAMETER: f64 = 16.0 / 60.0;
+// Dip of the horizon, in degrees per square root of a metre of elevation.
+const HORIZON_DIP: f64 = 1.76 / 60.0;
+const CIVIL_TWILIGHT: f64 = 6.0;
+const NAUTICAL_TWILIGHT: f64 = 12.0;
+const ASTRONOMICAL_TWILIGHT: f64 = 18.0;
+// Refining the position of the sun at the time of the event converges quickly.
+const ITERATIONS: usize = 3;
+
+/// A location on the surface of the Earth, for calculating the times of sunrise, sunset and twilight.
+///
+/// Calculations use the NOAA solar position algorithm, which is accurate to about a minute for latitudes within ±72°,
+/// and to within ten minutes beyond that.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Observer {
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+    refraction: f64,
+}
+
+/// When the sun is above a threshold (such as the horizon) during a day.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Daylight {
+    /// The sun rises above the threshold at `start`, and sets below it again at `end`.
+    Interval { start: Instant, end: Instant },
+    /// The sun stays above the threshold all day, as during the polar day.
+    PolarDay,
+    /// The sun stays below the threshold all day, as during the polar night.
+    PolarNight,
+}
+
+impl Observer {
+    /// Obtains an observer at sea level at the given location.
+    ///
+    /// # Parameters
+    ///  - `latitude`: the latitude in degrees, from -90 (south) to 90 (north).
+    ///  - `longitude`: the longitude in degrees, from -180 (west) to 180 (east).
+    ///
+    /// # Panics
+    /// - if the latitude or longitude is out of range.
+    pub fn of(latitude: f64, longitude: f64) -> Observer {
+        match Observer::of_checked(latitude, longitude) {
+            Ok(observer) => observer,
+            Err(reason) => panic!("{}", reason),
+        }
+    }
+
+    /// Obtains an observer at sea level at the given location, or an error if the latitude or longitude is out of range.
+    ///
+    /// # Parameters
+    ///  - `latitude`: the latitude in degrees, from -90 (south) to 90 (north).
+    ///  - `longitude`: the longitude in degrees, from -180 (west) to 180 (east).
+    pub fn try_of(latitude: f64, longitude: f64) -> Result<Observer, DateTimeError> {
+        Observer::of_checked(latitude, longitude).map_err(DateTimeError::InvalidArgument)
+    }
+
+    fn of_checked(latitude: f64, longitude: f64) -> Result<Observer, &'static str> {
+        if !(-DEGREES_IN_RIGHT_ANGLE..=DEGREES_IN_RIGHT_ANGLE).contains(&latitude) {
+            return Err("latitude out of range");
+        }
+        if !(-2.0 * DEGREES_IN_RIGHT_ANGLE..=2.0 * DEGREES_IN_RIGHT_ANGLE).contains(&longitude) {
+            return Err("longitude out of range");
+        }
+        Ok(Observer {
+            latitude,
+            longitude,
+            elevation: 0.0,
+            refraction: STANDARD_REFRACTION,
+        })
+    }
+
+    /// Returns a copy of this observer at the given elevation above the surrounding terrain.
+    ///
+    /// A higher elevation lowers the visible horizon, making sunrise earlier and sunset later.
+    ///
+    /// # Parameters
+    ///  - `elevation`: the elevation in metres.
+    ///
+    /// # Panics
+    /// - if the elevation is negative or not finite.
+    pub fn with_elevation(&self, elevation: f64) -> Observer {
+        self.with_elevation_checked(elevation)
+            .expect("elevation out of range")
+    }
+
+    /// Returns a copy of this observer at the given elevation, or an error if it is negative or not finite.
+    ///
+    /// # Parameters
+    ///  - `elevation`: the elevation in metres.
+    pub fn try_with_elevation(&self, elevation: f64) -> Result<Observer, DateTimeError> {
+        self.with_elevation_checked(elevation)
+            .ok_or(DateTimeError::InvalidArgument("elevation out of range"))
+    }
+
+    fn with_elevation_checked(&self, elevation: f64) -> Option<Observer> {
+        if !(elevation >= 0.0 && elevation.is_finite()) {
+            return None;
+        }
+        Some(Observer { elevation, ..*self })
+    }
+
+    /// Returns a copy of this observer using the given atmospheric refraction at the horizon.
+    ///
+    /// The default is the standard 34 arcminutes.
+    ///
+    /// # Parameters
+    ///  - `refraction`: the refraction in degrees.
+    ///
+    /// # Panics
+    /// - if the refraction is negative or not finite.
+    pub fn with_refraction(&self, refraction: f64) -> Observer {
+        self.with_refraction_checked(refraction)
+            .expect("refraction out of range")
+    }
+
+    /// Returns a copy of this observer using the given refraction, or an error if it is negative or not finite.
+    ///
+    /// # Parameters
+    ///  - `refraction`: the refraction in degrees.
+    pub fn try_with_refraction(&self, refraction: f64) -> Result<Observer, DateTimeError> {
+        self.with_refraction_checked(refraction)
+            .ok_or(DateTimeError::InvalidArgument("refraction out of range"))
+    }
+
+    fn with_refraction_checked(&self, refraction: f64) -> Option<Observer> {
+        if !(refraction >= 0.0 && refraction.is_finite()) {
+            return None;
+        }
+        Some(Observer {
+            refraction,
+            ..*self
+        })
+    }
+
+    /// Gets the latitude in degrees.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Gets the longitude in degrees.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Gets the elevation in metres.
+    pub fn elevation(&self) -> f64 {
+        self.elevation
+    }
+
+    /// Gets the atmospheric refraction at the horizon, in degrees.
+    pub fn refraction(&self) -> f64 {
+        self.refraction
+    }
+
+    /// Calculates when the sun is highest in the sky.
+    ///
+    /// The date is the local date at the observer's longitude, so solar noon is always near the middle of it.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// The instant of solar noon, or `None` if UTC is not defined on that date.
+    pub fn solar_noon(&self, date: LocalDate, table: &TaiUtcTable) -> Option<Instant> {
+        let day = date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH;
+        let mut minutes = MINUTES_AT_NOON - MINUTES_IN_DEGREE * self.longitude;
+        for _ in 0..ITERATIONS {
+            let (_, equation_of_time) = solar_position(utc_of(day, minutes).to_julian_day());
+            minutes = MINUTES_AT_NOON - MINUTES_IN_DEGREE * self.longitude - equation_of_time;
+        }
+        instant_of(day, minutes, table)
+    }
+
+    /// Calculates when the upper edge of the sun rises above and sets below the horizon.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// Sunrise and sunset, or `None` if UTC is not defined on that date.
+    pub fn sunrise_sunset(&self, date: LocalDate, table: &TaiUtcTable) -> Option<Daylight> {
+        let depression = self.refraction + SOLAR_SEMIDIAMETER + HORIZON_DIP * self.elevation.sqrt();
+        self.daylight(date, depression, table)
+    }
+
+    /// Calculates civil dawn and dusk, when the center of the sun is 6° below the horizon.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// Dawn and dusk, or `None` if UTC is not defined on that date.
+    pub fn civil_twilight(&self, date: LocalDate, table: &TaiUtcTable) -> Option<Daylight> {
+        self.daylight(date, CIVIL_TWILIGHT, table)
+    }
+
+    /// Calculates nautical dawn and dusk, when the center of the sun is 12° below the horizon.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// Dawn and dusk, or `None` if UTC is not defined on that date.
+    pub fn nautical_twilight(&self, date: LocalDate, table: &TaiUtcTable) -> Option<Daylight> {
+        self.daylight(date, NAUTICAL_TWILIGHT, table)
+    }
+
+    /// Calculates astronomical dawn and dusk, when the center of the sun is 18° below the horizon.
+    ///
+    /// # Parameters
+    ///  - `date`: the local date.
+    ///  - `table`: the table used to convert from UTC.
+    ///
+    /// # Returns
+    /// Dawn and dusk, or `None` if UTC is not defined on that date.
+    pub fn astronomical_twilight(&self, date: LocalDate, table: &TaiUtcTable) -> Option<Daylight> {
+        self.daylight(date, ASTRONOMICAL_TWILIGHT, table)
+    }
+
+    fn daylight(&self, date: LocalDate, depression: f64, table: &TaiUtcTable) -> Option<Daylight> {
+        let day = date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH;
+        let zenith = DEGREES_IN_RIGHT_ANGLE + depression;
+
+        let crossings = self
+            .crossing(day, zenith, 1.0)
+            .and_then(|start| self.crossing(day, zenith, -1.0).map(|end| (start, end)));
+        match crossings {
+            Err(daylight) => Some(daylight),
+            Ok((start, end)) => Some(Daylight::Interval {
+                start: instant_of(day, start, table)?,
+                end: instant_of(day, end, table)?,
+            }),
+        }
+    }
+
+    // Minutes since the start of the UTC day that the sun crosses the given zenith angle,
+    // rising (for a direction of 1) or setting (for -1).
+    fn crossing(&self, day: i64, zenith: f64, direction: f64) -> Result<f64, Daylight> {
+        let mut minutes = MINUTES_AT_NOON - MINUTES_IN_DEGREE * self.longitude;
+        for _ in 0..ITERATIONS {
+            let (declination, equation_of_time) =
+                solar_position(utc_of(day, minutes).to_julian_day());
+            let hour_angle = self.hour_angle(declination, zenith)?;
+            minutes = MINUTES_AT_NOON
+                - MINUTES_IN_DEGREE * (self.longitude + direction * hour_angle)
+                - equation_of_time;
+        }
+        Ok(minutes)
+    }
+
+    fn hour_angle(&self, declination: f64, zenith: f64) -> Result<f64, Daylight> {
+        let latitude = self.latitude.to_radians();
+        let declination = declination.to_radians();
+        let cos_hour_angle = (zenith.to_radians().cos() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+
+        if cos_hour_angle > 1.0 {
+            Err(Daylight::PolarNight)
+        } else if cos_hour_angle < -1.0 {
+            Err(Daylight::PolarDay)
+        } else {
+            Ok(cos_hour_angle.acos().to_degrees())
+        }
+    }
+}
+
+// The UTC instant some minutes after the start of a Modified Julian Day, which may run into the day before or after.
+fn utc_of(day: i64, minutes: f64) -> UtcInstant {
+    let nanos = (minutes * NANOSECONDS_IN_MINUTE as f64).round() as i64;
+    UtcInstant::of_modified_julian_day(
+        day + nanos.div_euclid(NANOSECONDS_IN_DAY),
+        nanos.rem_euclid(NANOSECONDS_IN_DAY),
+    )
+}
+
+fn instant_of(day: i64, minutes: f64, table: &TaiUtcTable) -> Option<Instant> {
+    table.to_instant(utc_of(day, minutes))
+}
+
+fn solar_position(julian_day: f64) -> (f64, f64) {
+    let t = (julian_day - JULIAN_DAY_OF_J2000) / DAYS_IN_JULIAN_CENTURY;
+
+    let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let anomaly = mean_anomaly.to_radians();
+    let equation_of_center = anomaly.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * anomaly).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * anomaly).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude = mean_longitude + equation_of_center - 0.00569 - 0.00478 * omega.sin();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.to_radians().sin())
+        .asin()
+        .to_degrees();
+
+    let y = (obliquity / 2.0).tan().powi(2);
+    let longitude = mean_longitude.to_radians();
+    let equation_of_time = y * (2.0 * longitude).sin() - 2.0 * eccentricity * anomaly.sin()
+        + 4.0 * eccentricity * y * anomaly.sin() * (2.0 * longitude).cos()
+        - 0.5 * y * y * (4.0 * longitude).sin()
+        - 1.25 * eccentricity * eccentricity * (2.0 * anomaly).sin();
+
+    (
+        declination,
+        MINUTES_IN_DEGREE * equation_of_time.to_degrees(),
+    )
+}
@@ -0,0 +1,164 @@
+use crate::constants::*;
+
+use crate::assert::expect_panic;
+use crate::{Daylight, Instant, LocalDate, Observer, TaiUtcTable, UtcInstant};
+
+const LONDON: (f64, f64) = (51.5074, -0.1278);
+const TROMSO: (f64, f64) = (69.6496, 18.9560);
+
+// The instant of the given UTC time on the given date.
+fn utc(date: LocalDate, hour: i64, minute: i64) -> Instant {
+    TaiUtcTable::default()
+        .to_instant(UtcInstant::of_modified_julian_day(
+            date.to_epoch_day() + MODIFIED_JULIAN_DAY_OF_EPOCH,
+            (hour * MINUTES_IN_HOUR + minute) * SECONDS_IN_MINUTE * NANOSECONDS_IN_SECOND,
+        ))
+        .unwrap()
+}
+
+fn assert_within_minutes(minutes: i64, expected: Instant, actual: Instant) {
+    let difference = (expected.epoch_second() - actual.epoch_second()).abs();
+    assert!(
+        difference <= minutes * SECONDS_IN_MINUTE,
+        "expected {:?}, was {:?}",
+        expected,
+        actual
+    );
+}
+
+fn interval(daylight: Option<Daylight>) -> (Instant, Instant) {
+    match daylight {
+        Some(Daylight::Interval { start, end }) => (start, end),
+        other => panic!("expected an interval, was {:?}", other),
+    }
+}
+
+#[test]
+fn london_midsummer() {
+    let table = TaiUtcTable::default();
+    let date = LocalDate::of(2020, 6, 21);
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    let (sunrise, sunset) = interval(observer.sunrise_sunset(date, &table));
+    let noon = observer.solar_noon(date, &table).unwrap();
+
+    assert_within_minutes(1, utc(date, 3, 43), sunrise);
+    assert_within_minutes(1, utc(date, 20, 21), sunset);
+    assert_within_minutes(1, utc(date, 12, 2), noon);
+}
+
+#[test]
+fn equator_at_equinox() {
+    let table = TaiUtcTable::default();
+    let date = LocalDate::of(2020, 3, 20);
+    let observer = Observer::of(0.0, 0.0);
+
+    let (sunrise, sunset) = interval(observer.sunrise_sunset(date, &table));
+
+    assert_within_minutes(2, utc(date, 6, 4), sunrise);
+    assert_within_minutes(2, utc(date, 18, 11), sunset);
+}
+
+#[test]
+fn twilight_is_ordered() {
+    let table = TaiUtcTable::default();
+    let date = LocalDate::of(2020, 3, 20);
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    let (sunrise, sunset) = interval(observer.sunrise_sunset(date, &table));
+    let (civil_dawn, civil_dusk) = interval(observer.civil_twilight(date, &table));
+    let (nautical_dawn, nautical_dusk) = interval(observer.nautical_twilight(date, &table));
+    let (astronomical_dawn, astronomical_dusk) =
+        interval(observer.astronomical_twilight(date, &table));
+
+    assert!(astronomical_dawn < nautical_dawn);
+    assert!(nautical_dawn < civil_dawn);
+    assert!(civil_dawn < sunrise);
+    assert!(sunset < civil_dusk);
+    assert!(civil_dusk < nautical_dusk);
+    assert!(nautical_dusk < astronomical_dusk);
+}
+
+#[test]
+fn polar_day_and_night() {
+    let table = TaiUtcTable::default();
+    let observer = Observer::of(TROMSO.0, TROMSO.1);
+
+    assert_eq!(
+        Some(Daylight::PolarDay),
+        observer.sunrise_sunset(LocalDate::of(2020, 6, 21), &table)
+    );
+    assert_eq!(
+        Some(Daylight::PolarNight),
+        observer.sunrise_sunset(LocalDate::of(2020, 12, 21), &table)
+    );
+    interval(observer.civil_twilight(LocalDate::of(2020, 12, 21), &table));
+    assert_eq!(
+        Some(Daylight::PolarNight),
+        Observer::of(90.0, 0.0).astronomical_twilight(LocalDate::of(2020, 12, 21), &table)
+    );
+}
+
+#[test]
+fn elevation_widens_daylight() {
+    let table = TaiUtcTable::default();
+    let date = LocalDate::of(2020, 3, 20);
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    let (sunrise, sunset) = interval(observer.sunrise_sunset(date, &table));
+    let (high_sunrise, high_sunset) =
+        interval(observer.with_elevation(1000.0).sunrise_sunset(date, &table));
+
+    assert!(high_sunrise < sunrise);
+    assert!(high_sunset > sunset);
+}
+
+#[test]
+fn refraction_widens_daylight() {
+    let table = TaiUtcTable::default();
+    let date = LocalDate::of(2020, 3, 20);
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    let (sunrise, sunset) = interval(observer.with_refraction(0.0).sunrise_sunset(date, &table));
+    let (refracted_sunrise, refracted_sunset) = interval(observer.sunrise_sunset(date, &table));
+
+    assert!(refracted_sunrise < sunrise);
+    assert!(refracted_sunset > sunset);
+}
+
+#[test]
+fn before_utc() {
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    assert_eq!(
+        None,
+        observer.sunrise_sunset(LocalDate::of(1900, 1, 1), &TaiUtcTable::default())
+    );
+}
+
+#[test]
+fn of_out_of_range() {
+    expect_panic("latitude out of range", || Observer::of(90.5, 0.0)).unwrap();
+    expect_panic("latitude out of range", || Observer::of(f64::NAN, 0.0)).unwrap();
+    expect_panic("longitude out of range", || Observer::of(0.0, -180.5)).unwrap();
+    expect_panic("elevation out of range", || {
+        Observer::of(0.0, 0.0).with_elevation(-1.0)
+    })
+    .unwrap();
+    expect_panic("refraction out of range", || {
+        Observer::of(0.0, 0.0).with_refraction(f64::INFINITY)
+    })
+    .unwrap();
+}
+
+#[test]
+fn extreme_dates() {
+    let table = TaiUtcTable::default();
+    let observer = Observer::of(LONDON.0, LONDON.1);
+
+    for &date in &[LocalDate::MIN, LocalDate::MAX] {
+        let noon = observer.solar_noon(date, &table);
+        let daylight = observer.sunrise_sunset(date, &table);
+        assert_eq!(noon.is_some(), daylight.is_some(), "{}", date);
+    }
+}
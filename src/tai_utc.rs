@@ -0,0 +1,400 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::calendar::*;
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::{Duration, Instant, UtcInstant};
+
+#[cfg(test)]
+pub mod conversions;
+#[cfg(test)]
+pub mod parse;
+
+const NANOSECONDS_IN_DAY_WIDE: i128 = NANOSECONDS_IN_DAY as i128;
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// The history of the offset between TAI and UTC.
+///
+/// This covers both eras of UTC:
+///  - from 1961 to 1972, when UTC ran at a rate offset from TAI (the "rubber second"),
+///    and was kept close to UT with fractional steps.
+///  - from 1972 onwards, when UTC ticks SI seconds, and is kept close to UT with whole leap seconds.
+///
+/// Each entry gives `TAI - UTC = offset + (MJD - reference MJD) × drift`, where `MJD` is the UTC Modified Julian Day,
+/// including the fraction of the day.
+///
+/// UTC is not defined before the first entry, and the last entry is assumed to remain in effect indefinitely.
+///
+/// The default table is the USNO `tai-utc.dat` history up to the leap second at the end of 2016.
+/// Other tables, such as a more recent copy of that file, can be read with [`parse`].
+///
+/// [`parse`]: struct.TaiUtcTable.html#method.parse
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TaiUtcTable {
+    entries: Vec<TaiUtcEntry>,
+}
+
+/// A single row of a [`TaiUtcTable`], in effect from the start of a UTC day until the start of the next entry.
+///
+/// [`TaiUtcTable`]: struct.TaiUtcTable.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TaiUtcEntry {
+    modified_julian_day: i64,
+    offset: i64,
+    reference_modified_julian_day: i64,
+    drift: i64,
+}
+
+/// An error that occurred while parsing a `tai-utc.dat` table.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParseTaiUtcError {
+    line: usize,
+    reason: &'static str,
+}
+
+impl TaiUtcTable {
+    /// Parses a table in the format of the USNO `tai-utc.dat` file.
+    ///
+    /// Each (non-blank) line has the form:
+    ///
+    /// ```text
+    ///  1961 JAN  1 =JD 2437300.5  TAI-UTC=   1.4228180 S + (MJD - 37300.) X 0.001296 S
+    /// ```
+    ///
+    /// The calendar date must agree with the Julian Day, and lines must be in chronological order.
+    ///
+    /// # Parameters
+    ///  - `text`: the contents of the table.
+    pub fn parse(text: &str) -> Result<TaiUtcTable, ParseTaiUtcError> {
+        let mut entries: Vec<TaiUtcEntry> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = parse_entry(line).map_err(|reason| ParseTaiUtcError {
+                line: index + 1,
+                reason,
+            })?;
+            if let Some(previous) = entries.last() {
+                if previous.modified_julian_day >= entry.modified_julian_day {
+                    return Err(ParseTaiUtcError {
+                        line: index + 1,
+                        reason: "entries are not in chronological order",
+                    });
+                }
+            }
+            entries.push(entry);
+        }
+
+        if entries.is_empty() {
+            Err(ParseTaiUtcError {
+                line: 0,
+                reason: "table has no entries",
+            })
+        } else {
+            Ok(TaiUtcTable { entries })
+        }
+    }
+
+    /// Gets the entries of this table, in chronological order.
+    pub fn entries(&self) -> &[TaiUtcEntry] {
+        &self.entries
+    }
+
+    /// Gets the length of the given UTC day.
+    ///
+    /// This is 86,400 seconds, unless the day ends with a leap second or a step adjustment.
+    ///
+    /// # Parameters
+    ///  - `modified_julian_day`: the day to get the length of.
+    ///
+    /// # Returns
+    /// The length of the day, or `None` if UTC is not defined on that day.
+    pub fn day_length(&self, modified_julian_day: i64) -> Option<Duration> {
+        self.day_length_nanos(modified_julian_day)
+            .map(|length| Duration::of_nanos(length as i64))
+    }
+
+    /// Gets the value of TAI - UTC at the given UTC instant.
+    ///
+    /// # Parameters
+    ///  - `utc`: the UTC instant to get the offset at.
+    ///
+    /// # Returns
+    /// The offset, or `None` if UTC is not defined at that instant.
+    pub fn tai_minus_utc(&self, utc: UtcInstant) -> Option<Duration> {
+        let entry = &self.entries[self.entry_index_of_day(utc.modified_julian_day())?];
+        Some(Duration::of_nanos(entry.offset_at(utc_nanos(utc)) as i64))
+    }
+
+    /// Converts a UTC instant to the (TAI) instant.
+    ///
+    /// # Parameters
+    ///  - `utc`: the UTC instant to convert.
+    ///
+    /// # Returns
+    /// The instant, or `None` if UTC is not defined at that instant,
+    /// the nano of day does not exist on that day, or the result would overflow the instant.
+    pub fn to_instant(&self, utc: UtcInstant) -> Option<Instant> {
+        let index = self.entry_index_of_day(utc.modified_julian_day())?;
+        if utc.nano_of_day() as i128 >= self.day_length_nanos(utc.modified_julian_day())? {
+            return None;
+        }
+
+        let utc_nanos = utc_nanos(utc);
+        let tai_nanos = utc_nanos + self.entries[index].offset_at(utc_nanos);
+        of_total_nanos_checked(
+            tai_nanos - MODIFIED_JULIAN_DAY_OF_EPOCH as i128 * NANOSECONDS_IN_DAY_WIDE,
+        )
+        .map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos as i64))
+    }
+
+    /// Converts an instant to a UTC instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to convert.
+    ///
+    /// # Returns
+    /// The UTC instant, or `None` if UTC is not defined at that instant.
+    pub fn to_utc(&self, instant: Instant) -> Option<UtcInstant> {
+        let tai_nanos = total_nanos(instant.epoch_second(), instant.nano())
+            + MODIFIED_JULIAN_DAY_OF_EPOCH as i128 * NANOSECONDS_IN_DAY_WIDE;
+
+        let index = self
+            .entries
+            .iter()
+            .rposition(|entry| entry.start_tai_nanos() <= tai_nanos)?;
+        let utc_nanos = self.entries[index].utc_nanos_of(tai_nanos)?;
+
+        let mut modified_julian_day = utc_nanos.div_euclid(NANOSECONDS_IN_DAY_WIDE) as i64;
+        // Instants in a leap second (or a positive step) belong to the end of the previous day.
+        if let Some(next) = self.entries.get(index + 1) {
+            if modified_julian_day >= next.modified_julian_day {
+                modified_julian_day = next.modified_julian_day - 1;
+            }
+        }
+        let nano_of_day = utc_nanos - modified_julian_day as i128 * NANOSECONDS_IN_DAY_WIDE;
+
+        Some(UtcInstant::of_modified_julian_day(
+            modified_julian_day,
+            nano_of_day as i64,
+        ))
+    }
+
+    fn entry_index_of_day(&self, modified_julian_day: i64) -> Option<usize> {
+        self.entries
+            .iter()
+            .rposition(|entry| entry.modified_julian_day <= modified_julian_day)
+    }
+
+    fn day_length_nanos(&self, modified_julian_day: i64) -> Option<i128> {
+        let index = self.entry_index_of_day(modified_julian_day)?;
+        match self.entries.get(index + 1) {
+            Some(next) if next.modified_julian_day == modified_julian_day + 1 => {
+                let end = next.modified_julian_day as i128 * NANOSECONDS_IN_DAY_WIDE;
+                Some(
+                    NANOSECONDS_IN_DAY_WIDE + next.offset_at(end)
+                        - self.entries[index].offset_at(end),
+                )
+            }
+            _ => Some(NANOSECONDS_IN_DAY_WIDE),
+        }
+    }
+}
+
+impl Default for TaiUtcTable {
+    fn default() -> TaiUtcTable {
+        TaiUtcTable::parse(include_str!("tai_utc/tai-utc.dat")).expect("built-in table is valid")
+    }
+}
+
+impl TaiUtcEntry {
+    /// Gets the first UTC day this entry is in effect, as a Modified Julian Day.
+    pub const fn modified_julian_day(&self) -> i64 {
+        self.modified_julian_day
+    }
+
+    /// Gets the offset of TAI from UTC on the reference day.
+    pub const fn offset(&self) -> Duration {
+        Duration::of_nanos(self.offset)
+    }
+
+    /// Gets the day the drift is measured from, as a Modified Julian Day.
+    pub const fn reference_modified_julian_day(&self) -> i64 {
+        self.reference_modified_julian_day
+    }
+
+    /// Gets how much the offset of TAI from UTC grows per UTC day.
+    ///
+    /// This is zero from 1972 onwards.
+    pub const fn drift(&self) -> Duration {
+        Duration::of_nanos(self.drift)
+    }
+
+    // TAI - UTC at the given UTC nanoseconds since MJD 0.
+    fn offset_at(&self, utc_nanos: i128) -> i128 {
+        let elapsed =
+            utc_nanos - self.reference_modified_julian_day as i128 * NANOSECONDS_IN_DAY_WIDE;
+        self.offset as i128 + (elapsed * self.drift as i128).div_euclid(NANOSECONDS_IN_DAY_WIDE)
+    }
+
+    fn start_tai_nanos(&self) -> i128 {
+        let start = self.modified_julian_day as i128 * NANOSECONDS_IN_DAY_WIDE;
+        start + self.offset_at(start)
+    }
+
+    // The latest UTC nanoseconds since MJD 0 that are at or before the given TAI nanoseconds since MJD 0.
+    fn utc_nanos_of(&self, tai_nanos: i128) -> Option<i128> {
+        if self.drift == 0 {
+            return Some(tai_nanos - self.offset as i128);
+        }
+        let drift = self.drift as i128;
+        let reference = self.reference_modified_julian_day as i128 * NANOSECONDS_IN_DAY_WIDE;
+        let numerator = (tai_nanos - self.offset as i128)
+            .checked_mul(NANOSECONDS_IN_DAY_WIDE)?
+            .checked_add(reference * drift)?;
+        let denominator = NANOSECONDS_IN_DAY_WIDE + drift;
+        let estimate = -(-numerator).div_euclid(denominator);
+        if estimate + self.offset_at(estimate) > tai_nanos {
+            Some(estimate - 1)
+        } else {
+            Some(estimate)
+        }
+    }
+}
+
+impl ParseTaiUtcError {
+    /// Gets the (1-based) line the error occurred on, or 0 if the error is not specific to a line.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets a description of what was wrong with the table.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseTaiUtcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid tai-utc table at line {}: {}",
+            self.line, self.reason
+        )
+    }
+}
+
+impl Error for ParseTaiUtcError {}
+
+fn utc_nanos(utc: UtcInstant) -> i128 {
+    utc.modified_julian_day() as i128 * NANOSECONDS_IN_DAY_WIDE + utc.nano_of_day() as i128
+}
+
+fn parse_entry(line: &str) -> Result<TaiUtcEntry, &'static str> {
+    let (date, rest) = split_once(line, "=JD").ok_or("missing julian day")?;
+    let (julian_day, rest) = split_once(rest, "TAI-UTC=").ok_or("missing TAI-UTC offset")?;
+    let (offset, rest) = split_once(rest, "S").ok_or("missing TAI-UTC offset")?;
+    let (_, rest) = split_once(rest, "(MJD -").ok_or("missing reference day")?;
+    let (reference, rest) = split_once(rest, ")").ok_or("missing reference day")?;
+    let (_, rest) = split_once(rest, "X").ok_or("missing drift")?;
+    let (drift, rest) = split_once(rest, "S").ok_or("missing drift")?;
+    if !rest.trim().is_empty() {
+        return Err("unexpected text after drift");
+    }
+
+    let modified_julian_day = parse_julian_day(julian_day.trim())?;
+    if parse_date(date)? != modified_julian_day {
+        return Err("date does not match julian day");
+    }
+    let reference = reference.trim();
+    let reference = reference.strip_suffix('.').unwrap_or(reference);
+    let drift = parse_nanos(drift.trim()).ok_or("invalid drift")?;
+    if drift < 0 {
+        return Err("drift must not be negative");
+    }
+
+    Ok(TaiUtcEntry {
+        modified_julian_day,
+        offset: parse_nanos(offset.trim()).ok_or("invalid TAI-UTC offset")?,
+        reference_modified_julian_day: reference.parse().map_err(|_| "invalid reference day")?,
+        drift,
+    })
+}
+
+fn split_once<'a>(text: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    text.find(delimiter)
+        .map(|index| (&text[..index], &text[index + delimiter.len()..]))
+}
+
+// "1961 JAN  1", to a Modified Julian Day.
+fn parse_date(date: &str) -> Result<i64, &'static str> {
+    let mut parts = date.split_whitespace();
+    let year: i64 = parts
+        .next()
+        .and_then(|year| year.parse().ok())
+        .ok_or("invalid year")?;
+    let month = parts
+        .next()
+        .and_then(|month| MONTH_ABBREVIATIONS.iter().position(|name| *name == month))
+        .ok_or("invalid month")? as u32
+        + 1;
+    let day: u32 = parts
+        .next()
+        .and_then(|day| day.parse().ok())
+        .ok_or("invalid day")?;
+    if parts.next().is_some() {
+        return Err("unexpected text in date");
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Err("invalid day");
+    }
+
+    Ok(epoch_day_of(year, month, day) + MODIFIED_JULIAN_DAY_OF_EPOCH)
+}
+
+// "2437300.5", to a Modified Julian Day.
+fn parse_julian_day(julian_day: &str) -> Result<i64, &'static str> {
+    julian_day
+        .strip_suffix(".5")
+        .and_then(|whole| whole.parse::<i64>().ok())
+        .map(|whole| whole - WHOLE_JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH)
+        .ok_or("invalid julian day")
+}
+
+// A decimal amount of seconds, to nanoseconds.
+fn parse_nanos(seconds: &str) -> Option<i64> {
+    let (negative, seconds) = match seconds.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, seconds),
+    };
+    let (whole, fraction) = match seconds.find('.') {
+        Some(index) => (&seconds[..index], &seconds[index + 1..]),
+        None => (seconds, ""),
+    };
+    if whole.is_empty()
+        || fraction.len() > 9
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let fraction_nanos = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(9)
+        .fold(0, |total, digit| total * 10 + (digit - b'0') as i64);
+    let nanos = whole
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(NANOSECONDS_IN_SECOND)?
+        .checked_add(fraction_nanos)?;
+
+    Some(if negative { -nanos } else { nanos })
+}
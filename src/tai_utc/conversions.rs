@@ -0,0 +1,196 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{Duration, Instant, TaiUtcTable, UtcInstant};
+
+const FIRST_UTC_DAY: i64 = 37_300;
+const LAST_LEAP_SECOND_DAY: i64 = 57_753;
+
+fn seconds_since_epoch(modified_julian_day: i64) -> i64 {
+    (modified_julian_day - MODIFIED_JULIAN_DAY_OF_EPOCH) * SECONDS_IN_DAY
+}
+
+#[test]
+fn to_instant_at_epoch_includes_drift() {
+    let table = TaiUtcTable::default();
+    let instant = table.to_instant(UtcInstant::of_modified_julian_day(
+        MODIFIED_JULIAN_DAY_OF_EPOCH,
+        0,
+    ));
+
+    // 4.2131700 + (40587 - 39126) × 0.002592
+    assert_eq!(
+        Some(Instant::of_epoch_second_and_adjustment(8, 82_000)),
+        instant
+    );
+}
+
+#[test]
+fn to_instant_after_1972_uses_whole_seconds() {
+    let table = TaiUtcTable::default();
+    let instant = table.to_instant(UtcInstant::of_modified_julian_day(41_317, 0));
+
+    assert_eq!(
+        Some(Instant::of_epoch_second(seconds_since_epoch(41_317) + 10)),
+        instant
+    );
+}
+
+#[test]
+fn to_instant_in_leap_second() {
+    let table = TaiUtcTable::default();
+    let leap_second = table.to_instant(UtcInstant::of_modified_julian_day(
+        LAST_LEAP_SECOND_DAY,
+        NANOSECONDS_IN_DAY,
+    ));
+    let next_day = table.to_instant(UtcInstant::of_modified_julian_day(
+        LAST_LEAP_SECOND_DAY + 1,
+        0,
+    ));
+
+    assert_eq!(
+        Some(Instant::of_epoch_second(
+            seconds_since_epoch(LAST_LEAP_SECOND_DAY + 1) + 36
+        )),
+        leap_second
+    );
+    assert_eq!(
+        Some(Instant::of_epoch_second(
+            seconds_since_epoch(LAST_LEAP_SECOND_DAY + 1) + 37
+        )),
+        next_day
+    );
+}
+
+#[test]
+fn to_instant_leap_second_on_ordinary_day() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        None,
+        table.to_instant(UtcInstant::of_modified_julian_day(
+            LAST_LEAP_SECOND_DAY + 1,
+            NANOSECONDS_IN_DAY
+        ))
+    );
+}
+
+#[test]
+fn to_instant_before_utc() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        None,
+        table.to_instant(UtcInstant::of_modified_julian_day(FIRST_UTC_DAY - 1, 0))
+    );
+    assert_eq!(
+        None,
+        table.to_utc(Instant::of_epoch_second(seconds_since_epoch(FIRST_UTC_DAY)))
+    );
+}
+
+#[test]
+fn to_utc_in_leap_second() {
+    let table = TaiUtcTable::default();
+    let instant = Instant::of_epoch_second_and_adjustment(
+        seconds_since_epoch(LAST_LEAP_SECOND_DAY + 1) + 36,
+        500_000_000,
+    );
+
+    let utc = table.to_utc(instant).unwrap();
+
+    assert_eq!(LAST_LEAP_SECOND_DAY, utc.modified_julian_day());
+    assert_eq!(NANOSECONDS_IN_DAY + 500_000_000, utc.nano_of_day());
+}
+
+#[test]
+fn day_length() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        Some(Duration::of_seconds(SECONDS_IN_DAY)),
+        table.day_length(50_000)
+    );
+    assert_eq!(
+        Some(Duration::of_seconds(SECONDS_IN_DAY + 1)),
+        table.day_length(LAST_LEAP_SECOND_DAY)
+    );
+    // The final step from rubber seconds to TAI - UTC = 10 s.
+    assert_eq!(
+        Some(Duration::of_seconds_and_adjustment(
+            SECONDS_IN_DAY,
+            107_758_000
+        )),
+        table.day_length(41_316)
+    );
+    // UTC skipped ahead 0.05 s at the start of August 1961.
+    assert_eq!(
+        Some(Duration::of_seconds_and_adjustment(
+            SECONDS_IN_DAY,
+            -50_000_000
+        )),
+        table.day_length(37_511)
+    );
+    assert_eq!(None, table.day_length(FIRST_UTC_DAY - 1));
+}
+
+#[test]
+fn tai_minus_utc() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(
+        Some(Duration::of_seconds(37)),
+        table.tai_minus_utc(UtcInstant::of_modified_julian_day(60_000, 0))
+    );
+    assert_eq!(
+        Some(Duration::of_nanos(1_422_818_000 + 648_000)),
+        table.tai_minus_utc(UtcInstant::of_modified_julian_day(
+            FIRST_UTC_DAY,
+            NANOSECONDS_IN_DAY / 2
+        ))
+    );
+}
+
+proptest! {
+    #[test]
+    fn utc_round_trip(modified_julian_day in FIRST_UTC_DAY..=70_000, nano_of_day in 0..NANOSECONDS_IN_DAY - NANOSECONDS_IN_SECOND) {
+        let table = TaiUtcTable::default();
+        let utc = UtcInstant::of_modified_julian_day(modified_julian_day, nano_of_day);
+
+        let instant = table.to_instant(utc).unwrap();
+
+        prop_assert_eq!(Some(utc), table.to_utc(instant));
+    }
+}
+
+proptest! {
+    #[test]
+    fn instant_round_trip(seconds in seconds_since_epoch(FIRST_UTC_DAY) + 2..seconds_since_epoch(70_000), nanos in 0..NANOSECONDS_IN_SECOND) {
+        let table = TaiUtcTable::default();
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let round_trip = table.to_instant(table.to_utc(instant).unwrap()).unwrap();
+
+        // While UTC drifted, not every nanosecond of TAI has an exact UTC counterpart.
+        let difference = (instant.epoch_second() - round_trip.epoch_second()) * NANOSECONDS_IN_SECOND
+            + instant.nano() as i64 - round_trip.nano() as i64;
+        prop_assert!((0..=1).contains(&difference), "difference of {} nanoseconds", difference);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_modified_julian_day_out_of_range(nano_of_day in NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND..=i64::MAX) {
+        crate::assert::expect_panic("nano of day out of range", || UtcInstant::of_modified_julian_day(0, nano_of_day))?;
+    }
+}
+
+#[test]
+fn to_utc_of_extremes() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(None, table.to_utc(Instant::MIN));
+    let utc = table.to_utc(Instant::MAX).unwrap();
+    assert_eq!(Some(Instant::MAX), table.to_instant(utc));
+}
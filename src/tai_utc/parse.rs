@@ -0,0 +1,109 @@
+use crate::{Duration, TaiUtcTable};
+
+const FIRST_LINE: &str =
+    " 1961 JAN  1 =JD 2437300.5  TAI-UTC=   1.4228180 S + (MJD - 37300.) X 0.001296 S";
+const LEAP_SECOND_LINE: &str =
+    " 1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S";
+
+#[test]
+fn parse_default_table() {
+    let table = TaiUtcTable::default();
+
+    assert_eq!(41, table.entries().len());
+    assert_eq!(37_300, table.entries()[0].modified_julian_day());
+    assert_eq!(
+        Duration::of_seconds(37),
+        table.entries().last().unwrap().offset()
+    );
+}
+
+#[test]
+fn parse_drift_entry() {
+    let table = TaiUtcTable::parse(FIRST_LINE).unwrap();
+    let entry = table.entries()[0];
+
+    assert_eq!(37_300, entry.modified_julian_day());
+    assert_eq!(Duration::of_nanos(1_422_818_000), entry.offset());
+    assert_eq!(37_300, entry.reference_modified_julian_day());
+    assert_eq!(Duration::of_nanos(1_296_000), entry.drift());
+}
+
+#[test]
+fn parse_unspaced_drift() {
+    let table = TaiUtcTable::parse(
+        " 1962 JAN  1 =JD 2437665.5  TAI-UTC=   1.8458580 S + (MJD - 37665.) X 0.0011232S",
+    )
+    .unwrap();
+
+    assert_eq!(Duration::of_nanos(1_123_200), table.entries()[0].drift());
+}
+
+#[test]
+fn parse_skips_blank_lines() {
+    let table = TaiUtcTable::parse(&format!("\n{}\n\n{}\n", FIRST_LINE, LEAP_SECOND_LINE)).unwrap();
+
+    assert_eq!(2, table.entries().len());
+}
+
+#[test]
+fn parse_empty() {
+    let error = TaiUtcTable::parse("\n \n").unwrap_err();
+
+    assert_eq!(0, error.line());
+    assert_eq!("table has no entries", error.reason());
+}
+
+#[test]
+fn parse_out_of_order() {
+    let error = TaiUtcTable::parse(&format!("{}\n{}", LEAP_SECOND_LINE, FIRST_LINE)).unwrap_err();
+
+    assert_eq!(2, error.line());
+    assert_eq!("entries are not in chronological order", error.reason());
+}
+
+#[test]
+fn parse_mismatched_date() {
+    let error = TaiUtcTable::parse(&FIRST_LINE.replace("JAN", "FEB")).unwrap_err();
+
+    assert_eq!(1, error.line());
+    assert_eq!("date does not match julian day", error.reason());
+}
+
+#[test]
+fn parse_invalid_julian_day() {
+    let error = TaiUtcTable::parse(&FIRST_LINE.replace("2437300.5", "2437300.0")).unwrap_err();
+
+    assert_eq!("invalid julian day", error.reason());
+}
+
+#[test]
+fn parse_invalid_offset() {
+    let error = TaiUtcTable::parse(&FIRST_LINE.replace("1.4228180", "1.42x")).unwrap_err();
+
+    assert_eq!("invalid TAI-UTC offset", error.reason());
+}
+
+#[test]
+fn parse_negative_drift() {
+    let error = TaiUtcTable::parse(&FIRST_LINE.replace("0.001296", "-0.001296")).unwrap_err();
+
+    assert_eq!("drift must not be negative", error.reason());
+}
+
+#[test]
+fn parse_missing_drift() {
+    let error =
+        TaiUtcTable::parse(" 1961 JAN  1 =JD 2437300.5  TAI-UTC=   1.4228180 S").unwrap_err();
+
+    assert_eq!("missing reference day", error.reason());
+}
+
+#[test]
+fn display() {
+    let error = TaiUtcTable::parse("nonsense").unwrap_err();
+
+    assert_eq!(
+        "invalid tai-utc table at line 1: missing julian day",
+        error.to_string()
+    );
+}
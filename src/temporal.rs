@@ -0,0 +1,775 @@
+use crate::calendar::*;
+use crate::constants::*;
+use crate::offset_date_time::MAX_OFFSET_SECONDS;
+use crate::seconds_nanos::*;
+use crate::{DateTimeError, Duration, Instant, LocalDate, OffsetDateTime, ValueRange};
+
+#[cfg(test)]
+pub mod adjustment;
+#[cfg(test)]
+pub mod fields;
+#[cfg(test)]
+pub mod units;
+
+// The average length of a year in the ISO-8601 calendar, 365.2425 days.
+const SECONDS_IN_YEAR_ESTIMATE: i64 =
+    DAYS_IN_LEAP_YEAR_EPICYCLE * SECONDS_IN_DAY / YEARS_IN_LEAP_YEAR_EPICYCLE;
+
+/// A standard field of a date, time or instant, following java.time's `ChronoField`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChronoField {
+    /// The nanosecond of the second, from 0 to 999,999,999.
+    NanoOfSecond,
+    /// The nanosecond of the day.
+    NanoOfDay,
+    /// The microsecond of the second, from 0 to 999,999.
+    MicroOfSecond,
+    /// The microsecond of the day.
+    MicroOfDay,
+    /// The millisecond of the second, from 0 to 999.
+    MilliOfSecond,
+    /// The millisecond of the day.
+    MilliOfDay,
+    /// The second of the minute, from 0 to 59 (or 60 during a leap second).
+    SecondOfMinute,
+    /// The second of the day.
+    SecondOfDay,
+    /// The minute of the hour, from 0 to 59.
+    MinuteOfHour,
+    /// The minute of the day, from 0 to 1439.
+    MinuteOfDay,
+    /// The hour of the morning or afternoon, from 0 to 11.
+    HourOfAmPm,
+    /// The hour of the morning or afternoon on a 12 hour clock, from 1 to 12.
+    ClockHourOfAmPm,
+    /// The hour of the day, from 0 to 23.
+    HourOfDay,
+    /// The hour of the day on a 24 hour clock, from 1 to 24.
+    ClockHourOfDay,
+    /// Whether the time is in the morning (0) or afternoon (1).
+    AmPmOfDay,
+    /// The ISO-8601 day of the week, from 1 (Monday) to 7 (Sunday).
+    DayOfWeek,
+    /// The day of the week, counting from 1 on the first day of the month.
+    AlignedDayOfWeekInMonth,
+    /// The day of the week, counting from 1 on the first day of the year.
+    AlignedDayOfWeekInYear,
+    /// The day of the month, from 1 to 31.
+    DayOfMonth,
+    /// The day of the year, from 1 to 366.
+    DayOfYear,
+    /// The number of days since '1970-01-01'.
+    EpochDay,
+    /// The week of the month, in weeks starting on the first day of the month.
+    AlignedWeekOfMonth,
+    /// The week of the year, in weeks starting on the first day of the year.
+    AlignedWeekOfYear,
+    /// The month of the year, from 1 (January) to 12 (December).
+    MonthOfYear,
+    /// The number of months since year 0.
+    ProlepticMonth,
+    /// The year within the era, so that the year before 1 AD is 1 BC.
+    YearOfEra,
+    /// The (proleptic) year, so that the year before year 1 is year 0.
+    Year,
+    /// The era, 0 for BC and 1 for AD.
+    Era,
+    /// The seconds since '1970-01-01 00:00:00 TAI', only available from an [`Instant`](struct.Instant.html).
+    InstantSeconds,
+    /// The offset from UTC, in seconds.
+    OffsetSeconds,
+}
+
+impl ChronoField {
+    /// Gets the range of values the field can have in any date, time or instant.
+    ///
+    /// Time fields allow for a leap second, so the second of the minute is from 0 to 59-60.
+    /// The range for a particular value is given by [`TemporalAccessor::range()`].
+    ///
+    /// [`TemporalAccessor::range()`]: trait.TemporalAccessor.html#method.range
+    pub const fn range(&self) -> ValueRange {
+        // The fields of the day, which run over by a second when it ends with a leap second.
+        const fn of_day(per_second: i64) -> ValueRange {
+            ValueRange::of_variable(
+                0,
+                SECONDS_IN_DAY * per_second - 1,
+                (SECONDS_IN_DAY + 1) * per_second - 1,
+            )
+        }
+        match self {
+            ChronoField::NanoOfSecond => ValueRange::of(0, NANOSECONDS_IN_SECOND - 1),
+            ChronoField::NanoOfDay => of_day(NANOSECONDS_IN_SECOND),
+            ChronoField::MicroOfSecond => ValueRange::of(0, MICROSECONDS_IN_SECOND - 1),
+            ChronoField::MicroOfDay => of_day(MICROSECONDS_IN_SECOND),
+            ChronoField::MilliOfSecond => ValueRange::of(0, MILLISECONDS_IN_SECOND - 1),
+            ChronoField::MilliOfDay => of_day(MILLISECONDS_IN_SECOND),
+            ChronoField::SecondOfMinute => {
+                ValueRange::of_variable(0, SECONDS_IN_MINUTE - 1, SECONDS_IN_MINUTE)
+            }
+            ChronoField::SecondOfDay => of_day(1),
+            ChronoField::MinuteOfHour => ValueRange::of(0, MINUTES_IN_HOUR - 1),
+            ChronoField::MinuteOfDay => ValueRange::of(0, MINUTES_IN_DAY - 1),
+            ChronoField::HourOfAmPm => ValueRange::of(0, 11),
+            ChronoField::ClockHourOfAmPm => ValueRange::of(1, 12),
+            ChronoField::HourOfDay => ValueRange::of(0, HOURS_IN_DAY - 1),
+            ChronoField::ClockHourOfDay => ValueRange::of(1, HOURS_IN_DAY),
+            ChronoField::AmPmOfDay => ValueRange::of(0, 1),
+            ChronoField::DayOfWeek
+            | ChronoField::AlignedDayOfWeekInMonth
+            | ChronoField::AlignedDayOfWeekInYear => ValueRange::of(1, DAYS_IN_WEEK_ISO),
+            ChronoField::DayOfMonth => ValueRange::of_variable(1, 28, 31),
+            ChronoField::DayOfYear => ValueRange::of_variable(1, 365, 366),
+            ChronoField::EpochDay => {
+                ValueRange::of(LocalDate::MIN.to_epoch_day(), LocalDate::MAX.to_epoch_day())
+            }
+            ChronoField::AlignedWeekOfMonth => ValueRange::of_variable(1, 4, 5),
+            ChronoField::AlignedWeekOfYear => ValueRange::of(1, 53),
+            ChronoField::MonthOfYear => ValueRange::of(1, MONTHS_IN_YEAR),
+            ChronoField::ProlepticMonth => ValueRange::of(
+                MIN_YEAR * MONTHS_IN_YEAR,
+                MAX_YEAR * MONTHS_IN_YEAR + MONTHS_IN_YEAR - 1,
+            ),
+            // Years before 1 AD reach one further, as there is no year 0 BC.
+            ChronoField::YearOfEra => ValueRange::of_variable(1, MAX_YEAR, 1 - MIN_YEAR),
+            ChronoField::Year => ValueRange::of(MIN_YEAR, MAX_YEAR),
+            ChronoField::Era => ValueRange::of(0, 1),
+            ChronoField::InstantSeconds => ValueRange::of(i64::MIN, i64::MAX),
+            ChronoField::OffsetSeconds => {
+                ValueRange::of(-MAX_OFFSET_SECONDS as i64, MAX_OFFSET_SECONDS as i64)
+            }
+        }
+    }
+}
+
+/// A standard unit of time, following java.time's `ChronoUnit`.
+///
+/// Units from days up are date-based, and their durations are estimates,
+/// as days may have leap seconds and months and years vary in length.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChronoUnit {
+    /// Nanoseconds.
+    Nanos,
+    /// Microseconds.
+    Micros,
+    /// Milliseconds.
+    Millis,
+    /// Seconds.
+    Seconds,
+    /// Minutes, of 60 seconds.
+    Minutes,
+    /// Hours, of 60 minutes.
+    Hours,
+    /// The morning or afternoon, of 12 hours.
+    HalfDays,
+    /// Days, estimated as 24 hours.
+    Days,
+    /// Weeks, of 7 days.
+    Weeks,
+    /// Months, estimated as a twelfth of a year.
+    Months,
+    /// Years, estimated as 365.2425 days.
+    Years,
+    /// Decades, of 10 years.
+    Decades,
+    /// Centuries, of 100 years.
+    Centuries,
+    /// Millennia, of 1000 years.
+    Millennia,
+    /// Eras, estimated as a billion years, so the year 1 AD is an era after 1 BC.
+    Eras,
+    /// A unit for forever, with a duration of [`Duration::MAX`](struct.Duration.html#associatedconstant.MAX).
+    Forever,
+}
+
+impl ChronoUnit {
+    /// Gets the (estimated) duration of this unit.
+    pub const fn duration(&self) -> Duration {
+        match self {
+            ChronoUnit::Nanos => Duration::of_nanos(1),
+            ChronoUnit::Micros => Duration::of_nanos(NANOSECONDS_IN_MICROSECOND),
+            ChronoUnit::Millis => Duration::of_millis(1),
+            ChronoUnit::Seconds => Duration::of_seconds(1),
+            ChronoUnit::Minutes => Duration::of_seconds(SECONDS_IN_MINUTE),
+            ChronoUnit::Hours => Duration::of_seconds(SECONDS_IN_HOUR),
+            ChronoUnit::HalfDays => Duration::of_seconds(SECONDS_IN_DAY / 2),
+            ChronoUnit::Days => Duration::of_seconds(SECONDS_IN_DAY),
+            ChronoUnit::Weeks => Duration::of_seconds(DAYS_IN_WEEK_ISO * SECONDS_IN_DAY),
+            ChronoUnit::Months => Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE / MONTHS_IN_YEAR),
+            ChronoUnit::Years => Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE),
+            ChronoUnit::Decades => Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE * YEARS_IN_DECADE),
+            ChronoUnit::Centuries => {
+                Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE * YEARS_IN_CENTURY)
+            }
+            ChronoUnit::Millennia => {
+                Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE * YEARS_IN_MILLENNIUM)
+            }
+            ChronoUnit::Eras => Duration::of_seconds(SECONDS_IN_YEAR_ESTIMATE * YEARS_IN_ERA),
+            ChronoUnit::Forever => Duration::MAX,
+        }
+    }
+
+    /// Checks if the duration of this unit is an estimate, which it is for days and longer.
+    pub const fn is_duration_estimated(&self) -> bool {
+        *self as u8 >= ChronoUnit::Days as u8
+    }
+
+    /// Checks if this unit is a date unit, from days to eras.
+    pub const fn is_date_based(&self) -> bool {
+        *self as u8 >= ChronoUnit::Days as u8 && (*self as u8) < ChronoUnit::Forever as u8
+    }
+
+    /// Checks if this unit is a time unit, from nanoseconds to half days.
+    pub const fn is_time_based(&self) -> bool {
+        (*self as u8) < ChronoUnit::Days as u8
+    }
+
+    /// Calculates the amount of this unit between two temporals, as [`Temporal::until()`] does.
+    ///
+    /// # Parameters
+    ///  - `start`: the start, inclusive.
+    ///  - `end`: the end, exclusive.
+    ///
+    /// # Returns
+    /// The amount, or an error if the unit is not supported by the type, or the amount would overflow.
+    ///
+    /// [`Temporal::until()`]: trait.Temporal.html#tymethod.until
+    pub fn between<T: Temporal>(&self, start: &T, end: &T) -> Result<i64, DateTimeError> {
+        start.until(end, *self)
+    }
+}
+
+/// Read access to the fields of a date, time or instant, such as the year or the hour of the day.
+///
+/// This is what formatting works from, so it can be implemented for other types to format them.
+pub trait TemporalAccessor {
+    /// Gets the value of a field.
+    ///
+    /// # Parameters
+    ///  - `field`: the field to get.
+    ///
+    /// # Returns
+    /// The value, or `None` if this type does not have the field.
+    fn get(&self, field: ChronoField) -> Option<i64>;
+
+    /// Gets the range of values a field can have, given the rest of this value,
+    /// so the day of the month of a date in February is from 1 to 28 or 29.
+    ///
+    /// By default this is the range of the field itself, from [`ChronoField::range()`].
+    ///
+    /// # Parameters
+    ///  - `field`: the field to get the range of.
+    ///
+    /// # Returns
+    /// The range, or `None` if this type does not have the field.
+    ///
+    /// [`ChronoField::range()`]: enum.ChronoField.html#method.range
+    fn range(&self, field: ChronoField) -> Option<ValueRange> {
+        self.get(field).map(|_| field.range())
+    }
+
+    /// Gets the ID of the time-zone, such as 'Europe/Paris', if this type has one.
+    fn zone_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// A date, time or instant that can be changed by field and moved or measured in [`ChronoUnit`](enum.ChronoUnit.html)s.
+pub trait Temporal: TemporalAccessor + Sized {
+    /// Returns a copy of this with a field changed, following java.time.
+    ///
+    /// Other fields are kept where they can be, so changing the month of '2007-03-31' to February gives '2007-02-28'.
+    ///
+    /// # Parameters
+    ///  - `field`: the field to change.
+    ///  - `value`: the new value of the field.
+    ///
+    /// # Returns
+    /// The changed value, or an error if this type does not have the field,
+    /// the value is outside the field's [`range()`](trait.TemporalAccessor.html#method.range), or the result would be out of range.
+    fn with(&self, field: ChronoField, value: i64) -> Result<Self, DateTimeError>;
+
+    /// Returns a copy of this with an amount of a unit added.
+    ///
+    /// # Parameters
+    ///  - `amount`: the amount of the unit to add, which may be negative.
+    ///  - `unit`: the unit of the amount.
+    ///
+    /// # Returns
+    /// The moved value, or an error if the unit is not supported by the type, or the result would be out of range.
+    fn plus(&self, amount: i64, unit: ChronoUnit) -> Result<Self, DateTimeError>;
+
+    /// Calculates the amount of time until another temporal, in whole units.
+    ///
+    /// The amount is truncated towards zero, so it is negative if `end` is before this,
+    /// and one day before the same time tomorrow is 0 days.
+    ///
+    /// # Parameters
+    ///  - `end`: the end, exclusive.
+    ///  - `unit`: the unit to measure in.
+    ///
+    /// # Returns
+    /// The amount, or an error if the unit is not supported by the type, or the amount would overflow.
+    fn until(&self, end: &Self, unit: ChronoUnit) -> Result<i64, DateTimeError>;
+}
+
+impl TemporalAccessor for LocalDate {
+    fn get(&self, field: ChronoField) -> Option<i64> {
+        date_field(self, field)
+    }
+
+    fn range(&self, field: ChronoField) -> Option<ValueRange> {
+        date_range(self, field)
+    }
+}
+
+impl TemporalAccessor for OffsetDateTime {
+    /// Gets a field of the local date and time, or the offset.
+    ///
+    /// During a leap second the time fields count it as the 60th second of the minute,
+    /// so (for example) the second of the day repeats the first second of the next minute.
+    fn get(&self, field: ChronoField) -> Option<i64> {
+        match field {
+            ChronoField::OffsetSeconds => Some(self.offset_seconds() as i64),
+            ChronoField::InstantSeconds => None,
+            _ => date_field(&self.date(), field).or_else(|| {
+                time_field(
+                    self.hour() as i64,
+                    self.minute() as i64,
+                    self.second() as i64,
+                    self.nano() as i64,
+                    field,
+                )
+            }),
+        }
+    }
+
+    /// Gets the range of a field of the local date and time, or the offset.
+    ///
+    /// The second of the minute always allows for a leap second, which is only checked when a date-time is made.
+    fn range(&self, field: ChronoField) -> Option<ValueRange> {
+        match field {
+            ChronoField::InstantSeconds => None,
+            _ => date_range(&self.date(), field).or_else(|| Some(field.range())),
+        }
+    }
+}
+
+impl TemporalAccessor for Instant {
+    /// Gets the seconds since the epoch, or a part of the second.
+    ///
+    /// Instants have no date or time of day, as those depend on the time scale;
+    /// convert to an [`OffsetDateTime`](struct.OffsetDateTime.html) for UTC ones.
+    fn get(&self, field: ChronoField) -> Option<i64> {
+        let nano = self.nano() as i64;
+        match field {
+            ChronoField::NanoOfSecond => Some(nano),
+            ChronoField::MicroOfSecond => Some(nano / NANOSECONDS_IN_MICROSECOND),
+            ChronoField::MilliOfSecond => Some(nano / NANOSECONDS_IN_MILLISECOND),
+            ChronoField::InstantSeconds => Some(self.epoch_second()),
+            _ => None,
+        }
+    }
+}
+
+impl Temporal for LocalDate {
+    /// Returns a copy of this date with a date field changed.
+    ///
+    /// Days of the week, aligned weeks and the proleptic month move the date by the difference,
+    /// and the day of the month is clamped when changing the month or year, as by [`LocalDate::plus_months()`].
+    ///
+    /// [`LocalDate::plus_months()`]: struct.LocalDate.html#method.plus_months
+    fn with(&self, field: ChronoField, value: i64) -> Result<LocalDate, DateTimeError> {
+        let range = date_range(self, field).ok_or(DateTimeError::UnsupportedField(field))?;
+        let value = range.check_valid_value(value, field)?;
+        let current = date_field(self, field).unwrap();
+        let year = self.year();
+        let month = self.month_value();
+        // Keeps the day of the month where it exists, as `plus_months()` does.
+        let clamped = |year: i64, month: u32| {
+            if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+                return Err(DateTimeError::Overflow);
+            }
+            let day = self.day_of_month().min(days_in_month(year, month));
+            Ok(LocalDate::of(year, month, day))
+        };
+        let plus_days = |days: i64| {
+            LocalDate::of_epoch_day_checked(self.to_epoch_day() + days)
+                .ok_or(DateTimeError::Overflow)
+        };
+        match field {
+            ChronoField::DayOfWeek
+            | ChronoField::AlignedDayOfWeekInMonth
+            | ChronoField::AlignedDayOfWeekInYear
+            | ChronoField::EpochDay => plus_days(value - current),
+            ChronoField::AlignedWeekOfMonth | ChronoField::AlignedWeekOfYear => {
+                plus_days((value - current) * DAYS_IN_WEEK_ISO)
+            }
+            ChronoField::DayOfMonth => Ok(LocalDate::of(year, month, value as u32)),
+            ChronoField::DayOfYear => Ok(LocalDate::of_year_day(year, value as u32)),
+            ChronoField::MonthOfYear => clamped(year, value as u32),
+            ChronoField::ProlepticMonth => Ok(self.plus_months(value - current)),
+            ChronoField::YearOfEra if year >= 1 => clamped(value, month),
+            ChronoField::YearOfEra => clamped(1 - value, month),
+            ChronoField::Year => clamped(value, month),
+            ChronoField::Era if value == current => Ok(*self),
+            ChronoField::Era => clamped(1 - year, month),
+            _ => Err(DateTimeError::UnsupportedField(field)),
+        }
+    }
+
+    /// Returns a copy of this date with an amount of a unit from days to eras added.
+    ///
+    /// Months and longer are added as by [`LocalDate::plus_months()`], and eras move the year to the other era.
+    ///
+    /// [`LocalDate::plus_months()`]: struct.LocalDate.html#method.plus_months
+    fn plus(&self, amount: i64, unit: ChronoUnit) -> Result<LocalDate, DateTimeError> {
+        let months = |months_in_unit: i64| {
+            amount
+                .checked_mul(months_in_unit)
+                .and_then(|months| self.plus_months_checked(months))
+                .ok_or(DateTimeError::Overflow)
+        };
+        match unit {
+            ChronoUnit::Days | ChronoUnit::Weeks => amount
+                .checked_mul(unit.duration().seconds() / SECONDS_IN_DAY)
+                .and_then(|days| self.to_epoch_day().checked_add(days))
+                .and_then(LocalDate::of_epoch_day_checked)
+                .ok_or(DateTimeError::Overflow),
+            ChronoUnit::Months => months(1),
+            ChronoUnit::Years => months(MONTHS_IN_YEAR),
+            ChronoUnit::Decades => months(MONTHS_IN_YEAR * YEARS_IN_DECADE),
+            ChronoUnit::Centuries => months(MONTHS_IN_YEAR * YEARS_IN_CENTURY),
+            ChronoUnit::Millennia => months(MONTHS_IN_YEAR * YEARS_IN_MILLENNIUM),
+            ChronoUnit::Eras => {
+                let era = date_field(self, ChronoField::Era).unwrap();
+                let era = era.checked_add(amount).ok_or(DateTimeError::Overflow)?;
+                self.with(ChronoField::Era, era)
+            }
+            _ => Err(DateTimeError::UnsupportedUnit(unit)),
+        }
+    }
+
+    /// Calculates the amount of time until another date, in units from days to eras.
+    ///
+    /// Months and longer count whole months from the day of the month,
+    /// so '2007-01-31' to '2007-02-28' is 0 months.
+    fn until(&self, end: &LocalDate, unit: ChronoUnit) -> Result<i64, DateTimeError> {
+        epoch_days_until(self.to_epoch_day(), end.to_epoch_day(), unit)
+    }
+}
+
+impl Temporal for OffsetDateTime {
+    /// Returns a copy of this date-time with a field of the local date and time, or the offset, changed.
+    ///
+    /// Date fields change the date as [`LocalDate`](struct.LocalDate.html) does, keeping the time,
+    /// and the offset is changed keeping the local date and time.
+    /// A second of 60 is only allowed where it is a leap second ending a UTC day.
+    fn with(&self, field: ChronoField, value: i64) -> Result<OffsetDateTime, DateTimeError> {
+        let (date, hour, minute) = (self.date(), self.hour() as i64, self.minute() as i64);
+        let (second, nano) = (self.second() as i64, self.nano() as i64);
+        let of = |date: LocalDate, (hour, minute, second, nano): (i64, i64, i64, i64)| {
+            OffsetDateTime::try_of(
+                date,
+                hour as u32,
+                minute as u32,
+                second as u32,
+                nano as u32,
+                self.offset_seconds(),
+            )
+        };
+        if field == ChronoField::InstantSeconds {
+            return Err(DateTimeError::UnsupportedField(field));
+        }
+        if field == ChronoField::OffsetSeconds {
+            let value = field.range().check_valid_value(value, field)?;
+            return OffsetDateTime::try_of(
+                date,
+                hour as u32,
+                minute as u32,
+                second as u32,
+                nano as u32,
+                value as i32,
+            );
+        }
+        if date_field(&date, field).is_some() {
+            return of(date.with(field, value)?, (hour, minute, second, nano));
+        }
+
+        let value = field.range().check_valid_value(value, field)?;
+        // The time at a second of the day, where the last is a leap second.
+        let at = |second_of_day: i64, nano: i64| {
+            if second_of_day == SECONDS_IN_DAY {
+                (
+                    HOURS_IN_DAY - 1,
+                    MINUTES_IN_HOUR - 1,
+                    SECONDS_IN_MINUTE,
+                    nano,
+                )
+            } else {
+                (
+                    second_of_day / SECONDS_IN_HOUR,
+                    second_of_day / SECONDS_IN_MINUTE % MINUTES_IN_HOUR,
+                    second_of_day % SECONDS_IN_MINUTE,
+                    nano,
+                )
+            }
+        };
+        let time = match field {
+            ChronoField::NanoOfSecond => (hour, minute, second, value),
+            ChronoField::MicroOfSecond => {
+                (hour, minute, second, value * NANOSECONDS_IN_MICROSECOND)
+            }
+            ChronoField::MilliOfSecond => {
+                (hour, minute, second, value * NANOSECONDS_IN_MILLISECOND)
+            }
+            ChronoField::NanoOfDay => {
+                at(value / NANOSECONDS_IN_SECOND, value % NANOSECONDS_IN_SECOND)
+            }
+            ChronoField::MicroOfDay => at(
+                value / MICROSECONDS_IN_SECOND,
+                value % MICROSECONDS_IN_SECOND * NANOSECONDS_IN_MICROSECOND,
+            ),
+            ChronoField::MilliOfDay => at(
+                value / MILLISECONDS_IN_SECOND,
+                value % MILLISECONDS_IN_SECOND * NANOSECONDS_IN_MILLISECOND,
+            ),
+            ChronoField::SecondOfDay => at(value, nano),
+            ChronoField::SecondOfMinute => (hour, minute, value, nano),
+            ChronoField::MinuteOfHour => (hour, value, second, nano),
+            ChronoField::MinuteOfDay => (
+                value / MINUTES_IN_HOUR,
+                value % MINUTES_IN_HOUR,
+                second,
+                nano,
+            ),
+            ChronoField::HourOfAmPm => (hour / 12 * 12 + value, minute, second, nano),
+            ChronoField::ClockHourOfAmPm => (hour / 12 * 12 + value % 12, minute, second, nano),
+            ChronoField::HourOfDay => (value, minute, second, nano),
+            ChronoField::ClockHourOfDay => (value % HOURS_IN_DAY, minute, second, nano),
+            ChronoField::AmPmOfDay => (value * 12 + hour % 12, minute, second, nano),
+            _ => return Err(DateTimeError::UnsupportedField(field)),
+        };
+        of(date, time)
+    }
+
+    /// Returns a copy of this date-time with an amount of any unit but forever added.
+    ///
+    /// Time units are added to the local date-time as by [`OffsetDateTime::plus_duration()`], without counting leap seconds,
+    /// and date units to the local date, keeping the time.
+    ///
+    /// [`OffsetDateTime::plus_duration()`]: struct.OffsetDateTime.html#method.plus_duration
+    fn plus(&self, amount: i64, unit: ChronoUnit) -> Result<OffsetDateTime, DateTimeError> {
+        if unit.is_time_based() {
+            let duration = unit.duration();
+            let nanos = total_nanos(duration.seconds(), duration.nano()) * amount as i128;
+            let duration = Duration::try_of_total_nanos(nanos)?;
+            return self
+                .plus_duration_checked(duration)
+                .ok_or(DateTimeError::Overflow);
+        }
+        let date = self.date().plus(amount, unit)?;
+        OffsetDateTime::try_of(
+            date,
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.nano(),
+            self.offset_seconds(),
+        )
+    }
+
+    /// Calculates the amount of time until another date-time, in any unit but forever.
+    ///
+    /// The end is first moved to the offset of this date-time.
+    /// Time units measure the time between as if days had no leap seconds,
+    /// while date units count whole days between the local date-times, and then as [`LocalDate`](struct.LocalDate.html) does.
+    fn until(&self, end: &OffsetDateTime, unit: ChronoUnit) -> Result<i64, DateTimeError> {
+        let start = self.local_nanos();
+        let end = end.local_nanos()
+            + (self.offset_seconds() as i128 - end.offset_seconds() as i128)
+                * NANOSECONDS_IN_SECOND as i128;
+        if unit.is_time_based() {
+            return nanos_until(end - start, unit);
+        }
+        if !unit.is_date_based() {
+            return Err(DateTimeError::UnsupportedUnit(unit));
+        }
+
+        let nanos_in_day = NANOSECONDS_IN_DAY as i128;
+        let start_day = start.div_euclid(nanos_in_day) as i64;
+        let mut end_day = end.div_euclid(nanos_in_day) as i64;
+        let (start_time, end_time) = (start.rem_euclid(nanos_in_day), end.rem_euclid(nanos_in_day));
+        if end_day > start_day && end_time < start_time {
+            end_day -= 1;
+        } else if end_day < start_day && end_time > start_time {
+            end_day += 1;
+        }
+        // Moving the end to this offset can take it a day past the range of a date, so count from the days.
+        epoch_days_until(start_day, end_day, unit)
+    }
+}
+
+impl Temporal for Instant {
+    /// Returns a copy of this instant with the seconds since the epoch, or a part of the second, changed.
+    ///
+    /// Changing the milliseconds or microseconds of the second replaces the whole nanosecond of the second.
+    fn with(&self, field: ChronoField, value: i64) -> Result<Instant, DateTimeError> {
+        let nano = match field {
+            ChronoField::InstantSeconds => {
+                return Ok(Instant::of_epoch_second_and_adjustment(
+                    value,
+                    self.nano() as i64,
+                ))
+            }
+            ChronoField::NanoOfSecond => 1,
+            ChronoField::MicroOfSecond => NANOSECONDS_IN_MICROSECOND,
+            ChronoField::MilliOfSecond => NANOSECONDS_IN_MILLISECOND,
+            _ => return Err(DateTimeError::UnsupportedField(field)),
+        };
+        let value = field.range().check_valid_value(value, field)?;
+        Ok(Instant::of_epoch_second_and_adjustment(
+            self.epoch_second(),
+            value * nano,
+        ))
+    }
+
+    /// Returns a copy of this instant with an amount of a unit from nanoseconds to days of 86,400 seconds added.
+    fn plus(&self, amount: i64, unit: ChronoUnit) -> Result<Instant, DateTimeError> {
+        if !unit.is_time_based() && unit != ChronoUnit::Days {
+            return Err(DateTimeError::UnsupportedUnit(unit));
+        }
+        let duration = unit.duration();
+        let nanos = total_nanos(duration.seconds(), duration.nano()) * amount as i128;
+        Instant::try_of_epoch_nano(self.to_epoch_nano() + nanos)
+    }
+
+    /// Calculates the amount of time until another instant, in units from nanoseconds to days of 86,400 seconds.
+    fn until(&self, end: &Instant, unit: ChronoUnit) -> Result<i64, DateTimeError> {
+        if !unit.is_time_based() && unit != ChronoUnit::Days {
+            return Err(DateTimeError::UnsupportedUnit(unit));
+        }
+        nanos_until(
+            total_nanos(end.epoch_second(), end.nano())
+                - total_nanos(self.epoch_second(), self.nano()),
+            unit,
+        )
+    }
+}
+
+// Divides nanoseconds into whole time units (or days), truncating towards zero.
+fn nanos_until(nanos: i128, unit: ChronoUnit) -> Result<i64, DateTimeError> {
+    let duration = unit.duration();
+    i64_of(nanos / total_nanos(duration.seconds(), duration.nano())).ok_or(DateTimeError::Overflow)
+}
+
+// Counts whole date units between two days since the epoch, as `LocalDate::until()` does.
+// The days may be just outside the range of a date.
+fn epoch_days_until(start: i64, end: i64, unit: ChronoUnit) -> Result<i64, DateTimeError> {
+    let days = end - start;
+    // Days of the month never reach 32, so this orders by month, then day.
+    let packed = |epoch_day: i64| {
+        let (year, month, day) = date_of_epoch_day(epoch_day);
+        (year * MONTHS_IN_YEAR + month as i64 - 1) * 32 + day as i64
+    };
+    let months = (packed(end) - packed(start)) / 32;
+    let era = |epoch_day: i64| {
+        if date_of_epoch_day(epoch_day).0 >= 1 {
+            1
+        } else {
+            0
+        }
+    };
+    Ok(match unit {
+        ChronoUnit::Days => days,
+        ChronoUnit::Weeks => days / DAYS_IN_WEEK_ISO,
+        ChronoUnit::Months => months,
+        ChronoUnit::Years => months / MONTHS_IN_YEAR,
+        ChronoUnit::Decades => months / (MONTHS_IN_YEAR * YEARS_IN_DECADE),
+        ChronoUnit::Centuries => months / (MONTHS_IN_YEAR * YEARS_IN_CENTURY),
+        ChronoUnit::Millennia => months / (MONTHS_IN_YEAR * YEARS_IN_MILLENNIUM),
+        ChronoUnit::Eras => era(end) - era(start),
+        _ => return Err(DateTimeError::UnsupportedUnit(unit)),
+    })
+}
+
+// The range of a date field for a particular date, or `None` for other fields.
+fn date_range(date: &LocalDate, field: ChronoField) -> Option<ValueRange> {
+    date_field(date, field)?;
+    Some(match field {
+        ChronoField::DayOfMonth => ValueRange::of(1, date.length_of_month() as i64),
+        ChronoField::DayOfYear => ValueRange::of(1, date.length_of_year() as i64),
+        // Only a February of 28 days has exactly four weeks.
+        ChronoField::AlignedWeekOfMonth => {
+            ValueRange::of(1, if date.length_of_month() == 28 { 4 } else { 5 })
+        }
+        ChronoField::YearOfEra if date.year() >= 1 => ValueRange::of(1, MAX_YEAR),
+        ChronoField::YearOfEra => ValueRange::of(1, 1 - MIN_YEAR),
+        _ => field.range(),
+    })
+}
+
+pub(crate) fn date_field(date: &LocalDate, field: ChronoField) -> Option<i64> {
+    let year = date.year();
+    let day_of_month = date.day_of_month() as i64;
+    let day_of_year = date.day_of_year() as i64;
+    Some(match field {
+        ChronoField::DayOfWeek => date.day_of_week().value() as i64,
+        ChronoField::AlignedDayOfWeekInMonth => (day_of_month - 1) % DAYS_IN_WEEK_ISO + 1,
+        ChronoField::AlignedDayOfWeekInYear => (day_of_year - 1) % DAYS_IN_WEEK_ISO + 1,
+        ChronoField::DayOfMonth => day_of_month,
+        ChronoField::DayOfYear => day_of_year,
+        ChronoField::EpochDay => date.to_epoch_day(),
+        ChronoField::AlignedWeekOfMonth => (day_of_month - 1) / DAYS_IN_WEEK_ISO + 1,
+        ChronoField::AlignedWeekOfYear => (day_of_year - 1) / DAYS_IN_WEEK_ISO + 1,
+        ChronoField::MonthOfYear => date.month_value() as i64,
+        ChronoField::ProlepticMonth => year * MONTHS_IN_YEAR + date.month_value() as i64 - 1,
+        ChronoField::YearOfEra => {
+            if year >= 1 {
+                year
+            } else {
+                1 - year
+            }
+        }
+        ChronoField::Year => year,
+        ChronoField::Era => (year >= 1) as i64,
+        _ => return None,
+    })
+}
+
+pub(crate) fn time_field(
+    hour: i64,
+    minute: i64,
+    second: i64,
+    nano: i64,
+    field: ChronoField,
+) -> Option<i64> {
+    let second_of_day = (hour * MINUTES_IN_HOUR + minute) * SECONDS_IN_MINUTE + second;
+    Some(match field {
+        ChronoField::NanoOfSecond => nano,
+        ChronoField::NanoOfDay => second_of_day * NANOSECONDS_IN_SECOND + nano,
+        ChronoField::MicroOfSecond => nano / NANOSECONDS_IN_MICROSECOND,
+        ChronoField::MicroOfDay => {
+            second_of_day * MICROSECONDS_IN_SECOND + nano / NANOSECONDS_IN_MICROSECOND
+        }
+        ChronoField::MilliOfSecond => nano / NANOSECONDS_IN_MILLISECOND,
+        ChronoField::MilliOfDay => {
+            second_of_day * MILLISECONDS_IN_SECOND + nano / NANOSECONDS_IN_MILLISECOND
+        }
+        ChronoField::SecondOfMinute => second,
+        ChronoField::SecondOfDay => second_of_day,
+        ChronoField::MinuteOfHour => minute,
+        ChronoField::MinuteOfDay => hour * MINUTES_IN_HOUR + minute,
+        ChronoField::HourOfAmPm => hour % 12,
+        ChronoField::ClockHourOfAmPm => (hour + 11) % 12 + 1,
+        ChronoField::HourOfDay => hour,
+        ChronoField::ClockHourOfDay => {
+            if hour == 0 {
+                HOURS_IN_DAY
+            } else {
+                hour
+            }
+        }
+        ChronoField::AmPmOfDay => hour / 12,
+        _ => return None,
+    })
+}
@@ -0,0 +1,352 @@
+use proptest::prelude::*;
+
+use crate::{
+    ChronoField, ChronoUnit, DateTimeError, Instant, LocalDate, OffsetDateTime, Temporal,
+    TemporalAccessor, ValueRange,
+};
+
+fn invalid(field: ChronoField, value: i64, min: i64, max: i64) -> DateTimeError {
+    DateTimeError::InvalidField {
+        field,
+        value,
+        range: min..=max,
+    }
+}
+
+#[test]
+fn date_ranges() {
+    let february = LocalDate::of(2007, 2, 10);
+    let leap_february = LocalDate::of(2008, 2, 10);
+
+    assert_eq!(
+        Some(ValueRange::of(1, 28)),
+        february.range(ChronoField::DayOfMonth)
+    );
+    assert_eq!(
+        Some(ValueRange::of(1, 29)),
+        leap_february.range(ChronoField::DayOfMonth)
+    );
+    assert_eq!(
+        Some(ValueRange::of(1, 366)),
+        leap_february.range(ChronoField::DayOfYear)
+    );
+    assert_eq!(
+        Some(ValueRange::of(1, 4)),
+        february.range(ChronoField::AlignedWeekOfMonth)
+    );
+    assert_eq!(
+        Some(ValueRange::of(1, 5)),
+        leap_february.range(ChronoField::AlignedWeekOfMonth)
+    );
+    assert_eq!(
+        Some(ValueRange::of(1, 12)),
+        february.range(ChronoField::MonthOfYear)
+    );
+    assert_eq!(None, february.range(ChronoField::HourOfDay));
+}
+
+#[test]
+fn date_time_ranges() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2007, 4, 10), 12, 0, 0, 0, 3_600);
+
+    assert_eq!(
+        Some(ValueRange::of(1, 30)),
+        date_time.range(ChronoField::DayOfMonth)
+    );
+    assert_eq!(
+        Some(ValueRange::of_variable(0, 59, 60)),
+        date_time.range(ChronoField::SecondOfMinute)
+    );
+    assert_eq!(None, date_time.range(ChronoField::InstantSeconds));
+    assert_eq!(
+        Some(ChronoField::InstantSeconds.range()),
+        Instant::EPOCH.range(ChronoField::InstantSeconds)
+    );
+}
+
+#[test]
+fn date_with() {
+    let date = LocalDate::of(2007, 3, 31);
+
+    assert_eq!(
+        Ok(LocalDate::of(2007, 2, 28)),
+        date.with(ChronoField::MonthOfYear, 2)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 15)),
+        date.with(ChronoField::DayOfMonth, 15)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 1, 1)),
+        date.with(ChronoField::DayOfYear, 1)
+    );
+    // 2007-03-31 is a Saturday.
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 26)),
+        date.with(ChronoField::DayOfWeek, 1)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 3)),
+        date.with(ChronoField::AlignedWeekOfMonth, 1)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2008, 3, 31)),
+        date.with(ChronoField::Year, 2008)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(-2006, 3, 31)),
+        date.with(ChronoField::Era, 0)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(-2, 3, 31)),
+        LocalDate::of(-1, 3, 31).with(ChronoField::YearOfEra, 3)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(1, 11, 30)),
+        date.with(ChronoField::ProlepticMonth, 22)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(1970, 1, 1)),
+        date.with(ChronoField::EpochDay, 0)
+    );
+}
+
+#[test]
+fn date_with_invalid() {
+    let date = LocalDate::of(2007, 2, 10);
+
+    assert_eq!(
+        Err(invalid(ChronoField::DayOfMonth, 29, 1, 28)),
+        date.with(ChronoField::DayOfMonth, 29)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::MonthOfYear, 13, 1, 12)),
+        date.with(ChronoField::MonthOfYear, 13)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedField(ChronoField::HourOfDay)),
+        date.with(ChronoField::HourOfDay, 1)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        LocalDate::MIN.with(ChronoField::Era, 1)
+    );
+}
+
+#[test]
+fn date_plus() {
+    let date = LocalDate::of(2007, 1, 31);
+
+    assert_eq!(
+        Ok(LocalDate::of(2007, 2, 1)),
+        date.plus(1, ChronoUnit::Days)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 1, 17)),
+        date.plus(-2, ChronoUnit::Weeks)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 2, 28)),
+        date.plus(1, ChronoUnit::Months)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2107, 1, 31)),
+        date.plus(1, ChronoUnit::Centuries)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(-2006, 1, 31)),
+        date.plus(-1, ChronoUnit::Eras)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Hours)),
+        date.plus(1, ChronoUnit::Hours)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        date.plus(i64::MAX, ChronoUnit::Years)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::Era, 2, 0, 1)),
+        date.plus(1, ChronoUnit::Eras)
+    );
+}
+
+#[test]
+fn date_time_with() {
+    let date = LocalDate::of(2016, 12, 31);
+    let date_time = OffsetDateTime::of(date, 22, 30, 15, 500, 3_600);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(date, 10, 30, 15, 500, 3_600)),
+        date_time.with(ChronoField::AmPmOfDay, 0)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(date, 22, 30, 15, 7_000_000, 3_600)),
+        date_time.with(ChronoField::MilliOfSecond, 7)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(date, 1, 2, 3, 500, 3_600)),
+        date_time.with(ChronoField::SecondOfDay, 3_723)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(date, 22, 30, 15, 500, -3_600)),
+        date_time.with(ChronoField::OffsetSeconds, -3_600)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2016, 2, 29),
+            22,
+            30,
+            15,
+            500,
+            3_600
+        )),
+        date_time.with(ChronoField::MonthOfYear, 2)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedField(ChronoField::InstantSeconds)),
+        date_time.with(ChronoField::InstantSeconds, 0)
+    );
+    assert_eq!(
+        Err(invalid(ChronoField::HourOfDay, 24, 0, 23)),
+        date_time.with(ChronoField::HourOfDay, 24)
+    );
+}
+
+#[test]
+fn date_time_with_leap_second() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 0, 0, 0);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2016, 12, 31),
+            23,
+            59,
+            60,
+            0,
+            0
+        )),
+        date_time.with(ChronoField::SecondOfMinute, 60)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2016, 12, 31),
+            23,
+            59,
+            60,
+            0,
+            0
+        )),
+        date_time.with(ChronoField::SecondOfDay, 86_400)
+    );
+    // Away from the end of a UTC day there is no leap second.
+    assert!(date_time
+        .with(ChronoField::OffsetSeconds, 3_600)
+        .unwrap()
+        .with(ChronoField::SecondOfMinute, 60)
+        .is_err());
+}
+
+#[test]
+fn date_time_plus() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2007, 1, 31), 23, 0, 0, 0, 0);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(LocalDate::of(2007, 2, 1), 1, 0, 0, 0, 0)),
+        date_time.plus(2, ChronoUnit::Hours)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2007, 2, 28),
+            23,
+            0,
+            0,
+            0,
+            0
+        )),
+        date_time.plus(1, ChronoUnit::Months)
+    );
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2007, 1, 31),
+            22,
+            59,
+            59,
+            999_999_999,
+            0
+        )),
+        date_time.plus(-1, ChronoUnit::Nanos)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        date_time.plus(i64::MAX, ChronoUnit::HalfDays)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Forever)),
+        date_time.plus(1, ChronoUnit::Forever)
+    );
+}
+
+#[test]
+fn instant_with_and_plus() {
+    let instant = Instant::of_epoch_second_and_adjustment(100, 123_456_789);
+
+    assert_eq!(
+        Ok(Instant::of_epoch_second_and_adjustment(100, 5_000_000)),
+        instant.with(ChronoField::MilliOfSecond, 5)
+    );
+    assert_eq!(
+        Ok(Instant::of_epoch_second_and_adjustment(-7, 123_456_789)),
+        instant.with(ChronoField::InstantSeconds, -7)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedField(ChronoField::Year)),
+        instant.with(ChronoField::Year, 2000)
+    );
+    assert_eq!(
+        Ok(Instant::of_epoch_second_and_adjustment(86_500, 123_456_789)),
+        instant.plus(1, ChronoUnit::Days)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Weeks)),
+        instant.plus(1, ChronoUnit::Weeks)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        Instant::MAX.plus(1, ChronoUnit::Nanos)
+    );
+}
+
+proptest! {
+    #[test]
+    fn date_with_own_field(epoch_day in -1_000_000i64..1_000_000) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        for &field in &[
+            ChronoField::DayOfWeek,
+            ChronoField::AlignedWeekOfYear,
+            ChronoField::DayOfMonth,
+            ChronoField::DayOfYear,
+            ChronoField::MonthOfYear,
+            ChronoField::ProlepticMonth,
+            ChronoField::YearOfEra,
+            ChronoField::Era,
+        ] {
+            prop_assert_eq!(Ok(date), date.with(field, date.get(field).unwrap()));
+        }
+    }
+
+    #[test]
+    fn date_plus_days(epoch_day in -1_000_000i64..1_000_000, days in -1_000_000i64..1_000_000) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+
+        prop_assert_eq!(
+            Ok(LocalDate::of_epoch_day(epoch_day + days)),
+            date.plus(days, ChronoUnit::Days)
+        );
+        prop_assert_eq!(
+            Ok(date),
+            date.plus(days, ChronoUnit::Days)?.plus(-days, ChronoUnit::Days)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use proptest::prelude::*;
+
+use crate::{ChronoField, Instant, LocalDate, OffsetDateTime, TemporalAccessor};
+
+#[test]
+fn local_date() {
+    let date = LocalDate::of(2003, 7, 1);
+
+    for &(field, value) in &[
+        (ChronoField::DayOfWeek, 2),
+        (ChronoField::AlignedDayOfWeekInMonth, 1),
+        (ChronoField::AlignedDayOfWeekInYear, 7),
+        (ChronoField::DayOfMonth, 1),
+        (ChronoField::DayOfYear, 182),
+        (ChronoField::EpochDay, 12_234),
+        (ChronoField::AlignedWeekOfMonth, 1),
+        (ChronoField::AlignedWeekOfYear, 26),
+        (ChronoField::MonthOfYear, 7),
+        (ChronoField::ProlepticMonth, 2003 * 12 + 6),
+        (ChronoField::YearOfEra, 2003),
+        (ChronoField::Year, 2003),
+        (ChronoField::Era, 1),
+    ] {
+        assert_eq!(Some(value), date.get(field), "{:?}", field);
+    }
+    assert_eq!(None, date.get(ChronoField::HourOfDay));
+    assert_eq!(None, date.get(ChronoField::OffsetSeconds));
+}
+
+#[test]
+fn local_date_before_common_era() {
+    let date = LocalDate::of(-1, 1, 1);
+
+    assert_eq!(Some(2), date.get(ChronoField::YearOfEra));
+    assert_eq!(Some(-1), date.get(ChronoField::Year));
+    assert_eq!(Some(0), date.get(ChronoField::Era));
+    assert_eq!(Some(-12), date.get(ChronoField::ProlepticMonth));
+}
+
+#[test]
+fn offset_date_time() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 22, 52, 37, 123_456_789, 7_200);
+
+    for &(field, value) in &[
+        (ChronoField::NanoOfSecond, 123_456_789),
+        (ChronoField::NanoOfDay, 82_357_123_456_789),
+        (ChronoField::MicroOfSecond, 123_456),
+        (ChronoField::MicroOfDay, 82_357_123_456),
+        (ChronoField::MilliOfSecond, 123),
+        (ChronoField::MilliOfDay, 82_357_123),
+        (ChronoField::SecondOfMinute, 37),
+        (ChronoField::SecondOfDay, 82_357),
+        (ChronoField::MinuteOfHour, 52),
+        (ChronoField::MinuteOfDay, 1_372),
+        (ChronoField::HourOfAmPm, 10),
+        (ChronoField::ClockHourOfAmPm, 10),
+        (ChronoField::HourOfDay, 22),
+        (ChronoField::ClockHourOfDay, 22),
+        (ChronoField::AmPmOfDay, 1),
+        (ChronoField::DayOfMonth, 1),
+        (ChronoField::OffsetSeconds, 7_200),
+    ] {
+        assert_eq!(Some(value), date_time.get(field), "{:?}", field);
+    }
+    assert_eq!(None, date_time.get(ChronoField::InstantSeconds));
+}
+
+#[test]
+fn clock_hours() {
+    let midnight = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 0, 0, 0, 0, 0);
+    let noon = OffsetDateTime::of(LocalDate::of(2003, 7, 1), 12, 0, 0, 0, 0);
+
+    assert_eq!(Some(12), midnight.get(ChronoField::ClockHourOfAmPm));
+    assert_eq!(Some(24), midnight.get(ChronoField::ClockHourOfDay));
+    assert_eq!(Some(12), noon.get(ChronoField::ClockHourOfAmPm));
+    assert_eq!(Some(0), noon.get(ChronoField::HourOfAmPm));
+    assert_eq!(Some(1), noon.get(ChronoField::AmPmOfDay));
+}
+
+#[test]
+fn leap_second() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2016, 12, 31), 23, 59, 60, 0, 0);
+
+    assert_eq!(Some(60), date_time.get(ChronoField::SecondOfMinute));
+    assert_eq!(Some(86_400), date_time.get(ChronoField::SecondOfDay));
+}
+
+proptest! {
+    #[test]
+    fn instant(seconds: i64, nanos in 0u32..1_000_000_000) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos as i64);
+
+        prop_assert_eq!(Some(seconds), instant.get(ChronoField::InstantSeconds));
+        prop_assert_eq!(Some(nanos as i64), instant.get(ChronoField::NanoOfSecond));
+        prop_assert_eq!(Some(nanos as i64 / 1_000), instant.get(ChronoField::MicroOfSecond));
+        prop_assert_eq!(Some(nanos as i64 / 1_000_000), instant.get(ChronoField::MilliOfSecond));
+        prop_assert_eq!(None, instant.get(ChronoField::Year));
+        prop_assert_eq!(None, instant.zone_id());
+    }
+}
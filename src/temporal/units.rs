@@ -0,0 +1,202 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{ChronoUnit, DateTimeError, Duration, Instant, LocalDate, OffsetDateTime, Temporal};
+
+fn date_strategy() -> impl Strategy<Value = LocalDate> {
+    (-1_000_000..=1_000_000i64).prop_map(LocalDate::of_epoch_day)
+}
+
+#[test]
+fn durations() {
+    assert_eq!(Duration::of_nanos(1), ChronoUnit::Nanos.duration());
+    assert_eq!(Duration::of_hours(12), ChronoUnit::HalfDays.duration());
+    assert_eq!(Duration::of_days(7), ChronoUnit::Weeks.duration());
+    assert_eq!(
+        Duration::of_seconds(2_629_746),
+        ChronoUnit::Months.duration()
+    );
+    assert_eq!(
+        Duration::of_seconds(31_556_952),
+        ChronoUnit::Years.duration()
+    );
+    assert_eq!(
+        Duration::of_seconds(31_556_952_000),
+        ChronoUnit::Millennia.duration()
+    );
+    assert_eq!(Duration::MAX, ChronoUnit::Forever.duration());
+
+    assert!(!ChronoUnit::HalfDays.is_duration_estimated());
+    assert!(ChronoUnit::Days.is_duration_estimated());
+    assert!(ChronoUnit::Forever.is_duration_estimated());
+    assert!(ChronoUnit::HalfDays.is_time_based());
+    assert!(ChronoUnit::Days.is_date_based());
+    assert!(ChronoUnit::Eras.is_date_based());
+    assert!(!ChronoUnit::Forever.is_date_based());
+    assert!(!ChronoUnit::Forever.is_time_based());
+}
+
+#[test]
+fn local_date_until() {
+    let start = LocalDate::of(2007, 1, 31);
+    let until = |end: LocalDate, unit| start.until(&end, unit);
+
+    assert_eq!(Ok(28), until(LocalDate::of(2007, 2, 28), ChronoUnit::Days));
+    assert_eq!(Ok(4), until(LocalDate::of(2007, 2, 28), ChronoUnit::Weeks));
+    assert_eq!(Ok(0), until(LocalDate::of(2007, 2, 28), ChronoUnit::Months));
+    assert_eq!(Ok(1), until(LocalDate::of(2007, 3, 1), ChronoUnit::Months));
+    assert_eq!(
+        Ok(-1),
+        until(LocalDate::of(2006, 12, 31), ChronoUnit::Months)
+    );
+    assert_eq!(Ok(0), until(LocalDate::of(2006, 12, 31), ChronoUnit::Years));
+    assert_eq!(Ok(-1), until(LocalDate::of(2006, 1, 31), ChronoUnit::Years));
+    assert_eq!(
+        Ok(2),
+        until(LocalDate::of(2027, 1, 31), ChronoUnit::Decades)
+    );
+    assert_eq!(
+        Ok(0),
+        until(LocalDate::of(2107, 1, 30), ChronoUnit::Centuries)
+    );
+    assert_eq!(
+        Ok(1),
+        until(LocalDate::of(3007, 1, 31), ChronoUnit::Millennia)
+    );
+    assert_eq!(
+        Ok(1),
+        LocalDate::of(0, 12, 31).until(&LocalDate::of(1, 1, 1), ChronoUnit::Eras)
+    );
+    assert_eq!(
+        Ok(1),
+        ChronoUnit::Months.between(&LocalDate::of(2007, 1, 31), &LocalDate::of(2007, 3, 1))
+    );
+}
+
+#[test]
+fn instant_until() {
+    let start = Instant::of_epoch_second_and_adjustment(0, 500_000_000);
+
+    assert_eq!(
+        Ok(0),
+        start.until(&Instant::of_epoch_second(1), ChronoUnit::Seconds)
+    );
+    assert_eq!(
+        Ok(500),
+        start.until(&Instant::of_epoch_second(1), ChronoUnit::Millis)
+    );
+    assert_eq!(
+        Ok(0),
+        start.until(&Instant::of_epoch_second(0), ChronoUnit::Seconds)
+    );
+    assert_eq!(
+        Ok(-2),
+        start.until(&Instant::of_epoch_second(-2), ChronoUnit::Seconds)
+    );
+    assert_eq!(
+        Ok(1),
+        start.until(
+            &Instant::of_epoch_second(SECONDS_IN_DAY + 1),
+            ChronoUnit::Days
+        )
+    );
+    assert_eq!(
+        Ok(23),
+        ChronoUnit::Hours.between(&start, &Instant::of_epoch_second(SECONDS_IN_DAY))
+    );
+}
+
+#[test]
+fn offset_date_time_until() {
+    let start = OffsetDateTime::of(LocalDate::of(2007, 1, 1), 12, 0, 0, 0, 0);
+    let before_noon = OffsetDateTime::of(LocalDate::of(2007, 1, 2), 12, 0, 0, 0, 3_600);
+
+    assert_eq!(Ok(23), start.until(&before_noon, ChronoUnit::Hours));
+    assert_eq!(Ok(0), start.until(&before_noon, ChronoUnit::Days));
+    assert_eq!(Ok(0), before_noon.until(&start, ChronoUnit::Days));
+    assert_eq!(
+        Ok(1),
+        start.until(
+            &before_noon.plus_duration(Duration::of_hours(1)),
+            ChronoUnit::Days
+        )
+    );
+    assert_eq!(
+        Ok(1),
+        start.until(
+            &OffsetDateTime::of(LocalDate::of(2007, 2, 1), 12, 0, 0, 0, 0),
+            ChronoUnit::Months
+        )
+    );
+    // Leap seconds aren't counted.
+    assert_eq!(
+        Ok(SECONDS_IN_DAY),
+        OffsetDateTime::of(LocalDate::of(2016, 12, 31), 12, 0, 0, 0, 0).until(
+            &OffsetDateTime::of(LocalDate::of(2017, 1, 1), 12, 0, 0, 0, 0),
+            ChronoUnit::Seconds
+        )
+    );
+}
+
+#[test]
+fn unsupported_units() {
+    let date = LocalDate::EPOCH;
+    let instant = Instant::of_epoch_second(0);
+    let date_time = OffsetDateTime::of(date, 0, 0, 0, 0, 0);
+
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Hours)),
+        date.until(&date, ChronoUnit::Hours)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Forever)),
+        date.until(&date, ChronoUnit::Forever)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Weeks)),
+        instant.until(&instant, ChronoUnit::Weeks)
+    );
+    assert_eq!(
+        Err(DateTimeError::UnsupportedUnit(ChronoUnit::Forever)),
+        ChronoUnit::Forever.between(&date_time, &date_time)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        instant.until(&Instant::of_epoch_second(i64::MAX), ChronoUnit::Nanos)
+    );
+}
+
+#[test]
+fn offset_date_time_until_end_of_range() {
+    let start = OffsetDateTime::of(LocalDate::MAX, 0, 0, 0, 0, 18 * 3_600);
+    let end = OffsetDateTime::of(LocalDate::MAX, 12, 0, 0, 0, -18 * 3_600);
+
+    assert_eq!(Ok(2), start.until(&end, ChronoUnit::Days));
+    assert_eq!(Ok(0), start.until(&end, ChronoUnit::Months));
+    assert_eq!(Ok(-2), end.until(&start, ChronoUnit::Days));
+    assert_eq!(
+        Ok(-2),
+        OffsetDateTime::of(LocalDate::MIN, 12, 0, 0, 0, -18 * 3_600).until(
+            &OffsetDateTime::of(LocalDate::MIN, 0, 0, 0, 0, 18 * 3_600),
+            ChronoUnit::Days
+        )
+    );
+}
+
+proptest! {
+    #[test]
+    fn months_until_fit(start in date_strategy(), end in date_strategy()) {
+        let months = start.until(&end, ChronoUnit::Months)?;
+        let reached = start.plus_months(months);
+
+        prop_assert_eq!(Ok(end.to_epoch_day() - start.to_epoch_day()), start.until(&end, ChronoUnit::Days));
+        prop_assert_eq!(Ok(months / 12), start.until(&end, ChronoUnit::Years));
+        // Clamping to the end of a month can reach the end with a month more, so only the whole months are checked.
+        if start <= end {
+            prop_assert!(reached <= end);
+        } else {
+            prop_assert!(reached >= end);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::constants::*;
+use crate::{DateTimeError, DayOfWeek, LocalDate};
+
+#[cfg(test)]
+pub mod adjusters;
+
+/// A strategy for adjusting a date, such as to the last day of the month or the next Tuesday, following java.time.
+///
+/// Adjusters are applied with [`LocalDate::with_adjuster()`] and [`OffsetDateTime::with_adjuster()`],
+/// and the common ones are made by [`TemporalAdjusters`].
+/// Any closure taking a `LocalDate` and returning a `Result` is an adjuster, so rules such as
+/// 'the last weekday of the month' can be written by calling other adjusters from a closure.
+///
+/// [`LocalDate::with_adjuster()`]: struct.LocalDate.html#method.with_adjuster
+/// [`OffsetDateTime::with_adjuster()`]: struct.OffsetDateTime.html#method.with_adjuster
+/// [`TemporalAdjusters`]: enum.TemporalAdjusters.html
+pub trait TemporalAdjuster {
+    /// Adjusts a date.
+    ///
+    /// # Parameters
+    ///  - `date`: the date to adjust.
+    ///
+    /// # Returns
+    /// The adjusted date, or an error if it would be out of range.
+    fn adjust_into(&self, date: LocalDate) -> Result<LocalDate, DateTimeError>;
+}
+
+impl<F> TemporalAdjuster for F
+where
+    F: Fn(LocalDate) -> Result<LocalDate, DateTimeError>,
+{
+    fn adjust_into(&self, date: LocalDate) -> Result<LocalDate, DateTimeError> {
+        self(date)
+    }
+}
+
+/// The common [`TemporalAdjuster`](trait.TemporalAdjuster.html)s, following java.time's `TemporalAdjusters`.
+///
+/// This only groups the functions making them, so it has no values.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TemporalAdjusters {}
+
+impl TemporalAdjusters {
+    /// Obtains an adjuster to the first day of the month, so '2007-03-15' is '2007-03-01'.
+    pub fn first_day_of_month() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| Ok(with_day(date, 1))
+    }
+
+    /// Obtains an adjuster to the last day of the month, so '2007-02-15' is '2007-02-28'.
+    pub fn last_day_of_month() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| Ok(with_day(date, date.length_of_month()))
+    }
+
+    /// Obtains an adjuster to the first day of the next month, so '2007-12-15' is '2008-01-01'.
+    pub fn first_day_of_next_month() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| {
+            date.plus_months_checked(1)
+                .map(|date| with_day(date, 1))
+                .ok_or(DateTimeError::Overflow)
+        }
+    }
+
+    /// Obtains an adjuster to the first day of the year, so '2007-03-15' is '2007-01-01'.
+    pub fn first_day_of_year() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| Ok(LocalDate::of(date.year(), 1, 1))
+    }
+
+    /// Obtains an adjuster to the last day of the year, so '2007-03-15' is '2007-12-31'.
+    pub fn last_day_of_year() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| Ok(LocalDate::of(date.year(), 12, 31))
+    }
+
+    /// Obtains an adjuster to the first day of the next year, so '2007-03-15' is '2008-01-01'.
+    pub fn first_day_of_next_year() -> impl TemporalAdjuster + Copy {
+        |date: LocalDate| {
+            LocalDate::try_of(date.year() + 1, 1, 1).map_err(|_| DateTimeError::Overflow)
+        }
+    }
+
+    /// Obtains an adjuster to the first given day of the week in the month, so the first Tuesday of '2007-03-15' is '2007-03-06'.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn first_in_month(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        TemporalAdjusters::day_of_week_in_month(1, day_of_week)
+    }
+
+    /// Obtains an adjuster to the last given day of the week in the month, so the last Tuesday of '2007-03-15' is '2007-03-27'.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn last_in_month(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        TemporalAdjusters::day_of_week_in_month(-1, day_of_week)
+    }
+
+    /// Obtains an adjuster to the nth given day of the week in the month, such as the second Tuesday.
+    ///
+    /// A negative ordinal counts back from the end of the month, so -1 is the last, and 0 is the last in the previous month.
+    /// Ordinals past the end of the month continue into the following months, so the fifth Tuesday of February 2007 is '2007-03-06'.
+    ///
+    /// # Parameters
+    ///  - `ordinal`: the position of the day of the week in the month.
+    ///  - `day_of_week`: the day of the week.
+    pub fn day_of_week_in_month(
+        ordinal: i64,
+        day_of_week: DayOfWeek,
+    ) -> impl TemporalAdjuster + Copy {
+        move |date: LocalDate| {
+            let (start, days) = if ordinal >= 0 {
+                let first = with_day(date, 1);
+                let days = days_until(first.day_of_week(), day_of_week);
+                (
+                    first,
+                    (ordinal - 1)
+                        .checked_mul(DAYS_IN_WEEK_ISO)
+                        .and_then(|weeks| weeks.checked_add(days)),
+                )
+            } else {
+                let last = with_day(date, date.length_of_month());
+                let days = -days_until(day_of_week, last.day_of_week());
+                (
+                    last,
+                    (ordinal + 1)
+                        .checked_mul(DAYS_IN_WEEK_ISO)
+                        .and_then(|weeks| weeks.checked_add(days)),
+                )
+            };
+            days.map_or(Err(DateTimeError::Overflow), |days| plus_days(start, days))
+        }
+    }
+
+    /// Obtains an adjuster to the next given day of the week after the date, so the next Tuesday of Tuesday '2007-03-13' is '2007-03-20'.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn next(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        move |date: LocalDate| {
+            let days = days_until(date.day_of_week(), day_of_week);
+            plus_days(date, if days == 0 { DAYS_IN_WEEK_ISO } else { days })
+        }
+    }
+
+    /// Obtains an adjuster to the next given day of the week, or the date itself if it is that day.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn next_or_same(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        move |date: LocalDate| plus_days(date, days_until(date.day_of_week(), day_of_week))
+    }
+
+    /// Obtains an adjuster to the previous given day of the week before the date, so the previous Tuesday of Tuesday '2007-03-13' is '2007-03-06'.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn previous(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        move |date: LocalDate| {
+            let days = days_until(day_of_week, date.day_of_week());
+            plus_days(date, if days == 0 { -DAYS_IN_WEEK_ISO } else { -days })
+        }
+    }
+
+    /// Obtains an adjuster to the previous given day of the week, or the date itself if it is that day.
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the day of the week.
+    pub fn previous_or_same(day_of_week: DayOfWeek) -> impl TemporalAdjuster + Copy {
+        move |date: LocalDate| plus_days(date, -days_until(day_of_week, date.day_of_week()))
+    }
+}
+
+// The day of the month always exists, so this can't fail.
+fn with_day(date: LocalDate, day: u32) -> LocalDate {
+    LocalDate::of(date.year(), date.month_value(), day)
+}
+
+fn plus_days(date: LocalDate, days: i64) -> Result<LocalDate, DateTimeError> {
+    date.to_epoch_day()
+        .checked_add(days)
+        .and_then(LocalDate::of_epoch_day_checked)
+        .ok_or(DateTimeError::Overflow)
+}
+
+// The days from one day of the week forward to another, from 0 to 6.
+fn days_until(from: DayOfWeek, to: DayOfWeek) -> i64 {
+    (to.value() as i64 - from.value() as i64).rem_euclid(DAYS_IN_WEEK_ISO)
+}
@@ -0,0 +1,205 @@
+use proptest::prelude::*;
+
+use crate::{
+    DateTimeError, DayOfWeek, LocalDate, OffsetDateTime, TemporalAdjuster, TemporalAdjusters,
+};
+
+#[test]
+fn days_of_month_and_year() {
+    let date = LocalDate::of(2008, 2, 15);
+
+    for &(adjuster, expected) in &[
+        (
+            &TemporalAdjusters::first_day_of_month() as &dyn TemporalAdjuster,
+            LocalDate::of(2008, 2, 1),
+        ),
+        (
+            &TemporalAdjusters::last_day_of_month(),
+            LocalDate::of(2008, 2, 29),
+        ),
+        (
+            &TemporalAdjusters::first_day_of_next_month(),
+            LocalDate::of(2008, 3, 1),
+        ),
+        (
+            &TemporalAdjusters::first_day_of_year(),
+            LocalDate::of(2008, 1, 1),
+        ),
+        (
+            &TemporalAdjusters::last_day_of_year(),
+            LocalDate::of(2008, 12, 31),
+        ),
+        (
+            &TemporalAdjusters::first_day_of_next_year(),
+            LocalDate::of(2009, 1, 1),
+        ),
+    ] {
+        assert_eq!(Ok(expected), adjuster.adjust_into(date));
+    }
+    assert_eq!(
+        Ok(LocalDate::of(2008, 1, 1)),
+        LocalDate::of(2007, 12, 31).with_adjuster(TemporalAdjusters::first_day_of_next_month())
+    );
+}
+
+#[test]
+fn days_of_week_in_month() {
+    // March 2007 starts on a Thursday and ends on a Saturday.
+    let date = LocalDate::of(2007, 3, 15);
+    let in_month = |ordinal, day_of_week| {
+        date.with_adjuster(TemporalAdjusters::day_of_week_in_month(
+            ordinal,
+            day_of_week,
+        ))
+    };
+
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 6)),
+        date.with_adjuster(TemporalAdjusters::first_in_month(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 1)),
+        date.with_adjuster(TemporalAdjusters::first_in_month(DayOfWeek::Thursday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 27)),
+        date.with_adjuster(TemporalAdjusters::last_in_month(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 31)),
+        date.with_adjuster(TemporalAdjusters::last_in_month(DayOfWeek::Saturday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 13)),
+        in_month(2, DayOfWeek::Tuesday)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 4, 3)),
+        in_month(5, DayOfWeek::Tuesday)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 2, 27)),
+        in_month(0, DayOfWeek::Tuesday)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 20)),
+        in_month(-2, DayOfWeek::Tuesday)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        in_month(i64::MAX, DayOfWeek::Tuesday)
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        in_month(i64::MIN, DayOfWeek::Tuesday)
+    );
+}
+
+#[test]
+fn relative_days_of_week() {
+    // A Tuesday.
+    let date = LocalDate::of(2007, 3, 13);
+
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 20)),
+        date.with_adjuster(TemporalAdjusters::next(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 16)),
+        date.with_adjuster(TemporalAdjusters::next(DayOfWeek::Friday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 6)),
+        date.with_adjuster(TemporalAdjusters::previous(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 9)),
+        date.with_adjuster(TemporalAdjusters::previous(DayOfWeek::Friday))
+    );
+    assert_eq!(
+        Ok(date),
+        date.with_adjuster(TemporalAdjusters::next_or_same(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(date),
+        date.with_adjuster(TemporalAdjusters::previous_or_same(DayOfWeek::Tuesday))
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 11)),
+        date.with_adjuster(TemporalAdjusters::previous_or_same(DayOfWeek::Sunday))
+    );
+    assert_eq!(
+        Err(DateTimeError::Overflow),
+        LocalDate::MAX.with_adjuster(TemporalAdjusters::next(LocalDate::MAX.day_of_week()))
+    );
+}
+
+#[test]
+fn closures() {
+    let last_business_day = |date: LocalDate| {
+        let last = date.with_adjuster(TemporalAdjusters::last_day_of_month())?;
+        match last.day_of_week() {
+            DayOfWeek::Saturday | DayOfWeek::Sunday => {
+                last.with_adjuster(TemporalAdjusters::previous(DayOfWeek::Friday))
+            }
+            _ => Ok(last),
+        }
+    };
+
+    assert_eq!(
+        Ok(LocalDate::of(2007, 3, 30)),
+        LocalDate::of(2007, 3, 15).with_adjuster(last_business_day)
+    );
+    assert_eq!(
+        Ok(LocalDate::of(2007, 4, 30)),
+        LocalDate::of(2007, 4, 1).with_adjuster(last_business_day)
+    );
+}
+
+#[test]
+fn offset_date_time() {
+    let date_time = OffsetDateTime::of(LocalDate::of(2016, 12, 15), 23, 59, 60, 5, 0);
+
+    assert_eq!(
+        Ok(OffsetDateTime::of(
+            LocalDate::of(2016, 12, 31),
+            23,
+            59,
+            60,
+            5,
+            0
+        )),
+        date_time.with_adjuster(TemporalAdjusters::last_day_of_month())
+    );
+}
+
+proptest! {
+    #[test]
+    fn next_and_previous(epoch_day in -1_000_000i64..1_000_000, day in 1u32..=7) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+        let day_of_week = DayOfWeek::of(day);
+
+        let next = date.with_adjuster(TemporalAdjusters::next(day_of_week))?;
+        prop_assert_eq!(day_of_week, next.day_of_week());
+        prop_assert!((1..=7).contains(&(next.to_epoch_day() - epoch_day)));
+
+        let previous = date.with_adjuster(TemporalAdjusters::previous_or_same(day_of_week))?;
+        prop_assert_eq!(day_of_week, previous.day_of_week());
+        prop_assert!((0..7).contains(&(epoch_day - previous.to_epoch_day())));
+    }
+
+    #[test]
+    fn in_month(epoch_day in -1_000_000i64..1_000_000, day in 1u32..=7, ordinal in 1i64..=4) {
+        let date = LocalDate::of_epoch_day(epoch_day);
+        let day_of_week = DayOfWeek::of(day);
+
+        let nth = date.with_adjuster(TemporalAdjusters::day_of_week_in_month(ordinal, day_of_week))?;
+        prop_assert_eq!(day_of_week, nth.day_of_week());
+        prop_assert_eq!(date.month_value(), nth.month_value());
+        prop_assert_eq!(ordinal, (nth.day_of_month() as i64 - 1) / 7 + 1);
+
+        let last = date.with_adjuster(TemporalAdjusters::last_in_month(day_of_week))?;
+        prop_assert_eq!(day_of_week, last.day_of_week());
+        prop_assert!(last.day_of_month() + 7 > last.length_of_month());
+    }
+}
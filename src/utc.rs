@@ -0,0 +1,82 @@
+use crate::constants::*;
+use crate::{ChronoField, DateTimeError};
+
+/// An instant on the UTC time scale, as a Modified Julian Day and the nanoseconds elapsed within that day.
+///
+/// UTC days are usually 86,400 seconds long, but a day ending in a leap second is 86,401 seconds long.
+/// Before 1972 UTC was instead steered with small fractional steps, so days could be a fraction of a second longer or shorter.
+/// Which days those are is described by a [`TaiUtcTable`], which is required to convert to and from an [`Instant`].
+///
+/// [`TaiUtcTable`]: struct.TaiUtcTable.html
+/// [`Instant`]: struct.Instant.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UtcInstant {
+    modified_julian_day: i64,
+    nano_of_day: i64,
+}
+
+impl UtcInstant {
+    /// Obtains a `UtcInstant` from a Modified Julian Day and the nanoseconds elapsed within that day.
+    ///
+    /// Whether the nanosecond of day actually exists on that day (for example, during a leap second)
+    /// is only checked when the instant is converted through a [`TaiUtcTable`].
+    ///
+    /// # Parameters
+    ///  - `modified_julian_day`: the day, counted from '1858-11-17'.
+    ///  - `nano_of_day`: the nanoseconds elapsed since the start of the day.
+    ///
+    /// # Panics
+    /// - if the nano of day is negative, or longer than a day with a leap second.
+    ///
+    /// [`TaiUtcTable`]: struct.TaiUtcTable.html
+    pub const fn of_modified_julian_day(modified_julian_day: i64, nano_of_day: i64) -> UtcInstant {
+        if nano_of_day < 0 || nano_of_day >= NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND {
+            panic!("nano of day out of range");
+        }
+        UtcInstant {
+            modified_julian_day,
+            nano_of_day,
+        }
+    }
+
+    /// Obtains a `UtcInstant` from a Modified Julian Day and the nanoseconds elapsed within that day,
+    /// or an error if the nano of day is negative, or longer than a day with a leap second.
+    ///
+    /// # Parameters
+    ///  - `modified_julian_day`: the day, counted from '1858-11-17'.
+    ///  - `nano_of_day`: the nanoseconds elapsed since the start of the day.
+    pub fn try_of_modified_julian_day(
+        modified_julian_day: i64,
+        nano_of_day: i64,
+    ) -> Result<UtcInstant, DateTimeError> {
+        let range = 0..=NANOSECONDS_IN_DAY + NANOSECONDS_IN_SECOND - 1;
+        DateTimeError::check(ChronoField::NanoOfDay, nano_of_day, range)?;
+        Ok(UtcInstant::of_modified_julian_day(
+            modified_julian_day,
+            nano_of_day,
+        ))
+    }
+
+    /// Gets the Modified Julian Day, the number of days since '1858-11-17'.
+    pub const fn modified_julian_day(&self) -> i64 {
+        self.modified_julian_day
+    }
+
+    /// Gets the number of nanoseconds elapsed within the day.
+    ///
+    /// During a leap second this is 86,400 seconds or more.
+    pub const fn nano_of_day(&self) -> i64 {
+        self.nano_of_day
+    }
+
+    /// Gets the Julian Day of this instant, including the elapsed fraction of the day.
+    ///
+    /// Julian Days start at noon, so '1970-01-01 00:00:00' is Julian Day 2,440,587.5.
+    /// During a leap second the fraction of the day is measured against a day of 86,400 seconds,
+    /// so it briefly overlaps with the start of the next day.
+    pub fn to_julian_day(&self) -> f64 {
+        self.modified_julian_day as f64
+            + JULIAN_DAY_OF_MODIFIED_JULIAN_EPOCH
+            + self.nano_of_day as f64 / NANOSECONDS_IN_DAY as f64
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::{ChronoField, DateTimeError};
+
+#[cfg(test)]
+pub mod ranges;
+
+/// The range of values a field can have, following java.time's `ValueRange`.
+///
+/// The maximum of some fields depends on the rest of the value, such as the day of the month,
+/// which is from 1 to 28-31. The range then has a smallest maximum, 28, and a largest maximum, 31;
+/// the range for a particular date, from [`TemporalAccessor::range()`], is fixed.
+///
+/// [`TemporalAccessor::range()`]: trait.TemporalAccessor.html#method.range
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ValueRange {
+    minimum: i64,
+    smallest_maximum: i64,
+    largest_maximum: i64,
+}
+
+impl ValueRange {
+    /// Obtains a fixed `ValueRange`, where the maximum is always the same.
+    ///
+    /// # Parameters
+    ///  - `minimum`: the smallest value, inclusive.
+    ///  - `maximum`: the largest value, inclusive.
+    ///
+    /// # Panics
+    /// - if the minimum is greater than the maximum.
+    pub const fn of(minimum: i64, maximum: i64) -> ValueRange {
+        ValueRange::of_variable(minimum, maximum, maximum)
+    }
+
+    /// Obtains a `ValueRange` where the maximum varies, such as 1 to 28-31.
+    ///
+    /// # Parameters
+    ///  - `minimum`: the smallest value, inclusive.
+    ///  - `smallest_maximum`: the smallest the maximum can be, inclusive.
+    ///  - `largest_maximum`: the largest the maximum can be, inclusive.
+    ///
+    /// # Panics
+    /// - if the minimum is greater than the smallest maximum, or the smallest maximum greater than the largest.
+    pub const fn of_variable(
+        minimum: i64,
+        smallest_maximum: i64,
+        largest_maximum: i64,
+    ) -> ValueRange {
+        if minimum > smallest_maximum || smallest_maximum > largest_maximum {
+            panic!("range bounds out of order");
+        }
+        ValueRange {
+            minimum,
+            smallest_maximum,
+            largest_maximum,
+        }
+    }
+
+    /// Gets the smallest value, inclusive.
+    pub const fn minimum(&self) -> i64 {
+        self.minimum
+    }
+
+    /// Gets the largest value, inclusive, which is the largest maximum.
+    pub const fn maximum(&self) -> i64 {
+        self.largest_maximum
+    }
+
+    /// Gets the smallest the maximum can be, such as 28 for the day of the month.
+    pub const fn smallest_maximum(&self) -> i64 {
+        self.smallest_maximum
+    }
+
+    /// Gets the largest the maximum can be, such as 31 for the day of the month.
+    pub const fn largest_maximum(&self) -> i64 {
+        self.largest_maximum
+    }
+
+    /// Checks if the maximum is always the same.
+    pub const fn is_fixed(&self) -> bool {
+        self.smallest_maximum == self.largest_maximum
+    }
+
+    /// Checks if a value is from the minimum to the (largest) maximum.
+    ///
+    /// # Parameters
+    ///  - `value`: the value to check.
+    pub const fn is_valid_value(&self, value: i64) -> bool {
+        value >= self.minimum && value <= self.largest_maximum
+    }
+
+    /// Checks that a value of a field is from the minimum to the (largest) maximum.
+    ///
+    /// # Parameters
+    ///  - `value`: the value to check.
+    ///  - `field`: the field the value is for, which is named in the error.
+    ///
+    /// # Returns
+    /// The value, or an error if it is out of range.
+    pub fn check_valid_value(&self, value: i64, field: ChronoField) -> Result<i64, DateTimeError> {
+        DateTimeError::check(field, value, self.minimum..=self.largest_maximum)
+    }
+}
+
+impl fmt::Display for ValueRange {
+    /// Formats this range as java.time does, such as '1 - 12' or '1 - 28/31'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.minimum, self.smallest_maximum)?;
+        if !self.is_fixed() {
+            write!(f, "/{}", self.largest_maximum)?;
+        }
+        Ok(())
+    }
+}
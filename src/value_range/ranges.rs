@@ -0,0 +1,81 @@
+use proptest::prelude::*;
+
+use crate::assert::expect_panic;
+use crate::{ChronoField, DateTimeError, ValueRange};
+
+#[test]
+fn fixed() {
+    let range = ValueRange::of(1, 12);
+
+    assert_eq!(1, range.minimum());
+    assert_eq!(12, range.maximum());
+    assert_eq!(12, range.smallest_maximum());
+    assert!(range.is_fixed());
+    assert_eq!("1 - 12", range.to_string());
+}
+
+#[test]
+fn variable() {
+    let range = ValueRange::of_variable(1, 28, 31);
+
+    assert_eq!(31, range.maximum());
+    assert_eq!(28, range.smallest_maximum());
+    assert_eq!(31, range.largest_maximum());
+    assert!(!range.is_fixed());
+    assert!(range.is_valid_value(30));
+    assert_eq!("1 - 28/31", range.to_string());
+}
+
+#[test]
+fn check_valid_value() {
+    let range = ValueRange::of_variable(1, 28, 31);
+
+    assert_eq!(Ok(31), range.check_valid_value(31, ChronoField::DayOfMonth));
+    assert_eq!(
+        Err(DateTimeError::InvalidField {
+            field: ChronoField::DayOfMonth,
+            value: 32,
+            range: 1..=31,
+        }),
+        range.check_valid_value(32, ChronoField::DayOfMonth)
+    );
+}
+
+#[test]
+fn out_of_order() {
+    expect_panic("range bounds out of order", || ValueRange::of(2, 1)).unwrap();
+    expect_panic("range bounds out of order", || {
+        ValueRange::of_variable(1, 31, 28)
+    })
+    .unwrap();
+}
+
+#[test]
+fn field_ranges() {
+    assert_eq!(
+        ValueRange::of_variable(0, 59, 60),
+        ChronoField::SecondOfMinute.range()
+    );
+    assert_eq!(
+        ValueRange::of_variable(0, 86_399, 86_400),
+        ChronoField::SecondOfDay.range()
+    );
+    assert_eq!(ValueRange::of(1, 24), ChronoField::ClockHourOfDay.range());
+    assert_eq!("1 - 365/366", ChronoField::DayOfYear.range().to_string());
+}
+
+proptest! {
+    #[test]
+    fn valid_values(minimum in -1_000i64..1_000, length in 0i64..1_000, value: i64) {
+        let range = ValueRange::of(minimum, minimum + length);
+
+        prop_assert_eq!(
+            value >= minimum && value <= minimum + length,
+            range.is_valid_value(value)
+        );
+        prop_assert_eq!(
+            range.is_valid_value(value),
+            range.check_valid_value(value, ChronoField::Year).is_ok()
+        );
+    }
+}